@@ -1,13 +1,56 @@
 use ts_std::*;
 #[allow(clippy::all)]
 fn main() {
-    console.assert(1 + 2 == 3);
-    console.assert(4 - 1 == 3);
-    console.assert(1 * 3 == 3);
-    console.assert(9 / 3 == 3);
-    console.assert(4 >> 1 == 2);
-    console.assert(2 << 4 == 32);
-    console.assert((14 & 3) == 2);
-    console.assert((1 | 4) == 5);
-    console.assert((2 ^ 2) == 0);
+    console.assert(1.0 + 2.0 == 3.0);
+    console.assert(4.0 - 1.0 == 3.0);
+    console.assert(1.0 * 3.0 == 3.0);
+    console.assert(9.0 / 3.0 == 3.0);
+    console.assert(number::shr(4.0, 1.0) == 2.0);
+    console.assert(number::shl(2.0, 4.0) == 32.0);
+    console.assert((number::bit_and(14.0, 3.0)) == 2.0);
+    console.assert((number::bit_or(1.0, 4.0)) == 5.0);
+    console.assert((number::bit_xor(2.0, 2.0)) == 0.0);
+    console.assert(7.0 / 2.0 == 3.5);
+    console.assert(-7.0 % 3.0 == -1.0);
+    console.assert(number::ushr(-1.0, 0.0) == 4294967295.0);
+    console.assert((number::bit_or(4294967296.0, 0.0)) == 0.0);
+    console.assert(number::shl(1.0, 32.0) == 1.0);
+    console.assert(number::shr(-16.0, 2.0) == -4.0);
+    let mut x = 5;
+    x = number::bit_or(x as f64, 2.0) as i64;
+    console.assert(x == 7);
+    x = number::ushr(x as f64, 1.0) as i64;
+    console.assert(x == 3);
+    x += 1;
+    console.assert(x == 4);
+    console.assert(f64::powi(2.0, 10) == 1024.0);
+    console.assert(number::pow(2.0, f64::powi(3.0, 2)) == 512.0);
+    console.assert(f64::powi(f64::powi(2.0, 3), 2) == 64.0);
+    console.assert(f64::powi(2.0, -1) == 0.5);
+    console.assert(number::pow(4.0, 0.5) == 2.0);
+    console.assert(-(f64::powi(2.0, 2)) == -4.0);
+    let side = 3;
+    console.assert(i64::pow(side, 2) == 9);
+    console.assert(f64::powi(side as f64, -1) * 3.0 == 1.0);
+    let mut y = 3.0;
+    y = f64::powi(y, 2);
+    console.assert(y == 9.0);
+    y = number::pow(y, 0.5);
+    console.assert(y == 3.0);
+    let short = vec![1.0, 2.0];
+    let long = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+    console.assert(short.len() as i64 - long.len() as i64 == -3);
+    console.assert(-(long.len() as f64) == -5.0);
+    console.assert(long.len() as i64 - short.len() as i64 - 1 == 2);
+    let base = 3;
+    console.assert(f64::powi(base as f64, 50) > 100000000000000000000000.0);
+    console.assert(i64::pow(base, 3) == 27);
+    let mut squares = vec![2.0, 3.0];
+    squares[1] = f64::powi(squares[1], 2);
+    console.assert(squares[1] == 9.0);
+    let mut record = Object::from([("width", 4.0)]);
+    record["width"] = number::pow(record["width"], 0.5);
+    console.assert(record["width"] == 2.0);
+    record.set("height", record["width"] * 3.0);
+    console.assert(record["height"] == 6.0);
 }
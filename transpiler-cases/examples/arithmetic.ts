@@ -7,3 +7,56 @@ console.assert(2 << 4 === 32);
 console.assert((14 & 3) === 2);
 console.assert((1 | 4) === 5);
 console.assert((2 ^ 2) === 0);
+console.assert(7 / 2 === 3.5);
+console.assert(-7 % 3 === -1);
+console.assert(-1 >>> 0 === 4294967295);
+console.assert((4294967296 | 0) === 0);
+console.assert(1 << 32 === 1);
+console.assert(-16 >> 2 === -4);
+
+let x = 5;
+x |= 2;
+console.assert(x === 7);
+x >>>= 1;
+console.assert(x === 3);
+x++;
+console.assert(x === 4);
+
+console.assert(2 ** 10 === 1024);
+console.assert(2 ** 3 ** 2 === 512);
+console.assert((2 ** 3) ** 2 === 64);
+console.assert(2 ** -1 === 0.5);
+console.assert(4 ** 0.5 === 2);
+console.assert(-(2 ** 2) === -4);
+
+const side = 3;
+console.assert(side ** 2 === 9);
+console.assert(side ** -1 * 3 === 1);
+
+let y = 3;
+y **= 2;
+console.assert(y === 9);
+y **= 0.5;
+console.assert(y === 3);
+
+// Differences of lengths may be negative.
+const short = [1, 2];
+const long = [1, 2, 3, 4, 5];
+console.assert(short.length - long.length === -3);
+console.assert(-long.length === -5);
+console.assert(long.length - short.length - 1 === 2);
+
+// Powers of integers that could overflow `i64` are floats.
+const base = 3;
+console.assert(base ** 50 > 1e23);
+console.assert(base ** 3 === 27);
+
+// `**=` works on elements and properties too.
+const squares = [2, 3];
+squares[1] **= 2;
+console.assert(squares[1] === 9);
+const record: Record<string, number> = { width: 4 };
+record.width **= 0.5;
+console.assert(record.width === 2);
+record.height = record.width * 3;
+console.assert(record.height === 6);
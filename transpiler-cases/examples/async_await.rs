@@ -0,0 +1,39 @@
+use ts_std::*;
+#[allow(clippy::all)]
+async fn double(x: f64) -> f64 {
+    return x * 2.0;
+}
+#[allow(clippy::all)]
+async fn greet(name: String) -> String {
+    let greeting = format!("Hello, {}", name);
+    return greeting.clone();
+}
+#[allow(clippy::all)]
+async fn sum(values: Vec<f64>) -> f64 {
+    let mut total = 0.0;
+    for i in 0..values.len() {
+        total += Promise::from_future(double(values[i])).await;
+    }
+    return total / 2.0;
+}
+#[allow(clippy::all)]
+async fn check() {
+    console.assert(string::length(&(Promise::from_future(greet("Grace".to_string())).await)) == 12);
+}
+#[allow(clippy::all)]
+fn main() {
+    run_main(async {
+        console.assert((Promise::from_future(double(21.0)).await) == 42.0);
+        console.assert((Promise::from_future(greet("Ada".to_string())).await) == "Hello, Ada");
+        let values = vec![1.0, 2.0, 3.0];
+        console.assert((Promise::from_future(sum(values.clone())).await) == 6.0);
+        console.assert(values.len() == 3);
+        let pending = Promise::from_future(double(5.0));
+        console.assert((pending.clone().await) == 10.0);
+        console.assert((pending.clone().await) + 1.0 == 11.0);
+        Promise::from_future(check());
+        Promise::from_future(check()).await;
+        let plain = Promise::resolve(7.0).await;
+        console.assert(plain == 7.0);
+    });
+}
@@ -0,0 +1,42 @@
+// Async functions become `async fn`s, and `await` becomes `.await`.
+async function double(x: number): Promise<number> {
+  return x * 2;
+}
+
+async function greet(name: string): Promise<string> {
+  const greeting = "Hello, " + name;
+  return greeting;
+}
+
+async function sum(values: number[]): Promise<number> {
+  let total = 0;
+  for (let i = 0; i < values.length; i++) {
+    total += await double(values[i]);
+  }
+  return total / 2;
+}
+
+async function check(): Promise<void> {
+  console.assert((await greet("Grace")).length === 12);
+}
+
+// Top-level `await` runs in the future that `main` drives.
+console.assert((await double(21)) === 42);
+console.assert((await greet("Ada")) === "Hello, Ada");
+
+const values = [1, 2, 3];
+console.assert((await sum(values)) === 6);
+console.assert(values.length === 3);
+
+// Promises that are stored can be awaited more than once.
+const pending = double(5);
+console.assert((await pending) === 10);
+console.assert((await pending) + 1 === 11);
+
+// Async functions start running when they are called, even if nothing awaits them.
+check();
+await check();
+
+// Awaiting a value that isn't a promise results in the value.
+const plain = await 7;
+console.assert(plain === 7);
@@ -0,0 +1,94 @@
+use ts_std::*;
+#[allow(clippy::all)]
+fn sleep(ms: f64) -> Promise<()> {
+    return Promise::new_void(|resolve, _| {
+        set_timeout(
+            {
+                let resolve = resolve.clone();
+                move || resolve()
+            },
+            ms,
+        );
+    });
+}
+#[allow(clippy::all)]
+fn countdown(from: f64) -> AsyncGenerator<f64> {
+    AsyncGenerator::new(move |yielder| async move {
+        let mut n = from;
+        while n > 0.0 {
+            sleep(10.0).await;
+            yielder.yield_value(n).await;
+            n -= 1.0;
+        }
+    })
+}
+#[allow(clippy::all)]
+fn chunks(parts: Vec<String>) -> AsyncGenerator<String, f64> {
+    AsyncGenerator::new(move |yielder| async move {
+        let mut count = 0.0;
+        for part in parts.iter().map(String::as_str) {
+            console.log("reading chunk");
+            yielder.yield_value(part.to_string()).await;
+            count += 1.0;
+        }
+        return count;
+    })
+}
+#[allow(clippy::all)]
+fn numbered(parts: Vec<String>) -> AsyncGenerator<String> {
+    AsyncGenerator::new(move |yielder| async move {
+        let count = yielder.yield_all(chunks(parts.clone())).await;
+        yielder
+            .yield_value(format!("chunks: {}", count.to_js_string()))
+            .await;
+    })
+}
+#[allow(clippy::all)]
+fn main() {
+    run_main(async {
+        {
+            let generator = countdown(3.0);
+            while let Some(n) = generator.next().await {
+                console.log(n);
+            }
+            generator.close();
+        }
+        {
+            let generator = numbered(vec![
+                "stream".to_string(),
+                "ing".to_string(),
+                " text".to_string(),
+            ]);
+            while let Some(chunk) = generator.next().await {
+                console.log(&chunk);
+            }
+            generator.close();
+        }
+        let stream = chunks(vec!["ab".to_string(), "cd".to_string(), "ef".to_string()]);
+        while let Some(chunk) = stream.next().await {
+            if chunk == "cd" {
+                break;
+            }
+            console.log(&chunk);
+        }
+        stream.close();
+        while let Some(chunk) = stream.next().await {
+            console.log(format!("after break: {}", chunk));
+        }
+        stream.close();
+        set_timeout(
+            move || {
+                console.log("timeout");
+            },
+            15.0,
+        );
+        {
+            let generator = countdown(2.0);
+            while let Some(n) = generator.next().await {
+                console.log(format!("count {}", n.to_js_string()));
+            }
+            generator.close();
+        }
+        console.log("done");
+    });
+}
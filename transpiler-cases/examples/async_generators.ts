@@ -0,0 +1,57 @@
+// Async generators yield values that `for await` loops receive one at a time.
+function sleep(ms: number): Promise<void> {
+  return new Promise<void>((resolve) => setTimeout(resolve, ms));
+}
+
+async function* countdown(from: number): AsyncGenerator<number> {
+  let n = from;
+  while (n > 0) {
+    await sleep(10);
+    yield n;
+    n -= 1;
+  }
+}
+
+for await (const n of countdown(3)) {
+  console.log(n);
+}
+
+// The body only runs when the next value is requested.
+async function* chunks(parts: string[]): AsyncGenerator<string, number> {
+  let count = 0;
+  for (const part of parts) {
+    console.log("reading chunk");
+    yield part;
+    count += 1;
+  }
+  return count;
+}
+
+// `yield*` delegates to another async generator, and evaluates to what it returned.
+async function* numbered(parts: string[]): AsyncGenerator<string> {
+  const count = yield* chunks(parts);
+  yield "chunks: " + count;
+}
+
+for await (const chunk of numbered(["stream", "ing", " text"])) {
+  console.log(chunk);
+}
+
+// `break` finishes the generator, so it doesn't read further chunks, and later loops over it get nothing.
+const stream = chunks(["ab", "cd", "ef"]);
+for await (const chunk of stream) {
+  if (chunk === "cd") {
+    break;
+  }
+  console.log(chunk);
+}
+for await (const chunk of stream) {
+  console.log("after break: " + chunk);
+}
+
+// Timers run while a generator waits.
+setTimeout(() => console.log("timeout"), 15);
+for await (const n of countdown(2)) {
+  console.log("count " + n);
+}
+console.log("done");
@@ -0,0 +1,43 @@
+use ts_std::*;
+#[allow(clippy::all)]
+fn main() {
+    let x = -4.5;
+    let sign = if x < 0.0 { -1 } else { 1 };
+    console.assert(sign == -1);
+    let magnitude = if x < 0.0 { -x } else { x };
+    console.assert(magnitude == 4.5);
+    let parity = if sign == -1 { "negative" } else { "positive" };
+    console.assert(parity == "negative");
+    let name = "";
+    let label = if name.truthy() { name } else { "anonymous" };
+    console.assert(label == "anonymous");
+    let nested = if x > 0.0 {
+        1
+    } else if x > -5.0 {
+        2
+    } else {
+        3
+    };
+    console.assert(nested == 2);
+    let mut steps = 0;
+    let last = {
+        steps += 1;
+        steps += 2;
+        steps * 10
+    };
+    console.assert(last == 30);
+    let n = 10;
+    let mut matches = 0;
+    let mut i = 0;
+    let mut j = 0;
+    console.assert(i == j);
+    i = 0;
+    j = n;
+    while i < j {
+        matches += 1;
+        i += 1;
+        j -= 1;
+    }
+    console.assert(matches == 5);
+    console.assert(i == 5 && j == 5);
+}
@@ -0,0 +1,30 @@
+// Conditional expressions become `if` expressions.
+const x = -4.5;
+const sign = x < 0 ? -1 : 1;
+console.assert(sign === -1);
+const magnitude = x < 0 ? -x : x;
+console.assert(magnitude === 4.5);
+const parity = sign === -1 ? "negative" : "positive";
+console.assert(parity === "negative");
+const name = "";
+const label = name ? name : "anonymous";
+console.assert(label === "anonymous");
+const nested = x > 0 ? 1 : x > -5 ? 2 : 3;
+console.assert(nested === 2);
+
+// Sequences evaluate every expression and result in the last one.
+let steps = 0;
+const last = (steps += 1, steps += 2, steps * 10);
+console.assert(last === 30);
+
+// They are commonly used in `for` initializers and updates.
+const n = 10;
+let matches = 0;
+let i = 0;
+let j = 0;
+console.assert(i === j);
+for (i = 0, j = n; i < j; i++, j--) {
+  matches += 1;
+}
+console.assert(matches === 5);
+console.assert(i === 5 && j === 5);
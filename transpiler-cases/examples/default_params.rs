@@ -0,0 +1,55 @@
+use ts_std::*;
+#[allow(clippy::all)]
+fn greet(name: &str, greeting: Option<&str>, suffix: Option<&str>) -> String {
+    let greeting = match greeting {
+        Some(greeting) => greeting,
+        None => "Hello",
+    };
+    let mut message = format!("{}, {}", greeting, name);
+    if !strict_eq(&suffix, &undefined) {
+        message.push_str(&suffix.to_js_string());
+    }
+    return message.clone();
+}
+#[allow(clippy::all)]
+fn scale(value: f64, factor: Option<f64>) -> f64 {
+    let factor = match factor {
+        Some(factor) => factor,
+        None => value * 2.0,
+    };
+    return value * factor;
+}
+#[allow(clippy::all)]
+fn counter(start: Option<f64>, step: Option<f64>) -> f64 {
+    let mut start = match start {
+        Some(start) => start,
+        None => 0.0,
+    };
+    let step = match step {
+        Some(step) => step,
+        None => 1.0,
+    };
+    start += step;
+    return start;
+}
+#[allow(clippy::all)]
+fn label(text: &str, count: Option<f64>) -> String {
+    if strict_eq(&count, &undefined) {
+        return text.to_string();
+    }
+    return format!("{} ({})", text, count.to_js_string());
+}
+#[allow(clippy::all)]
+fn main() {
+    console.assert(greet("Ada", None, None) == "Hello, Ada");
+    console.assert(greet("Ada", Some("Hi"), None) == "Hi, Ada");
+    console.assert(greet("Ada", Some("Hi"), Some("!")) == "Hi, Ada!");
+    console.assert(greet("Ada", None, Some("?")) == "Hello, Ada?");
+    console.assert(scale(3.0, None) == 18.0);
+    console.assert(scale(3.0, Some(1.0)) == 3.0);
+    console.assert(counter(None, None) == 1.0);
+    console.assert(counter(Some(5.0), None) == 6.0);
+    console.assert(counter(Some(5.0), Some(5.0)) == 10.0);
+    console.assert(label("items", None) == "items");
+    console.assert(label("items", Some(3.0)) == "items (3)");
+}
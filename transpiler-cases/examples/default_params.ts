@@ -0,0 +1,41 @@
+// Default parameters apply their default when the argument is omitted.
+function greet(name: string, greeting = "Hello", suffix?: string): string {
+  let message = greeting + ", " + name;
+  if (suffix !== undefined) {
+    message += suffix;
+  }
+  return message;
+}
+
+console.assert(greet("Ada") === "Hello, Ada");
+console.assert(greet("Ada", "Hi") === "Hi, Ada");
+console.assert(greet("Ada", "Hi", "!") === "Hi, Ada!");
+console.assert(greet("Ada", undefined, "?") === "Hello, Ada?");
+
+// Defaults are evaluated at call time and can refer to earlier parameters.
+function scale(value: number, factor: number = value * 2): number {
+  return value * factor;
+}
+
+console.assert(scale(3) === 18);
+console.assert(scale(3, 1) === 3);
+
+function counter(start = 0, step = 1): number {
+  start += step;
+  return start;
+}
+
+console.assert(counter() === 1);
+console.assert(counter(5) === 6);
+console.assert(counter(5, 5) === 10);
+
+// Optional parameters are `undefined` when omitted.
+function label(text: string, count?: number): string {
+  if (count === undefined) {
+    return text;
+  }
+  return text + " (" + count + ")";
+}
+
+console.assert(label("items") === "items");
+console.assert(label("items", 3) === "items (3)");
@@ -0,0 +1,42 @@
+use ts_std::*;
+#[allow(clippy::all)]
+fn same(left: &[f64], right: &[f64]) -> bool {
+    return strict_eq(&left, &right);
+}
+#[allow(clippy::all)]
+fn main() {
+    console.assert(1.0 == 1.0);
+    console.assert(NaN != NaN);
+    console.assert(0.0 == -0.0);
+    console.assert("a" == "a");
+    console.assert(true != false);
+    let nothing = null;
+    let missing = undefined;
+    console.assert(strict_eq(&nothing, &null));
+    console.assert(strict_eq(&missing, &undefined));
+    console.assert(!strict_eq(&nothing, &missing));
+    console.assert(!strict_eq(&1.0, &"1"));
+    console.assert(loose_eq(&nothing, &missing));
+    console.assert(loose_eq(&null, &undefined));
+    console.assert(!loose_eq(&missing, &0.0));
+    console.assert(!loose_eq(&nothing, &false));
+    console.assert(loose_eq(&1.0, &"1"));
+    console.assert(loose_eq(&(1.0 + 1.0), &"2"));
+    console.assert(loose_eq(&" 42 ", &42.0));
+    console.assert(loose_eq(&"", &0.0));
+    console.assert(loose_eq(&"0x10", &16.0));
+    console.assert(loose_eq(&"1e3", &1000.0));
+    console.assert(loose_eq(&"Infinity", &Infinity));
+    console.assert(!loose_eq(&"inf", &Infinity));
+    console.assert(loose_eq(&true, &1.0));
+    console.assert(loose_eq(&true, &"1"));
+    console.assert(loose_eq(&false, &""));
+    console.assert(!loose_eq(&"abc", &NaN));
+    let first = empty_array();
+    let second = empty_array();
+    let filled = vec![1.0, 2.0];
+    console.assert(same(&first, &first));
+    console.assert(!same(&first, &second));
+    console.assert(same(&filled, &filled));
+    console.assert(!same(&filled, &first));
+}
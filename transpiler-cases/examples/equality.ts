@@ -0,0 +1,43 @@
+// Strict equality compares numbers by value, where NaN isn't equal to anything.
+console.assert(1 === 1.0);
+console.assert(NaN !== NaN);
+console.assert(0 === -0);
+console.assert("a" === "a");
+console.assert(true !== false);
+
+const nothing = null;
+const missing = undefined;
+console.assert(nothing === null);
+console.assert(missing === undefined);
+console.assert(nothing !== missing);
+console.assert(1 !== "1");
+
+// Loose equality coerces operands of different types.
+console.assert(nothing == missing);
+console.assert(null == undefined);
+console.assert(missing != 0);
+console.assert(nothing != false);
+console.assert(1 == "1");
+console.assert(1 + 1 == "2");
+console.assert(" 42 " == 42);
+console.assert("" == 0);
+console.assert("0x10" == 16);
+console.assert("1e3" == 1000);
+console.assert("Infinity" == Infinity);
+console.assert("inf" != Infinity);
+console.assert(true == 1);
+console.assert(true == "1");
+console.assert(false == "");
+console.assert("abc" != NaN);
+
+// Arrays are only identical to themselves, also when they are empty or borrowed by a function.
+function same(left: number[], right: number[]): boolean {
+  return left === right;
+}
+const first: number[] = [];
+const second: number[] = [];
+const filled = [1, 2];
+console.assert(same(first, first));
+console.assert(!same(first, second));
+console.assert(same(filled, filled));
+console.assert(!same(filled, first));
@@ -0,0 +1,116 @@
+use ts_std::*;
+#[allow(clippy::all)]
+fn total(counts: &Object<f64>) -> f64 {
+    let mut sum = 0.0;
+    for name in counts.own_keys() {
+        sum += counts[&name];
+    }
+    return sum;
+}
+#[allow(clippy::all)]
+#[allow(dead_code)]
+#[derive(Clone, Debug)]
+struct Size {
+    width: f64,
+    height: f64,
+    depth: Option<f64>,
+}
+impl OwnKeys for Size {
+    fn own_keys(&self) -> Vec<String> {
+        let mut keys = vec!["width", "height"];
+        if self.depth.is_some() {
+            keys.push("depth");
+        }
+        ordered_keys(keys)
+    }
+}
+impl HasProperty for Size {
+    fn has_property(&self, key: &str) -> bool {
+        match key {
+            "width" => true,
+            "height" => true,
+            "depth" => self.depth.is_some(),
+            _ => false,
+        }
+    }
+}
+impl Delete for Size {
+    fn delete(&mut self, key: &str) -> bool {
+        if key == "depth" {
+            self.depth = None;
+        }
+        true
+    }
+}
+#[allow(clippy::all)]
+fn volume(size: &Size) -> f64 {
+    return size.width
+        * size.height
+        * (match size.depth {
+            Some(value) => value,
+            None => 1.0,
+        });
+}
+#[allow(clippy::all)]
+#[allow(dead_code)]
+#[derive(Clone, Debug)]
+struct Standings {
+    total: f64,
+    _10: f64,
+    _2: f64,
+}
+impl OwnKeys for Standings {
+    fn own_keys(&self) -> Vec<String> {
+        ordered_keys(["total", "10", "2"])
+    }
+}
+impl HasProperty for Standings {
+    fn has_property(&self, key: &str) -> bool {
+        matches!(key, "total" | "10" | "2")
+    }
+}
+#[allow(clippy::all)]
+fn main() {
+    let stock = Object::from([("pears", 4.0), ("10", 1.0), ("apples", 12.0), ("2", 7.0)]);
+    for fruit in stock.own_keys() {
+        console.log(format!("{}: {}", fruit, stock[&fruit].to_js_string()));
+    }
+    let labels = Object::from([("b", "bee".to_string()), ("a", "ay".to_string())]);
+    let mut joined = "".to_string();
+    for key in labels.own_keys() {
+        joined = format!("{}{}={};", joined, key, labels[&key]);
+    }
+    console.log(&joined);
+    console.log(format!(
+        "{} {}",
+        labels["a"],
+        stock["apples"].to_js_string()
+    ));
+    console.log(total(&stock));
+    let letters = vec!["x".to_string(), "y".to_string(), "z".to_string()];
+    for index in letters.own_keys() {
+        console.log(format!("index {}", index));
+    }
+    let mut parcel = Size {
+        width: 2.0,
+        height: 3.0,
+        depth: None,
+    };
+    for key in parcel.own_keys() {
+        console.log(format!("parcel {}", key));
+    }
+    parcel.depth = Some(4.0);
+    for key in parcel.own_keys() {
+        console.log(format!("parcel {}", key));
+    }
+    console.log(volume(&parcel));
+    let standings = Standings {
+        total: 7.0,
+        _10: 4.0,
+        _2: 3.0,
+    };
+    for key in standings.own_keys() {
+        console.log(format!("standings {}", key));
+    }
+    console.log(standings._10 + standings._2);
+}
@@ -0,0 +1,64 @@
+// `for...in` loops over the keys of dynamic objects, with integer keys first in ascending order, followed by the
+// other keys in insertion order.
+const stock: Record<string, number> = { pears: 4, "10": 1, apples: 12, "2": 7 };
+for (const fruit in stock) {
+  console.log(fruit + ": " + stock[fruit]);
+}
+
+// Object literals with values of one type are dynamic objects too.
+const labels = { b: "bee", a: "ay" };
+let joined = "";
+for (const key in labels) {
+  joined = joined + key + "=" + labels[key] + ";";
+}
+console.log(joined);
+
+// Properties can be read by name.
+console.log(labels.a + " " + stock["apples"]);
+
+function total(counts: { [name: string]: number }): number {
+  let sum = 0;
+  for (const name in counts) {
+    sum += counts[name];
+  }
+  return sum;
+}
+console.log(total(stock));
+
+// Arrays have their indices as keys.
+const letters = ["x", "y", "z"];
+for (const index in letters) {
+  console.log("index " + index);
+}
+
+// Interfaces are structs, whose keys are their properties in the order they are declared in, where optional
+// properties are only keys while they are set.
+interface Size {
+  width: number;
+  height: number;
+  depth?: number;
+}
+function volume(size: Size): number {
+  return size.width * size.height * (size.depth ?? 1);
+}
+const parcel: Size = { width: 2, height: 3 };
+for (const key in parcel) {
+  console.log("parcel " + key);
+}
+parcel.depth = 4;
+for (const key in parcel) {
+  console.log("parcel " + key);
+}
+console.log(volume(parcel));
+
+// Integer keys of interfaces come first in ascending order too.
+interface Standings {
+  total: number;
+  10: number;
+  2: number;
+}
+const standings: Standings = { total: 7, 10: 4, 2: 3 };
+for (const key in standings) {
+  console.log("standings " + key);
+}
+console.log(standings[10] + standings["2"]);
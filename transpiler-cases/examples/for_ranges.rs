@@ -0,0 +1,288 @@
+use ts_std::*;
+#[allow(clippy::all)]
+fn main() {
+    let n = 4;
+    {
+        let mut sum = 0.0;
+        for i in 0..5 {
+            sum = sum * 16.0 + i as f64;
+        }
+        let mut expected = 0.0;
+        let mut j = 0;
+        while j < 5 {
+            expected = expected * 16.0 + j as f64;
+            j += 1;
+        }
+        console.assert(sum == expected);
+    };
+    {
+        let mut sum = 0.0;
+        for i in 0..=5 {
+            sum = sum * 16.0 + i as f64;
+        }
+        let mut expected = 0.0;
+        let mut j = 0;
+        while j <= 5 {
+            expected = expected * 16.0 + j as f64;
+            j += 1;
+        }
+        console.assert(sum == expected);
+    };
+    {
+        let mut sum = 0.0;
+        for i in (1..=5).rev() {
+            sum = sum * 16.0 + i as f64;
+        }
+        let mut expected = 0.0;
+        let mut j = 5;
+        while j > 0 {
+            expected = expected * 16.0 + j as f64;
+            j -= 1;
+        }
+        console.assert(sum == expected);
+    };
+    {
+        let mut sum = 0.0;
+        for i in (0..=5).rev() {
+            sum = sum * 16.0 + i as f64;
+        }
+        let mut expected = 0.0;
+        let mut j = 5;
+        while j >= 0 {
+            expected = expected * 16.0 + j as f64;
+            j -= 1;
+        }
+        console.assert(sum == expected);
+    };
+    {
+        let mut sum = 0.0;
+        for i in (0..10).step_by(3) {
+            sum = sum * 16.0 + i as f64;
+        }
+        let mut expected = 0.0;
+        let mut j = 0;
+        while j < 10 {
+            expected = expected * 16.0 + j as f64;
+            j += 3;
+        }
+        console.assert(sum == expected);
+    };
+    {
+        let mut sum = 0.0;
+        for i in (0..=9).step_by(3) {
+            sum = sum * 16.0 + i as f64;
+        }
+        let mut expected = 0.0;
+        let mut j = 0;
+        while j <= 9 {
+            expected = expected * 16.0 + j as f64;
+            j += 3;
+        }
+        console.assert(sum == expected);
+    };
+    {
+        let mut sum = 0.0;
+        for i in (1..=10).rev().step_by(3) {
+            sum = sum * 16.0 + i as f64;
+        }
+        let mut expected = 0.0;
+        let mut j = 10;
+        while j > 0 {
+            expected = expected * 16.0 + j as f64;
+            j -= 3;
+        }
+        console.assert(sum == expected);
+    };
+    {
+        let mut sum = 0.0;
+        for i in (1..=10).rev().step_by(3) {
+            sum = sum * 16.0 + i as f64;
+        }
+        let mut expected = 0.0;
+        let mut j = 10;
+        while j >= 1 {
+            expected = expected * 16.0 + j as f64;
+            j -= 3;
+        }
+        console.assert(sum == expected);
+    };
+    {
+        let mut sum = 0.0;
+        for i in (0..6).step_by(2) {
+            sum = sum * 16.0 + i as f64;
+        }
+        let mut expected = 0.0;
+        let mut j = 0;
+        while j != 6 {
+            expected = expected * 16.0 + j as f64;
+            j += 2;
+        }
+        console.assert(sum == expected);
+    };
+    {
+        let mut sum = 0.0;
+        for i in (1..=6).rev().step_by(2) {
+            sum = sum * 16.0 + i as f64;
+        }
+        let mut expected = 0.0;
+        let mut j = 6;
+        while j != 0 {
+            expected = expected * 16.0 + j as f64;
+            j -= 2;
+        }
+        console.assert(sum == expected);
+    };
+    {
+        let mut sum = 0.0;
+        for i in 0..5 {
+            sum = sum * 16.0 + i as f64;
+        }
+        let mut expected = 0.0;
+        let mut j = 0;
+        while 5 > j {
+            expected = expected * 16.0 + j as f64;
+            j += 1;
+        }
+        console.assert(sum == expected);
+    };
+    {
+        let mut sum = 0.0;
+        for i in (1..=5).rev() {
+            sum = sum * 16.0 + i as f64;
+        }
+        let mut expected = 0.0;
+        let mut j = 5;
+        while 1 <= j {
+            expected = expected * 16.0 + j as f64;
+            j -= 1;
+        }
+        console.assert(sum == expected);
+    };
+    {
+        let mut sum = 0.0;
+        for i in (-2..=2).rev() {
+            sum = sum * 16.0 + i as f64;
+        }
+        let mut expected = 0.0;
+        let mut j = 2;
+        while j > -3 {
+            expected = expected * 16.0 + j as f64;
+            j -= 1;
+        }
+        console.assert(sum == expected);
+    };
+    {
+        let mut sum = 0.0;
+        {
+            let mut i = 0;
+            while i > 2 {
+                sum = sum * 16.0 + i as f64;
+                i += 1;
+            }
+        };
+        let mut expected = 0.0;
+        let mut j = 0;
+        while j > 2 {
+            expected = expected * 16.0 + j as f64;
+            j += 1;
+        }
+        console.assert(sum == expected);
+    };
+    {
+        let mut sum = 0.0;
+        {
+            let mut i = 0;
+            while i < 0 {
+                sum = sum * 16.0 + i as f64;
+                i -= 1;
+            }
+        };
+        let mut expected = 0.0;
+        let mut j = 0;
+        while j < 0 {
+            expected = expected * 16.0 + j as f64;
+            j -= 1;
+        }
+        console.assert(sum == expected);
+    };
+    {
+        let mut sum = 0.0;
+        for i in 0..n {
+            sum = sum * 16.0 + i as f64;
+        }
+        let mut expected = 0.0;
+        let mut j = 0;
+        while j < n {
+            expected = expected * 16.0 + j as f64;
+            j += 1;
+        }
+        console.assert(sum == expected);
+    };
+    {
+        let mut sum = 0.0;
+        for i in (0..n).rev() {
+            sum = sum * 16.0 + i as f64;
+        }
+        let mut expected = 0.0;
+        let mut j = n - 1;
+        while j >= 0 {
+            expected = expected * 16.0 + j as f64;
+            j -= 1;
+        }
+        console.assert(sum == expected);
+    };
+    {
+        let mut sum = 0.0;
+        for i in (1..n).rev() {
+            sum = sum * 16.0 + i as f64;
+        }
+        let mut expected = 0.0;
+        let mut j = n - 1;
+        while j > 0 {
+            expected = expected * 16.0 + j as f64;
+            j -= 1;
+        }
+        console.assert(sum == expected);
+    };
+    {
+        let mut sum = 0.0;
+        for i in (1..=n).step_by(2) {
+            sum = sum * 16.0 + i as f64;
+        }
+        let mut expected = 0.0;
+        let mut j = 1;
+        while j <= n {
+            expected = expected * 16.0 + j as f64;
+            j += 2;
+        }
+        console.assert(sum == expected);
+    };
+    {
+        let mut sum = 0.0;
+        for i in (n..=9).rev().step_by(2) {
+            sum = sum * 16.0 + i as f64;
+        }
+        let mut expected = 0.0;
+        let mut j = 9;
+        while j >= n {
+            expected = expected * 16.0 + j as f64;
+            j -= 2;
+        }
+        console.assert(sum == expected);
+    };
+    {
+        let x = 0.0 / 0.0;
+        let mut count = 0.0;
+        if !f64::is_nan(x) {
+            for j in ((f64::floor(x) + 1.0) as i64..=10).rev() {
+                count += j as f64;
+            }
+        }
+        if !f64::is_nan(x) {
+            for i in 0..f64::ceil(x) as i64 {
+                count += i as f64;
+            }
+        }
+        console.assert(count == 0.0);
+    };
+}
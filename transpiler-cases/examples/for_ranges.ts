@@ -0,0 +1,355 @@
+const n = 4;
+
+// i < end, i++
+{
+  let sum = 0;
+  for (let i = 0; i < 5; i++) {
+    sum = sum * 16 + i;
+  }
+
+  let expected = 0;
+  let j = 0;
+  while (j < 5) {
+    expected = expected * 16 + j;
+    j++;
+  }
+
+  console.assert(sum === expected);
+}
+
+// i <= end, i++
+{
+  let sum = 0;
+  for (let i = 0; i <= 5; i++) {
+    sum = sum * 16 + i;
+  }
+
+  let expected = 0;
+  let j = 0;
+  while (j <= 5) {
+    expected = expected * 16 + j;
+    j++;
+  }
+
+  console.assert(sum === expected);
+}
+
+// i > end, i--
+{
+  let sum = 0;
+  for (let i = 5; i > 0; i--) {
+    sum = sum * 16 + i;
+  }
+
+  let expected = 0;
+  let j = 5;
+  while (j > 0) {
+    expected = expected * 16 + j;
+    j--;
+  }
+
+  console.assert(sum === expected);
+}
+
+// i >= end, i--
+{
+  let sum = 0;
+  for (let i = 5; i >= 0; i--) {
+    sum = sum * 16 + i;
+  }
+
+  let expected = 0;
+  let j = 5;
+  while (j >= 0) {
+    expected = expected * 16 + j;
+    j--;
+  }
+
+  console.assert(sum === expected);
+}
+
+// i < end, i += step
+{
+  let sum = 0;
+  for (let i = 0; i < 10; i += 3) {
+    sum = sum * 16 + i;
+  }
+
+  let expected = 0;
+  let j = 0;
+  while (j < 10) {
+    expected = expected * 16 + j;
+    j += 3;
+  }
+
+  console.assert(sum === expected);
+}
+
+// i <= end, i += step
+{
+  let sum = 0;
+  for (let i = 0; i <= 9; i += 3) {
+    sum = sum * 16 + i;
+  }
+
+  let expected = 0;
+  let j = 0;
+  while (j <= 9) {
+    expected = expected * 16 + j;
+    j += 3;
+  }
+
+  console.assert(sum === expected);
+}
+
+// i > end, i -= step
+{
+  let sum = 0;
+  for (let i = 10; i > 0; i -= 3) {
+    sum = sum * 16 + i;
+  }
+
+  let expected = 0;
+  let j = 10;
+  while (j > 0) {
+    expected = expected * 16 + j;
+    j -= 3;
+  }
+
+  console.assert(sum === expected);
+}
+
+// i >= end, i -= step
+{
+  let sum = 0;
+  for (let i = 10; i >= 1; i -= 3) {
+    sum = sum * 16 + i;
+  }
+
+  let expected = 0;
+  let j = 10;
+  while (j >= 1) {
+    expected = expected * 16 + j;
+    j -= 3;
+  }
+
+  console.assert(sum === expected);
+}
+
+// i != end, i += step
+{
+  let sum = 0;
+  for (let i = 0; i != 6; i += 2) {
+    sum = sum * 16 + i;
+  }
+
+  let expected = 0;
+  let j = 0;
+  while (j != 6) {
+    expected = expected * 16 + j;
+    j += 2;
+  }
+
+  console.assert(sum === expected);
+}
+
+// i != end, i -= step
+{
+  let sum = 0;
+  for (let i = 6; i != 0; i -= 2) {
+    sum = sum * 16 + i;
+  }
+
+  let expected = 0;
+  let j = 6;
+  while (j != 0) {
+    expected = expected * 16 + j;
+    j -= 2;
+  }
+
+  console.assert(sum === expected);
+}
+
+// end > i, ++i
+{
+  let sum = 0;
+  for (let i = 0; 5 > i; ++i) {
+    sum = sum * 16 + i;
+  }
+
+  let expected = 0;
+  let j = 0;
+  while (5 > j) {
+    expected = expected * 16 + j;
+    ++j;
+  }
+
+  console.assert(sum === expected);
+}
+
+// end <= i, --i
+{
+  let sum = 0;
+  for (let i = 5; 1 <= i; --i) {
+    sum = sum * 16 + i;
+  }
+
+  let expected = 0;
+  let j = 5;
+  while (1 <= j) {
+    expected = expected * 16 + j;
+    --j;
+  }
+
+  console.assert(sum === expected);
+}
+
+// i > end, i-- across zero
+{
+  let sum = 0;
+  for (let i = 2; i > -3; i--) {
+    sum = sum * 16 + i;
+  }
+
+  let expected = 0;
+  let j = 2;
+  while (j > -3) {
+    expected = expected * 16 + j;
+    j--;
+  }
+
+  console.assert(sum === expected);
+}
+
+// i > end, i++ (mismatched direction)
+{
+  let sum = 0;
+  for (let i = 0; i > 2; i++) {
+    sum = sum * 16 + i;
+  }
+
+  let expected = 0;
+  let j = 0;
+  while (j > 2) {
+    expected = expected * 16 + j;
+    j++;
+  }
+
+  console.assert(sum === expected);
+}
+
+// i < end, i-- (mismatched direction)
+{
+  let sum = 0;
+  for (let i = 0; i < 0; i--) {
+    sum = sum * 16 + i;
+  }
+
+  let expected = 0;
+  let j = 0;
+  while (j < 0) {
+    expected = expected * 16 + j;
+    j--;
+  }
+
+  console.assert(sum === expected);
+}
+
+// i < n, i++
+{
+  let sum = 0;
+  for (let i = 0; i < n; i++) {
+    sum = sum * 16 + i;
+  }
+
+  let expected = 0;
+  let j = 0;
+  while (j < n) {
+    expected = expected * 16 + j;
+    j++;
+  }
+
+  console.assert(sum === expected);
+}
+
+// i >= end, i-- from n - 1
+{
+  let sum = 0;
+  for (let i = n - 1; i >= 0; i--) {
+    sum = sum * 16 + i;
+  }
+
+  let expected = 0;
+  let j = n - 1;
+  while (j >= 0) {
+    expected = expected * 16 + j;
+    j--;
+  }
+
+  console.assert(sum === expected);
+}
+
+// i > end, i-- from n - 1
+{
+  let sum = 0;
+  for (let i = n - 1; i > 0; i--) {
+    sum = sum * 16 + i;
+  }
+
+  let expected = 0;
+  let j = n - 1;
+  while (j > 0) {
+    expected = expected * 16 + j;
+    j--;
+  }
+
+  console.assert(sum === expected);
+}
+
+// i <= n, i += step
+{
+  let sum = 0;
+  for (let i = 1; i <= n; i += 2) {
+    sum = sum * 16 + i;
+  }
+
+  let expected = 0;
+  let j = 1;
+  while (j <= n) {
+    expected = expected * 16 + j;
+    j += 2;
+  }
+
+  console.assert(sum === expected);
+}
+
+// i >= n, i -= step
+{
+  let sum = 0;
+  for (let i = 9; i >= n; i -= 2) {
+    sum = sum * 16 + i;
+  }
+
+  let expected = 0;
+  let j = 9;
+  while (j >= n) {
+    expected = expected * 16 + j;
+    j -= 2;
+  }
+
+  console.assert(sum === expected);
+}
+
+// Comparisons with a NaN bound are false, so the loop doesn't run.
+{
+  const x = 0 / 0;
+  let count = 0;
+  for (let j = 10; j > x; j--) {
+    count += j;
+  }
+  for (let i = 0; i < x; i++) {
+    count += i;
+  }
+
+  console.assert(count === 0);
+}
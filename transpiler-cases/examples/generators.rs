@@ -0,0 +1,443 @@
+use ts_std::*;
+#[allow(clippy::all)]
+fn range(start: f64, end: f64, step: Option<f64>) -> Box<dyn Generator<Item = f64, Return = ()>> {
+    let step = match step {
+        Some(step) => step,
+        None => 1.0,
+    };
+    struct RangeGenerator {
+        state: usize,
+        start: f64,
+        end: f64,
+        step: f64,
+        i: f64,
+    }
+    impl Iterator for RangeGenerator {
+        type Item = f64;
+        fn next(&mut self) -> Option<f64> {
+            loop {
+                match self.state {
+                    1 => {
+                        self.i = self.start;
+                        self.state = 2;
+                        continue;
+                    }
+                    2 => {
+                        self.state = if self.i < self.end { 3 } else { 5 };
+                        continue;
+                    }
+                    3 => {
+                        self.state = 6;
+                        return Some(self.i);
+                    }
+                    4 => {
+                        self.i += self.step;
+                        self.state = 2;
+                        continue;
+                    }
+                    5 => {
+                        self.state = 0;
+                        return None;
+                    }
+                    6 => {
+                        self.state = 4;
+                        continue;
+                    }
+                    _ => return None,
+                }
+            }
+        }
+    }
+    impl Generator for RangeGenerator {
+        type Return = ();
+        fn take_return(&mut self) {}
+    }
+    Box::new(RangeGenerator {
+        state: 1,
+        start,
+        end,
+        step,
+        i: Default::default(),
+    })
+}
+#[allow(clippy::all)]
+fn fibonacci() -> Box<dyn Generator<Item = f64, Return = ()>> {
+    struct FibonacciGenerator {
+        state: usize,
+        a: f64,
+        b: f64,
+        next: f64,
+    }
+    impl Iterator for FibonacciGenerator {
+        type Item = f64;
+        fn next(&mut self) -> Option<f64> {
+            loop {
+                match self.state {
+                    1 => {
+                        self.a = 0.0;
+                        self.b = 1.0;
+                        self.state = 2;
+                        continue;
+                    }
+                    2 => {
+                        self.state = 3;
+                        continue;
+                    }
+                    3 => {
+                        self.state = 5;
+                        return Some(self.a);
+                    }
+                    4 => {
+                        self.state = 0;
+                        return None;
+                    }
+                    5 => {
+                        self.next = self.a + self.b;
+                        self.a = self.b;
+                        self.b = self.next;
+                        self.state = 2;
+                        continue;
+                    }
+                    _ => return None,
+                }
+            }
+        }
+    }
+    impl Generator for FibonacciGenerator {
+        type Return = ();
+        fn take_return(&mut self) {}
+    }
+    Box::new(FibonacciGenerator {
+        state: 1,
+        a: Default::default(),
+        b: Default::default(),
+        next: Default::default(),
+    })
+}
+#[allow(clippy::all)]
+fn shout(words: Vec<String>, stop: String) -> Box<dyn Generator<Item = String, Return = f64>> {
+    struct ShoutGenerator {
+        state: usize,
+        words: Vec<String>,
+        stop: String,
+        count: f64,
+        iterator0: Option<Box<dyn Generator<Item = String, Return = ()>>>,
+        word: String,
+        returned: Option<f64>,
+    }
+    impl Iterator for ShoutGenerator {
+        type Item = String;
+        fn next(&mut self) -> Option<String> {
+            loop {
+                match self.state {
+                    1 => {
+                        self.count = 0.0;
+                        self.iterator0 = Some(Box::new(self.words.clone().into_iter()));
+                        self.state = 2;
+                        continue;
+                    }
+                    2 => {
+                        match self.iterator0.as_mut().expect("Loop is iterating.").next() {
+                            Some(value) => {
+                                self.word = value;
+                                self.state = 3;
+                            }
+                            None => self.state = 4,
+                        }
+                        continue;
+                    }
+                    3 => {
+                        if self.word == self.stop {
+                            self.returned = Some(self.count);
+                            self.state = 0;
+                            return None;
+                        }
+                        self.state = if string::length(&self.word) > 3 { 5 } else { 6 };
+                        continue;
+                    }
+                    4 => {
+                        self.iterator0 = None;
+                        self.returned = Some(self.count);
+                        self.state = 0;
+                        return None;
+                    }
+                    5 => {
+                        self.state = 8;
+                        return Some(format!("{}!", self.word));
+                    }
+                    6 => {
+                        self.state = 9;
+                        return Some(self.word.clone());
+                    }
+                    7 => {
+                        self.count += 1.0;
+                        self.state = 2;
+                        continue;
+                    }
+                    8 => {
+                        self.state = 7;
+                        continue;
+                    }
+                    9 => {
+                        self.state = 7;
+                        continue;
+                    }
+                    10 => {
+                        self.state = 0;
+                        return None;
+                    }
+                    _ => return None,
+                }
+            }
+        }
+    }
+    impl Generator for ShoutGenerator {
+        type Return = f64;
+        fn take_return(&mut self) -> f64 {
+            self.returned.take().expect("Generator returned a value.")
+        }
+    }
+    Box::new(ShoutGenerator {
+        state: 1,
+        words,
+        stop,
+        count: Default::default(),
+        iterator0: None,
+        word: Default::default(),
+        returned: None,
+    })
+}
+#[allow(clippy::all)]
+fn summarize(words: Vec<String>) -> Box<dyn Generator<Item = String, Return = ()>> {
+    struct SummarizeGenerator {
+        state: usize,
+        words: Vec<String>,
+        iterator0: Option<Box<dyn Generator<Item = String, Return = f64>>>,
+        count: f64,
+    }
+    impl Iterator for SummarizeGenerator {
+        type Item = String;
+        fn next(&mut self) -> Option<String> {
+            loop {
+                match self.state {
+                    1 => {
+                        self.iterator0 = Some(shout(self.words.clone(), "stop".to_string()));
+                        self.state = 2;
+                        continue;
+                    }
+                    2 => {
+                        if let Some(value) = self
+                            .iterator0
+                            .as_mut()
+                            .expect("Generator is delegating.")
+                            .next()
+                        {
+                            return Some(value);
+                        }
+                        self.count = self
+                            .iterator0
+                            .take()
+                            .expect("Generator is delegating.")
+                            .take_return();
+                        self.state = 3;
+                        return Some(format!("shouted {} words", self.count.to_js_string()));
+                    }
+                    3 => {
+                        self.state = 0;
+                        return None;
+                    }
+                    _ => return None,
+                }
+            }
+        }
+    }
+    impl Generator for SummarizeGenerator {
+        type Return = ();
+        fn take_return(&mut self) {}
+    }
+    Box::new(SummarizeGenerator {
+        state: 1,
+        words,
+        iterator0: None,
+        count: Default::default(),
+    })
+}
+#[allow(clippy::all)]
+fn inorder(tree: Vec<f64>, index: f64) -> Box<dyn Generator<Item = f64, Return = ()>> {
+    struct InorderGenerator {
+        state: usize,
+        tree: Vec<f64>,
+        index: f64,
+        iterator0: Option<Box<dyn Generator<Item = f64, Return = ()>>>,
+        iterator1: Option<Box<dyn Generator<Item = f64, Return = ()>>>,
+    }
+    impl Iterator for InorderGenerator {
+        type Item = f64;
+        fn next(&mut self) -> Option<f64> {
+            loop {
+                match self.state {
+                    1 => {
+                        if self.index >= self.tree.len() as f64 {
+                            self.state = 0;
+                            return None;
+                        }
+                        self.iterator0 = Some(inorder(self.tree.clone(), 2.0 * self.index + 1.0));
+                        self.state = 2;
+                        continue;
+                    }
+                    2 => {
+                        if let Some(value) = self
+                            .iterator0
+                            .as_mut()
+                            .expect("Generator is delegating.")
+                            .next()
+                        {
+                            return Some(value);
+                        }
+                        self.iterator0 = None;
+                        self.state = 3;
+                        return Some(self.tree[self.index as usize]);
+                    }
+                    3 => {
+                        self.iterator1 = Some(inorder(self.tree.clone(), 2.0 * self.index + 2.0));
+                        self.state = 4;
+                        continue;
+                    }
+                    4 => {
+                        if let Some(value) = self
+                            .iterator1
+                            .as_mut()
+                            .expect("Generator is delegating.")
+                            .next()
+                        {
+                            return Some(value);
+                        }
+                        self.iterator1 = None;
+                        self.state = 0;
+                        return None;
+                    }
+                    _ => return None,
+                }
+            }
+        }
+    }
+    impl Generator for InorderGenerator {
+        type Return = ();
+        fn take_return(&mut self) {}
+    }
+    Box::new(InorderGenerator {
+        state: 1,
+        tree,
+        index,
+        iterator0: None,
+        iterator1: None,
+    })
+}
+#[allow(clippy::all)]
+fn flatten(groups: Vec<Vec<f64>>) -> Box<dyn Generator<Item = f64, Return = ()>> {
+    struct FlattenGenerator {
+        state: usize,
+        groups: Vec<Vec<f64>>,
+        iterator0: Option<Box<dyn Generator<Item = Vec<f64>, Return = ()>>>,
+        group: Vec<f64>,
+        iterator1: Option<Box<dyn Generator<Item = f64, Return = ()>>>,
+    }
+    impl Iterator for FlattenGenerator {
+        type Item = f64;
+        fn next(&mut self) -> Option<f64> {
+            loop {
+                match self.state {
+                    1 => {
+                        self.iterator0 = Some(Box::new(self.groups.clone().into_iter()));
+                        self.state = 2;
+                        continue;
+                    }
+                    2 => {
+                        match self.iterator0.as_mut().expect("Loop is iterating.").next() {
+                            Some(value) => {
+                                self.group = value;
+                                self.state = 3;
+                            }
+                            None => self.state = 4,
+                        }
+                        continue;
+                    }
+                    3 => {
+                        self.iterator1 = Some(Box::new(self.group.clone().into_iter()));
+                        self.state = 5;
+                        continue;
+                    }
+                    4 => {
+                        self.iterator0 = None;
+                        self.state = 0;
+                        return None;
+                    }
+                    5 => {
+                        if let Some(value) = self
+                            .iterator1
+                            .as_mut()
+                            .expect("Generator is delegating.")
+                            .next()
+                        {
+                            return Some(value);
+                        }
+                        self.iterator1 = None;
+                        self.state = 2;
+                        continue;
+                    }
+                    _ => return None,
+                }
+            }
+        }
+    }
+    impl Generator for FlattenGenerator {
+        type Return = ();
+        fn take_return(&mut self) {}
+    }
+    Box::new(FlattenGenerator {
+        state: 1,
+        groups,
+        iterator0: None,
+        group: Default::default(),
+        iterator1: None,
+    })
+}
+#[allow(clippy::all)]
+fn main() {
+    for i in range(0.0, 5.0, None) {
+        console.log(i);
+    }
+    for i in range(10.0, 0.0, None) {
+        console.log(i);
+    }
+    for n in fibonacci() {
+        if n > 50.0 {
+            break;
+        }
+        console.log(n);
+    }
+    let words = vec![
+        "hello".to_string(),
+        "big".to_string(),
+        "world".to_string(),
+        "stop".to_string(),
+        "ignored".to_string(),
+    ];
+    for word in summarize(words.clone()) {
+        console.log(&word);
+    }
+    let tree = vec![4.0, 2.0, 6.0, 1.0, 3.0, 5.0, 7.0];
+    for value in inorder(tree.clone(), 0.0) {
+        console.log(value);
+    }
+    let mut total = 0.0;
+    for value in flatten(vec![
+        vec![1.0, 2.0],
+        vec![3.0],
+        empty_array(),
+        vec![4.0, 5.0, 6.0],
+    ]) {
+        total += value;
+    }
+    console.log(total);
+}
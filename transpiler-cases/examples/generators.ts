@@ -0,0 +1,102 @@
+// Generator functions produce their values lazily, and `for...of` iterates over them.
+function* range(start: number, end: number, step: number = 1): Generator<number> {
+  for (let i = start; i < end; i += step) {
+    yield i;
+  }
+}
+
+for (const i of range(0, 5)) {
+  console.log(i);
+}
+
+// Ranges that are empty from the start yield nothing.
+for (const i of range(10, 0)) {
+  console.log(i);
+}
+
+// Generators can be infinite, as long as the loop over them stops.
+function* fibonacci(): Generator<number> {
+  let a = 0;
+  let b = 1;
+
+  while (true) {
+    yield a;
+
+    const next = a + b;
+    a = b;
+    b = next;
+  }
+}
+
+for (const n of fibonacci()) {
+  if (n > 50) {
+    break;
+  }
+
+  console.log(n);
+}
+
+// Generators can branch between `yield`s and return early with a value.
+function* shout(words: string[], stop: string): Generator<string, number> {
+  let count = 0;
+
+  for (const word of words) {
+    if (word === stop) {
+      return count;
+    }
+
+    if (word.length > 3) {
+      yield word + "!";
+    } else {
+      yield word;
+    }
+
+    count++;
+  }
+
+  return count;
+}
+
+// `yield*` delegates to another generator, and evaluates to the value it returns.
+function* summarize(words: string[]): Generator<string> {
+  const count = yield* shout(words, "stop");
+  yield "shouted " + count + " words";
+}
+
+const words = ["hello", "big", "world", "stop", "ignored"];
+
+for (const word of summarize(words)) {
+  console.log(word);
+}
+
+// Recursive generators walk trees, like this binary tree stored in an array.
+function* inorder(tree: number[], index: number): Generator<number> {
+  if (index >= tree.length) {
+    return;
+  }
+
+  yield* inorder(tree, 2 * index + 1);
+  yield tree[index];
+  yield* inorder(tree, 2 * index + 2);
+}
+
+const tree = [4, 2, 6, 1, 3, 5, 7];
+
+for (const value of inorder(tree, 0)) {
+  console.log(value);
+}
+
+// `yield*` also delegates to arrays.
+function* flatten(groups: number[][]): Generator<number> {
+  for (const group of groups) {
+    yield* group;
+  }
+}
+
+let total = 0;
+
+for (const value of flatten([[1, 2], [3], [], [4, 5, 6]])) {
+  total += value;
+}
+
+console.log(total);
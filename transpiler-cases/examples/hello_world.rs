@@ -1,4 +1,5 @@
 use ts_std::*;
+#[allow(clippy::all)]
 fn main() {
     console.log("Hello World!");
 }
@@ -0,0 +1,114 @@
+use ts_std::*;
+#[allow(clippy::all)]
+fn apply(f: impl Fn(f64) -> f64, value: f64) -> f64 {
+    return f(value);
+}
+#[allow(clippy::all)]
+fn describe(value: f64, format: impl Fn(f64) -> String) -> String {
+    return format!("value: {}", format(value));
+}
+#[allow(clippy::all)]
+fn square(x: f64) -> f64 {
+    return x * x;
+}
+#[allow(clippy::all)]
+fn repeat(times: f64, mut action: impl FnMut(f64)) {
+    if !f64::is_nan(times) {
+        for i in 0..f64::ceil(times) as i64 {
+            action(i as f64);
+        }
+    }
+}
+#[allow(clippy::all)]
+fn main() {
+    console.assert(apply(|x: f64| x * 2.0, 21.0) == 42.0);
+    console.assert(describe(3.0, |x: f64| format!("#{}", x.to_js_string())) == "value: #3");
+    console.assert(apply(square, 5.0) == 25.0);
+    let offset = 10;
+    let shift = |x: f64| x + offset as f64;
+    console.assert(shift(1.0) == 11.0);
+    console.assert(apply(&shift, 5.0) == 15.0);
+    let mut total = 0.0;
+    repeat(4.0, |i: f64| {
+        total += i;
+    });
+    console.assert(total == 6.0);
+    let mut calls = 0;
+    let mut increment = || {
+        calls += 1;
+    };
+    increment();
+    increment();
+    console.assert(calls == 2);
+    let steps = vec![
+        Rc::new(move |x: f64| x + 1.0) as Rc<dyn Fn(f64) -> f64>,
+        Rc::new(square) as Rc<dyn Fn(f64) -> f64>,
+        Rc::new(shift) as Rc<dyn Fn(f64) -> f64>,
+    ];
+    let mut result = 2.0;
+    for i in 0..steps.len() {
+        result = steps[i](result);
+    }
+    console.assert(result == 19.0);
+    console.assert(steps[1](4.0) == 16.0);
+    let mut pick = Rc::new(square) as Rc<dyn Fn(f64) -> f64>;
+    console.assert(pick(3.0) == 9.0);
+    pick = Rc::new(move |x: f64| x - 1.0) as Rc<dyn Fn(f64) -> f64>;
+    console.assert(pick(3.0) == 2.0);
+    let numbers = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+    let doubled = numbers.iter().copied().map(|n| n * 2.0).collect::<Vec<_>>();
+    console.assert(doubled[4] == 10.0);
+    let evens = numbers
+        .iter()
+        .copied()
+        .filter(|&n| n % 2.0 == 0.0)
+        .collect::<Vec<_>>();
+    console.assert(evens.len() == 2);
+    let sum = numbers.iter().copied().fold(0.0, |acc, n| acc + n);
+    console.assert(sum == 15.0);
+    let product = numbers
+        .iter()
+        .copied()
+        .reduce(|acc, n| acc * n)
+        .expect("Reduce of empty array with no initial value");
+    console.assert(product == 120.0);
+    console.assert(numbers.iter().copied().any(|n| n > 4.0));
+    console.assert(!numbers.iter().copied().all(|n| n > 1.0));
+    console.assert(strict_eq(&numbers.iter().copied().find(|&n| n > 3.0), &4.0));
+    let words = vec![
+        "apple".to_string(),
+        "kiwi".to_string(),
+        "banana".to_string(),
+    ];
+    let lengths = words
+        .iter()
+        .map(String::as_str)
+        .map(|word| string::length(word))
+        .collect::<Vec<_>>();
+    console.assert(lengths[2] == 6);
+    let long = words
+        .iter()
+        .map(String::as_str)
+        .filter(|&word| string::length(word) > 4)
+        .map(str::to_string)
+        .collect::<Vec<_>>();
+    console.assert(long.len() == 2 && long[1] == "banana");
+    let labels = words
+        .iter()
+        .map(String::as_str)
+        .enumerate()
+        .map(|(i, word)| format!("{}: {}", i.to_js_string(), word))
+        .collect::<Vec<_>>();
+    console.assert(labels[1] == "1: kiwi");
+    let squares = numbers
+        .iter()
+        .copied()
+        .map(|element| square(element))
+        .collect::<Vec<_>>();
+    console.assert(squares[2] == 9.0);
+    let mut count = 0.0;
+    numbers.iter().copied().for_each(|n| {
+        count += n;
+    });
+    console.assert(count == 15.0);
+}
@@ -0,0 +1,96 @@
+// Callbacks passed to functions are generic `impl Fn` parameters.
+function apply(f: (x: number) => number, value: number): number {
+  return f(value);
+}
+
+function describe(value: number, format: (x: number) => string): string {
+  return "value: " + format(value);
+}
+
+console.assert(apply((x) => x * 2, 21) === 42);
+console.assert(describe(3, (x) => "#" + x) === "value: #3");
+
+function square(x: number): number {
+  return x * x;
+}
+
+console.assert(apply(square, 5) === 25);
+
+// Closures bound to constants can be called and passed on.
+const offset = 10;
+const shift = (x: number): number => x + offset;
+
+console.assert(shift(1) === 11);
+console.assert(apply(shift, 5) === 15);
+
+// Callbacks that change captured bindings are `FnMut`.
+function repeat(times: number, action: (i: number) => void): void {
+  for (let i = 0; i < times; i++) {
+    action(i);
+  }
+}
+
+let total = 0;
+repeat(4, (i) => {
+  total += i;
+});
+console.assert(total === 6);
+
+let calls = 0;
+const increment = () => {
+  calls++;
+};
+increment();
+increment();
+console.assert(calls === 2);
+
+// Functions stored in arrays or reassigned bindings are shared as `Rc<dyn Fn>`.
+const steps: ((x: number) => number)[] = [(x) => x + 1, square, shift];
+let result = 2;
+for (let i = 0; i < steps.length; i++) {
+  result = steps[i](result);
+}
+console.assert(result === 19);
+console.assert(steps[1](4) === 16);
+
+let pick: (x: number) => number = square;
+console.assert(pick(3) === 9);
+pick = (x) => x - 1;
+console.assert(pick(3) === 2);
+
+// Array methods with callbacks become iterator adapters.
+const numbers = [1, 2, 3, 4, 5];
+const doubled = numbers.map((n) => n * 2);
+console.assert(doubled[4] === 10);
+
+const evens = numbers.filter((n) => n % 2 === 0);
+console.assert(evens.length === 2);
+
+const sum = numbers.reduce((acc, n) => acc + n, 0);
+console.assert(sum === 15);
+
+const product = numbers.reduce((acc, n) => acc * n);
+console.assert(product === 120);
+
+console.assert(numbers.some((n) => n > 4));
+console.assert(!numbers.every((n) => n > 1));
+console.assert(numbers.find((n) => n > 3) === 4);
+
+const words = ["apple", "kiwi", "banana"];
+const lengths = words.map((word) => word.length);
+console.assert(lengths[2] === 6);
+
+const long = words.filter((word) => word.length > 4);
+console.assert(long.length === 2 && long[1] === "banana");
+
+const labels = words.map((word, i) => i + ": " + word);
+console.assert(labels[1] === "1: kiwi");
+
+const squares = numbers.map(square);
+console.assert(squares[2] === 9);
+
+let count = 0;
+numbers.forEach((n) => {
+  count += n;
+});
+console.assert(count === 15);
@@ -0,0 +1,92 @@
+use ts_std::*;
+#[allow(clippy::all)]
+#[allow(dead_code)]
+#[derive(Clone, Debug)]
+struct Shipment {
+    weight: f64,
+    insured: Option<bool>,
+}
+impl OwnKeys for Shipment {
+    fn own_keys(&self) -> Vec<String> {
+        let mut keys = vec!["weight"];
+        if self.insured.is_some() {
+            keys.push("insured");
+        }
+        ordered_keys(keys)
+    }
+}
+impl HasProperty for Shipment {
+    fn has_property(&self, key: &str) -> bool {
+        match key {
+            "weight" => true,
+            "insured" => self.insured.is_some(),
+            _ => false,
+        }
+    }
+}
+impl Delete for Shipment {
+    fn delete(&mut self, key: &str) -> bool {
+        if key == "insured" {
+            self.insured = None;
+        }
+        true
+    }
+}
+#[allow(clippy::all)]
+fn contains(key: &str, value: &Shipment) -> bool {
+    return value.has_property(key);
+}
+#[allow(clippy::all)]
+#[allow(unused_variables)]
+fn total_number_array(values: &[f64]) -> f64 {
+    let mut sum = 0.0;
+    {
+        for value in values.iter().copied() {
+            sum += value;
+        }
+    };
+    return sum;
+}
+#[allow(clippy::all)]
+#[allow(unused_variables)]
+fn total_number_object(values: &Object<f64>) -> f64 {
+    let mut sum = 0.0;
+    {
+        for key in values.own_keys() {
+            sum += values[&key];
+        }
+    };
+    return sum;
+}
+#[allow(clippy::all)]
+fn main() {
+    let mut shipment = Shipment {
+        weight: 12.0,
+        insured: None,
+    };
+    console.assert(true);
+    console.assert(!(false));
+    console.assert(!(shipment.insured.is_some()));
+    shipment.insured = Some(true);
+    console.assert(shipment.insured.is_some());
+    console.assert(contains("weight", &shipment));
+    console.assert(contains("insured", &shipment));
+    console.assert(!contains("volume", &shipment));
+    let stock = Object::from([("apples", 3.0), ("pears", 5.0)]);
+    console.assert(stock.has_property("apples"));
+    console.assert(!(stock.has_property("plums")));
+    let sizes = vec![4.0, 8.0];
+    console.assert(sizes.has_property("1"));
+    console.assert(!(sizes.has_property("2")));
+    console.assert(sizes.has_property("length"));
+    console.assert(true);
+    console.assert(true);
+    console.assert(!(false));
+    console.assert(true);
+    let mut found: Option<Vec<f64>> = None;
+    console.assert(!(found.is_some()));
+    found = Some(vec![1.0, 2.0, 3.0]);
+    console.assert(found.is_some());
+    console.assert(total_number_array(&sizes) == 12.0);
+    console.assert(total_number_object(&stock) == 8.0);
+}
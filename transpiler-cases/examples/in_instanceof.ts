@@ -0,0 +1,57 @@
+// `in` is resolved from the declaration of an interface, where optional properties are only present while set.
+interface Shipment {
+  weight: number;
+  insured?: boolean;
+}
+const shipment: Shipment = { weight: 12 };
+console.assert("weight" in shipment);
+console.assert(!("volume" in shipment));
+console.assert(!("insured" in shipment));
+shipment.insured = true;
+console.assert("insured" in shipment);
+
+// Computed keys are looked up when the program runs.
+function contains(key: string, value: Shipment): boolean {
+  return key in value;
+}
+console.assert(contains("weight", shipment));
+console.assert(contains("insured", shipment));
+console.assert(!contains("volume", shipment));
+
+// Records have the keys they were given, and arrays their indices and `length`.
+const stock: Record<string, number> = { apples: 3, pears: 5 };
+console.assert("apples" in stock);
+console.assert(!("plums" in stock));
+const sizes = [4, 8];
+console.assert(1 in sizes);
+console.assert(!(2 in sizes));
+console.assert("length" in sizes);
+
+// `instanceof` is resolved from the types of values.
+console.assert(sizes instanceof Array);
+console.assert(sizes instanceof Object);
+console.assert(!(stock instanceof Array));
+console.assert(shipment instanceof Object);
+let found: number[] | undefined = undefined;
+console.assert(!(found instanceof Array));
+found = [1, 2, 3];
+console.assert(found instanceof Array);
+
+// So it also narrows the parameters of overloads.
+function total(values: number[]): number;
+function total(values: Record<string, number>): number;
+function total(values: number[] | Record<string, number>): number {
+  let sum = 0;
+  if (values instanceof Array) {
+    for (const value of values) {
+      sum += value;
+    }
+  } else {
+    for (const key in values) {
+      sum += values[key];
+    }
+  }
+  return sum;
+}
+console.assert(total(sizes) === 12);
+console.assert(total(stock) === 8);
@@ -0,0 +1,56 @@
+use ts_std::*;
+#[allow(clippy::all)]
+fn main() {
+    let scores = vec![3.0, 1.0, 4.0, 1.0, 5.0];
+    let mut total = 0.0;
+    for i in 0..scores.len() {
+        total += scores[i];
+    }
+    console.log(total);
+    let n = 4;
+    for i in (1..=n * 2).step_by(3) {
+        console.log(format!("step {}", i.to_js_string()));
+    }
+    for i in 0..n - 1 {
+        console.log(format!("pair {}", i.to_js_string()));
+    }
+    {
+        let mut i = 0;
+        while i < 10 {
+            if i == 2 {
+                i = 7;
+            }
+            console.log(format!("skip {}", i.to_js_string()));
+            i += 1;
+        }
+    };
+    let mut limit = 6;
+    {
+        let mut i = 0;
+        while i < limit {
+            limit = limit - 1;
+            console.log(format!("shrink {}", i.to_js_string()));
+            i += 1;
+        }
+    };
+    let remaining = Shared::new(5);
+    let consume = || {
+        remaining.set(remaining.get() - 2);
+    };
+    {
+        let mut i = 0;
+        while i < remaining.get() {
+            consume();
+            console.log(format!("consume {}", i.to_js_string()));
+            i += 1;
+        }
+    };
+    let temperatures = vec![21.0, 19.0, 24.0];
+    for i in 0..temperatures.len() {
+        console.log(temperatures[i]);
+    }
+    let word = "héllo";
+    for i in 0..string::length(word) {
+        console.log(format!("letter {}", i.to_js_string()));
+    }
+}
@@ -0,0 +1,52 @@
+// Counting loops with bounds the body can't change become `for` loops over a range.
+const scores = [3, 1, 4, 1, 5];
+let total = 0;
+for (let i = 0; i < scores.length; i++) {
+  total += scores[i];
+}
+console.log(total);
+
+// Identifier and expression bounds.
+const n = 4;
+for (let i = 1; i <= n * 2; i += 3) {
+  console.log("step " + i);
+}
+for (let i = 0; i < n - 1; i++) {
+  console.log("pair " + i);
+}
+
+// Loops whose variable is reassigned in the body stay `while` loops.
+for (let i = 0; i < 10; i++) {
+  if (i === 2) {
+    i = 7;
+  }
+  console.log("skip " + i);
+}
+
+// So do loops whose bound the body changes, directly or through a closure it calls.
+let limit = 6;
+for (let i = 0; i < limit; i++) {
+  limit = limit - 1;
+  console.log("shrink " + i);
+}
+
+let remaining = 5;
+const consume = () => {
+  remaining = remaining - 2;
+};
+for (let i = 0; i < remaining; i++) {
+  consume();
+  console.log("consume " + i);
+}
+
+// Reading elements and lengths doesn't change the array, even when they are passed on.
+const temperatures = [21, 19, 24];
+for (let i = 0; i < temperatures.length; i++) {
+  console.log(temperatures[i]);
+}
+
+// Strings count UTF-16 code units, not bytes.
+const word = "héllo";
+for (let i = 0; i < word.length; i++) {
+  console.log("letter " + i);
+}
@@ -0,0 +1,69 @@
+use ts_std::*;
+#[allow(clippy::all)]
+fn main() {
+    let mut count = 0;
+    let mut total = 0.0;
+    while count < 10 {
+        total += (count * 2) as f64;
+        count += 1;
+    }
+    console.assert(count == 10);
+    console.assert(total == 90.0);
+    let mut hash = 7;
+    let mut i = 0;
+    while i < 10 {
+        hash = number::bit_or((hash as i64 * 31 + i) as f64, 0.0) as i32;
+        i += 1;
+    }
+    console.assert(hash == 471272268);
+    let mut wrapped = number::bit_or(4294967295.0, 0.0) as i32;
+    wrapped = wrapped ^ 1;
+    console.assert(wrapped == -2);
+    wrapped = number::shr(wrapped as f64, 1.0) as i32;
+    console.assert(wrapped == -1);
+    let half = Math.floor(7.0 / 2.0) as i64;
+    console.assert(half == 3);
+    let low = Math.floor(-7.0 / 2.0) as i64;
+    console.assert(low == -4);
+    let nearest = Math.round(-2.5) as i64;
+    console.assert(nearest == -2);
+    let mut ratio = 1.0;
+    ratio = ratio / 4.0;
+    console.assert(ratio == 0.25);
+    let mut mixed = 3.0;
+    mixed += 0.5;
+    console.assert(mixed == 3.5);
+    let mut power = 1.0;
+    let mut steps = 0;
+    while steps < 70 {
+        power = power * 3.0;
+        steps += 1;
+    }
+    console.assert(power > 1000000000000000000000000000000000.0);
+    let divisor = 0;
+    let remainder = 5.0 % divisor as f64;
+    console.log(remainder);
+    let odd = count % 2;
+    console.assert(odd == 0);
+    let floored = Math.floor(0.0 / 0.0);
+    console.log(floored);
+    let zero = -0.0;
+    console.log(1.0 / zero);
+    let mut below = -6;
+    below += 1;
+    let multiple = below as f64 % 5.0;
+    console.log(1.0 / multiple);
+    let ceiled = Math.ceil(-0.5);
+    console.log(1.0 / ceiled);
+    let rounded = Math.round(-0.25);
+    console.log(1.0 / rounded);
+    let negated = -(count as f64) * 0.0;
+    console.log(1.0 / negated);
+    let tenfold = count * 10;
+    console.assert(tenfold == 100);
+    let scale = 2;
+    let scaled = half as f64 * 1.5 + scale as f64;
+    console.assert(scaled == 6.5);
+    console.assert(Math.sqrt(count - 1) == 3.0);
+    console.log(total);
+}
@@ -0,0 +1,82 @@
+// Loop counters count up from integer literals, but sums that grow on every iteration stay `f64`.
+let count = 0;
+let total = 0;
+while (count < 10) {
+  total += count * 2;
+  count++;
+}
+console.assert(count === 10);
+console.assert(total === 90);
+
+// `| 0` truncates to a signed 32-bit integer.
+let hash = 7;
+let i = 0;
+while (i < 10) {
+  hash = (hash * 31 + i) | 0;
+  i++;
+}
+console.assert(hash === 471272268);
+
+let wrapped = 4294967295 | 0;
+wrapped = wrapped ^ 1;
+console.assert(wrapped === -2);
+wrapped = wrapped >> 1;
+console.assert(wrapped === -1);
+
+// `Math.floor` outputs are integers.
+const half = Math.floor(7 / 2);
+console.assert(half === 3);
+const low = Math.floor(-7 / 2);
+console.assert(low === -4);
+const nearest = Math.round(-2.5);
+console.assert(nearest === -2);
+
+// Any non-integer definition keeps a binding `f64`.
+let ratio = 1;
+ratio = ratio / 4;
+console.assert(ratio === 0.25);
+
+let mixed = 3;
+mixed += 0.5;
+console.assert(mixed === 3.5);
+
+// Values that could overflow `i64`, or be NaN, stay `f64`.
+let power = 1;
+let steps = 0;
+while (steps < 70) {
+  power = power * 3;
+  steps++;
+}
+console.assert(power > 1e33);
+
+const divisor = 0;
+const remainder = 5 % divisor;
+console.log(remainder);
+const odd = count % 2;
+console.assert(odd === 0);
+
+const floored = Math.floor(0 / 0);
+console.log(floored);
+
+// Values that may be `-0` stay `f64` too, since `1 / -0` is `-Infinity`.
+const zero = -0;
+console.log(1 / zero);
+let below = -6;
+below += 1;
+const multiple = below % 5;
+console.log(1 / multiple);
+const ceiled = Math.ceil(-0.5);
+console.log(1 / ceiled);
+const rounded = Math.round(-0.25);
+console.log(1 / rounded);
+const negated = -count * 0;
+console.log(1 / negated);
+const tenfold = count * 10;
+console.assert(tenfold === 100);
+
+// Integers mix with floats.
+const scale = 2;
+const scaled = half * 1.5 + scale;
+console.assert(scaled === 6.5);
+console.assert(Math.sqrt(count - 1) === 3);
+console.log(total);
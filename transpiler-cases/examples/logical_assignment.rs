@@ -0,0 +1,104 @@
+use ts_std::*;
+#[allow(clippy::all)]
+fn main() {
+    let mut name = "".to_string();
+    if !name.truthy() {
+        name = "anonymous".to_string();
+    }
+    console.assert(name == "anonymous");
+    if !name.truthy() {
+        name = "unused".to_string();
+    }
+    console.assert(name == "anonymous");
+    if name.truthy() {
+        name = "known".to_string();
+    }
+    console.assert(name == "known");
+    let mut count = 0.0;
+    if count.truthy() {
+        count = 5.0;
+    }
+    console.assert(count == 0.0);
+    if !count.truthy() {
+        count = 2.0;
+    }
+    if count.truthy() {
+        count = count * 10.0;
+    }
+    console.assert(count == 20.0);
+    let mut enabled = true;
+    if !enabled {
+        enabled = false;
+    }
+    console.assert(enabled);
+    if enabled {
+        enabled = count > 20.0;
+    }
+    console.assert(!enabled);
+    let mut limit: Option<f64> = None;
+    console.assert(strict_eq(&limit, &undefined));
+    console
+        .assert(
+            (match limit {
+                Some(value) => value,
+                None => 10.0,
+            }) == 10.0,
+        );
+    if limit.is_none() {
+        limit = Some(3.0);
+    }
+    console.assert(strict_eq(&limit, &3.0));
+    if limit.is_none() {
+        limit = Some(4.0);
+    }
+    console.assert(strict_eq(&limit, &3.0));
+    console
+        .assert(
+            (match limit {
+                Some(value) => value,
+                None => 10.0,
+            }) == 3.0,
+        );
+    let mut label = None;
+    let fallback: Option<&str> = None;
+    console
+        .assert(
+            strict_eq(
+                &(match label {
+                    None => fallback,
+                    value => value,
+                }),
+                &undefined,
+            ),
+        );
+    if label.is_none() {
+        label = Some("set");
+    }
+    console.assert(strict_eq(&label, &"set"));
+    console
+        .assert(
+            (match match label {
+                None => fallback,
+                value => value,
+            } {
+                Some(value) => value,
+                None => "none",
+            }) == "set",
+        );
+    console.assert(strict_eq(&("default"), &"default"));
+    console.assert((count) == 20.0);
+    let mut retries: Option<f64> = None;
+    console.assert(type_of(&retries) == "undefined");
+    if retries.is_none() {
+        retries = Some(5.0);
+    }
+    console.assert(strict_eq(&retries, &5.0));
+    let missing: Option<bool> = None;
+    console
+        .assert(
+            match missing {
+                Some(value) => value,
+                None => true,
+            },
+        );
+}
@@ -0,0 +1,48 @@
+// `||=` and `&&=` assign depending on the truthiness of the target.
+let name = "";
+name ||= "anonymous";
+console.assert(name === "anonymous");
+name ||= "unused";
+console.assert(name === "anonymous");
+name &&= "known";
+console.assert(name === "known");
+
+let count = 0;
+count &&= 5;
+console.assert(count === 0);
+count ||= 2;
+count &&= count * 10;
+console.assert(count === 20);
+
+let enabled = true;
+enabled ||= false;
+console.assert(enabled);
+enabled &&= count > 20;
+console.assert(!enabled);
+
+// `??=` and `??` work on optional values.
+let limit: number | undefined = undefined;
+console.assert(limit === undefined);
+console.assert((limit ?? 10) === 10);
+limit ??= 3;
+console.assert(limit === 3);
+limit ??= 4;
+console.assert(limit === 3);
+console.assert((limit ?? 10) === 3);
+
+let label: string | null = null;
+const fallback: string | undefined = undefined;
+console.assert((label ?? fallback) === undefined);
+label ??= "set";
+console.assert(label === "set");
+console.assert((label ?? fallback ?? "none") === "set");
+console.assert((null ?? "default") === "default");
+console.assert((count ?? 1) === 20);
+
+// Optional bindings keep their declared type while they are unset.
+let retries: number | undefined = undefined;
+console.assert(typeof retries === "undefined");
+retries ??= 5;
+console.assert(retries === 5);
+const missing: boolean | undefined = undefined;
+console.assert(missing ?? true);
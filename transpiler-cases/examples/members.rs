@@ -0,0 +1,47 @@
+use ts_std::*;
+#[allow(clippy::all)]
+#[allow(dead_code)]
+#[derive(Clone, Debug)]
+struct Reading {
+    sensor: String,
+    value: f64,
+}
+impl OwnKeys for Reading {
+    fn own_keys(&self) -> Vec<String> {
+        ordered_keys(["sensor", "value"])
+    }
+}
+impl HasProperty for Reading {
+    fn has_property(&self, key: &str) -> bool {
+        matches!(key, "sensor" | "value")
+    }
+}
+#[allow(clippy::all)]
+fn main() {
+    let word = "héllo";
+    console.assert(string::length(word) == 5);
+    console.assert(string::length("😀") == 2);
+    let empty = "";
+    console.assert(string::length(empty) == 0);
+    console.assert(Math.floor(2.5) == 2.0);
+    console.log(word);
+    console.assert(Math.abs(Math.floor(-2.5)) == 3.0);
+    console.assert(Math.sqrt(string::length(word) as i64 - 1) == 2.0);
+    let mut queue = vec![3.0, 1.0];
+    queue.push(2.0);
+    queue.reverse();
+    console.assert(queue[0] == 2.0);
+    console.assert(queue.len() == 3);
+    let doubled = queue
+        .iter()
+        .copied()
+        .map(|value| value * 2.0)
+        .collect::<Vec<_>>();
+    console.assert(doubled[2] == 6.0);
+    let reading = Reading {
+        sensor: "thermometer".to_string(),
+        value: 21.5,
+    };
+    console.assert(reading.value == 21.5);
+    console.assert(string::length(&reading.sensor) == 11);
+}
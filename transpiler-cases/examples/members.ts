@@ -0,0 +1,32 @@
+// String lengths count UTF-16 code units.
+const word = "héllo";
+console.assert(word.length === 5);
+console.assert("😀".length === 2);
+const empty = "";
+console.assert(empty.length === 0);
+
+// Computed members with literal keys are the same as named ones.
+console.assert(Math["floor"](2.5) === 2);
+console["log"](word);
+
+// Calls can be nested in arguments and receivers.
+console.assert(Math.abs(Math.floor(-2.5)) === 3);
+console.assert(Math.sqrt(word.length - 1) === 2);
+
+// Methods can change constant arrays in place, while other methods only read them.
+const queue = [3, 1];
+queue.push(2);
+queue.reverse();
+console.assert(queue[0] === 2);
+console.assert(queue.length === 3);
+const doubled = queue.map((value) => value * 2);
+console.assert(doubled[2] === 6);
+
+// Interfaces give objects with properties of different types their struct.
+interface Reading {
+  sensor: string;
+  value: number;
+}
+const reading: Reading = { sensor: "thermometer", value: 21.5 };
+console.assert(reading.value === 21.5);
+console.assert(reading.sensor.length === 11);
@@ -0,0 +1,5 @@
+use ts_std::*;
+#[allow(clippy::all)]
+pub fn init_module() {
+    console.log("banner");
+}
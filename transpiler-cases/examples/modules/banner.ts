@@ -0,0 +1,2 @@
+// Top-level code of an imported module runs in its `init_module`, which `main` calls once before its own code.
+console.log("banner");
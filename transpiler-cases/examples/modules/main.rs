@@ -0,0 +1,27 @@
+mod banner;
+mod math;
+mod registry;
+mod shapes;
+mod util;
+use crate::math::{add, default as clamp, multiply as times, sq, square, TAU};
+use crate::registry::register;
+use crate::shapes::{area, circumference, describe};
+use crate::util::text::shout;
+use ts_std::*;
+#[allow(clippy::all)]
+fn main() {
+    run_main(async {
+        crate::banner::init_module();
+        crate::registry::init_module();
+        console.log(add(1.0, 2.0));
+        console.log(times(3.0, 4.0));
+        console.log(square(5.0) == sq(5.0));
+        console.log(clamp(15.0, 0.0, 10.0));
+        console.log(TAU);
+        console.log(circumference(1.0));
+        console.log(area(2.0));
+        console.log(describe(3.0));
+        console.log(Promise::from_future(shout("modules".to_string())).await);
+        console.log(register("modules"));
+    });
+}
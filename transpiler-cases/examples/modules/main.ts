@@ -0,0 +1,19 @@
+// Imports of other modules become `use` declarations of the Rust modules they are transpiled to.
+import clamp, { add, multiply as times, sq, square, TAU } from "./math.js";
+import { area, circumference, describe } from "./shapes/index.js";
+import { type Shape } from "./types.js";
+import type { Shape as Circle } from "./shapes/index.js";
+import { shout } from "./util/text.js";
+import { register } from "./registry.js";
+import "./banner.js";
+
+console.log(add(1, 2));
+console.log(times(3, 4));
+console.log(square(5) === sq(5));
+console.log(clamp(15, 0, 10));
+console.log(TAU);
+console.log(circumference(1));
+console.log(area(2));
+console.log(describe(3));
+console.log(await shout("modules"));
+console.log(register("modules"));
@@ -0,0 +1,32 @@
+#[allow(unused_imports)]
+use ts_std::*;
+#[allow(clippy::all)]
+#[allow(non_upper_case_globals)]
+pub const TAU: f64 = 6.28;
+#[allow(clippy::all)]
+#[allow(non_upper_case_globals)]
+pub const UNIT: &str = "cm";
+#[allow(clippy::all)]
+pub fn add(a: f64, b: f64) -> f64 {
+    return a + b;
+}
+#[allow(clippy::all)]
+pub fn multiply(a: f64, b: f64) -> f64 {
+    return a * b;
+}
+#[allow(clippy::all)]
+pub fn square(x: f64) -> f64 {
+    return multiply(x, x);
+}
+pub use self::square as sq;
+#[allow(clippy::all)]
+pub fn clamp(value: f64, min: f64, max: f64) -> f64 {
+    if value < min {
+        return min;
+    }
+    if value > max {
+        return max;
+    }
+    return value;
+}
+pub use self::clamp as default;
@@ -0,0 +1,29 @@
+// Exported constants become `const` items, since only the crate root has a `main` for bindings.
+export const TAU = 6.28;
+export const UNIT = "cm";
+
+export function add(a: number, b: number): number {
+  return a + b;
+}
+
+export function multiply(a: number, b: number): number {
+  return a * b;
+}
+
+function square(x: number): number {
+  return multiply(x, x);
+}
+
+// Local bindings can be exported by another name.
+export { square, square as sq };
+
+// The default export is exported as `default`.
+export default function clamp(value: number, min: number, max: number): number {
+  if (value < min) {
+    return min;
+  }
+  if (value > max) {
+    return max;
+  }
+  return value;
+}
@@ -0,0 +1,12 @@
+use crate::math::add;
+use ts_std::*;
+#[allow(clippy::all)]
+pub fn register(name: &str) -> String {
+    return format!("registered {}", name);
+}
+#[allow(clippy::all)]
+pub fn init_module() {
+    let mut total = add(1.0, 2.0);
+    total += 3.0;
+    console.log(total);
+}
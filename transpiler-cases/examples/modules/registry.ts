@@ -0,0 +1,11 @@
+// The modules a module imports run first, including those imported only for their side effects.
+import "./banner.js";
+import { add } from "./math.js";
+
+let total = add(1, 2);
+total += 3;
+console.log(total);
+
+export function register(name: string): string {
+  return "registered " + name;
+}
@@ -0,0 +1,5 @@
+pub mod circle;
+pub use crate::shapes::circle::default as area;
+pub use crate::shapes::circle::{circumference, describe};
+#[allow(unused_imports)]
+use ts_std::*;
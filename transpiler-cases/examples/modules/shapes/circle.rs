@@ -0,0 +1,16 @@
+use crate::math::{multiply, TAU, UNIT};
+#[allow(unused_imports)]
+use ts_std::*;
+#[allow(clippy::all)]
+pub fn circumference(radius: f64) -> f64 {
+    return multiply(TAU, radius);
+}
+#[allow(clippy::all)]
+pub fn describe(radius: f64) -> String {
+    return format!("circle of radius {}{}", radius.to_js_string(), UNIT);
+}
+#[allow(clippy::all)]
+pub fn area(radius: f64) -> f64 {
+    return (TAU / 2.0) * radius * radius;
+}
+pub use self::area as default;
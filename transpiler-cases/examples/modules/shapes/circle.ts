@@ -0,0 +1,14 @@
+import { multiply, TAU, UNIT } from "../math.js";
+import type { Shape } from "../types.js";
+
+export function circumference(radius: number): number {
+  return multiply(TAU, radius);
+}
+
+export function describe(radius: number): string {
+  return "circle of radius " + radius + UNIT;
+}
+
+export default function area(radius: number): number {
+  return (TAU / 2) * radius * radius;
+}
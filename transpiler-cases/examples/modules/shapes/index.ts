@@ -0,0 +1,4 @@
+// `export *` re-exports everything but the default export, which can be re-exported by name.
+export * from "./circle.js";
+export { default as area } from "./circle.js";
+export type { Shape } from "../types.js";
@@ -0,0 +1,4 @@
+// Only imported as types, so it isn't transpiled.
+export interface Shape {
+  radius: number;
+}
@@ -0,0 +1,6 @@
+#[allow(unused_imports)]
+use ts_std::*;
+#[allow(clippy::all)]
+pub async fn shout(text: String) -> String {
+    return format!("{}!", text);
+}
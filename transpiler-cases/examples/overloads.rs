@@ -0,0 +1,85 @@
+use ts_std::*;
+#[allow(clippy::all)]
+#[allow(unused_variables)]
+fn describe_string(value: &str) -> String {
+    let verbose: Option<bool> = None;
+    let verbose = match verbose {
+        Some(verbose) => verbose,
+        None => false,
+    };
+    {
+        return format!("string of length {}", string::length(value).to_js_string());
+    };
+}
+#[allow(clippy::all)]
+#[allow(unused_variables)]
+fn describe_number(value: f64) -> String {
+    let verbose: Option<bool> = None;
+    let verbose = match verbose {
+        Some(verbose) => verbose,
+        None => false,
+    };
+    {
+        return if value < 0.0 {
+            "negative number".to_string()
+        } else {
+            format!("number {}", value.to_js_string())
+        };
+    };
+}
+#[allow(clippy::all)]
+#[allow(unused_variables)]
+fn describe_boolean_boolean(value: bool, verbose: Option<bool>) -> String {
+    let verbose = match verbose {
+        Some(verbose) => verbose,
+        None => false,
+    };
+    return if verbose {
+        format!("the boolean {}", value.to_js_string())
+    } else {
+        "boolean".to_string()
+    };
+}
+#[allow(clippy::all)]
+#[allow(unused_variables)]
+fn area_number(width: f64) -> f64 {
+    let height: Option<f64> = None;
+    return width
+        * (match height {
+            Some(value) => value,
+            None => width,
+        });
+}
+#[allow(clippy::all)]
+#[allow(unused_variables)]
+fn area_number_number(width: f64, height: f64) -> f64 {
+    return width * (height);
+}
+#[allow(clippy::all)]
+#[allow(unused_variables)]
+fn double_number(value: f64) -> f64 {
+    return value * 2.0;
+}
+#[allow(clippy::all)]
+#[allow(unused_variables)]
+fn double_string(value: &str) -> String {
+    return format!("{}{}", value, value);
+}
+#[allow(clippy::all)]
+fn main() {
+    console.assert(describe_string("abc") == "string of length 3");
+    console.assert(describe_number(-1.0) == "negative number");
+    console.assert(describe_number(42.0) == "number 42");
+    console.assert(describe_boolean_boolean(true, None) == "boolean");
+    console.assert(describe_boolean_boolean(false, Some(true)) == "the boolean false");
+    console.assert(area_number(3.0) == 9.0);
+    console.assert(area_number_number(2.0, 5.0) == 10.0);
+    console.assert(double_number(4.0) == 8.0);
+    console.assert(double_string("ab") == "abab");
+    let words = vec!["x".to_string(), "yz".to_string()];
+    let mut total = 0.0;
+    for i in 0..words.len() {
+        total += string::length(&describe_string(&words[i])) as f64;
+    }
+    console.assert(total == 36.0);
+}
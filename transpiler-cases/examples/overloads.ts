@@ -0,0 +1,45 @@
+// Overloads are resolved by the types of the arguments.
+function describe(value: string): string;
+function describe(value: number): string;
+function describe(value: boolean, verbose?: boolean): string;
+function describe(value: string | number | boolean, verbose = false): string {
+  if (typeof value === "string") {
+    return "string of length " + value.length;
+  } else if (typeof value === "number") {
+    return value < 0 ? "negative number" : "number " + value;
+  }
+  return verbose ? "the boolean " + value : "boolean";
+}
+
+console.assert(describe("abc") === "string of length 3");
+console.assert(describe(-1) === "negative number");
+console.assert(describe(42) === "number 42");
+console.assert(describe(true) === "boolean");
+console.assert(describe(false, true) === "the boolean false");
+
+// Overloads can differ in the number of parameters.
+function area(side: number): number;
+function area(width: number, height: number): number;
+function area(width: number, height?: number): number {
+  return width * (height ?? width);
+}
+
+console.assert(area(3) === 9);
+console.assert(area(2, 5) === 10);
+
+// Overloads can return different types.
+function double(value: number): number;
+function double(value: string): string;
+function double(value: number | string): number | string {
+  return typeof value === "number" ? value * 2 : value + value;
+}
+
+console.assert(double(4) === 8);
+console.assert(double("ab") === "abab");
+
+const words = ["x", "yz"];
+let total = 0;
+for (let i = 0; i < words.length; i++) {
+  total += describe(words[i]).length;
+}
+console.assert(total === 36);
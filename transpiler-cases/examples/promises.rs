@@ -0,0 +1,88 @@
+use ts_std::*;
+#[allow(clippy::all)]
+fn say(message: &str) {
+    console.log(message);
+}
+#[allow(clippy::all)]
+async fn square(x: f64) -> f64 {
+    return x * x;
+}
+#[allow(clippy::all)]
+async fn task(name: String) {
+    console.log(format!("{} 1", name));
+    Promise::resolve(null).await;
+    console.log(format!("{} 2", name));
+    Promise::resolve(null).await;
+    console.log(format!("{} 3", name));
+}
+#[allow(clippy::all)]
+fn main() {
+    run_main(async {
+        console.log("start");
+        let ready = Promise::resolve(1.0);
+        ready.then(move |value| console.log(format!("then {}", value.to_js_string())));
+        Promise::resolve(()).then(move |_| console.log("then void"));
+        say("sync");
+        let chained = ready
+            .then(move |value| value + 1.0)
+            .then(move |value| value * 10.0)
+            .and_then(move |value| Promise::resolve(value + 5.0));
+        console.assert((chained.clone().await) == 25.0);
+        let failing = Promise::<f64>::reject("boom");
+        let recovered = failing
+            .then(move |value| value + 1.0)
+            .catch(move |reason| string::length(&reason) as f64);
+        console.assert((recovered.clone().await) == 4.0);
+        let cleaned = Promise::resolve("done".to_string()).finally(move || {
+            console.log("finally");
+        });
+        console.assert((cleaned.clone().await) == "done");
+        let made = Promise::new(|resolve, reject| {
+            console.log("executor");
+            resolve(42.0);
+            reject("ignored");
+        });
+        console.assert((made.clone().await) == 42.0);
+        let signal = Promise::new_void(|resolve, _| {
+            resolve();
+        });
+        signal.clone().await;
+        let squares = Promise::all(vec![
+            Promise::from_future(square(1.0)),
+            Promise::from_future(square(2.0)),
+            Promise::from_future(square(3.0)),
+        ])
+        .await;
+        console.assert(squares.len() == 3 && squares[2] == 9.0);
+        let first = Promise::race(vec![
+            Promise::from_future(square(4.0)),
+            Promise::resolve(5.0),
+        ])
+        .await;
+        console.assert(first == 16.0);
+        let any = Promise::any(vec![
+            Promise::reject("no"),
+            Promise::from_future(square(6.0)),
+        ])
+        .await;
+        console.assert(strict_eq(&any, &36.0));
+        let outcomes = Promise::all_settled(vec![
+            Promise::from_future(square(7.0)),
+            Promise::reject("nope"),
+        ])
+        .await;
+        console.assert(outcomes.len() == 2);
+        console.assert(
+            strict_eq(&outcomes[0].status, &"fulfilled")
+                && strict_eq(&outcomes[1].status, &"rejected"),
+        );
+        let a = Promise::from_future(task("a".to_string()));
+        let b = Promise::from_future(task("b".to_string()));
+        Promise::resolve(())
+            .then(move |_| console.log("t 1"))
+            .then(move |_| console.log("t 2"));
+        a.clone().await;
+        b.clone().await;
+        console.log("end");
+    });
+}
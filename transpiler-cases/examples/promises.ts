@@ -0,0 +1,73 @@
+// Promise callbacks run in microtasks, so they log in the same order as in Node.
+console.log("start");
+
+const ready = Promise.resolve(1);
+ready.then((value) => console.log("then " + value));
+Promise.resolve().then(() => console.log("then void"));
+say("sync");
+
+function say(message: string): void {
+  console.log(message);
+}
+
+// `then` chains map the value, and callbacks that return a promise are flattened.
+const chained = ready
+  .then((value) => value + 1)
+  .then((value) => value * 10)
+  .then((value) => Promise.resolve(value + 5));
+console.assert((await chained) === 25);
+
+// Rejections skip `then` callbacks until a `catch` handles them.
+const failing: Promise<number> = Promise.reject("boom");
+const recovered = failing.then((value) => value + 1).catch((reason) => reason.length);
+console.assert((await recovered) === 4);
+
+const cleaned = Promise.resolve("done").finally(() => console.log("finally"));
+console.assert((await cleaned) === "done");
+
+// `new Promise` calls its executor right away with `resolve` and `reject`.
+const made = new Promise<number>((resolve, reject) => {
+  console.log("executor");
+  resolve(42);
+  reject("ignored");
+});
+console.assert((await made) === 42);
+
+const signal = new Promise<void>((resolve) => resolve());
+await signal;
+
+// Combinators over arrays of promises.
+async function square(x: number): Promise<number> {
+  return x * x;
+}
+
+const squares = await Promise.all([square(1), square(2), square(3)]);
+console.assert(squares.length === 3 && squares[2] === 9);
+
+const first = await Promise.race([square(4), Promise.resolve(5)]);
+console.assert(first === 16);
+
+const any = await Promise.any([Promise.reject("no"), square(6)]);
+console.assert(any === 36);
+
+const outcomes = await Promise.allSettled([square(7), Promise.reject("nope")]);
+console.assert(outcomes.length === 2);
+console.assert(outcomes[0].status === "fulfilled" && outcomes[1].status === "rejected");
+
+// Interleaving of async functions and reactions.
+async function task(name: string): Promise<void> {
+  console.log(name + " 1");
+  await null;
+  console.log(name + " 2");
+  await null;
+  console.log(name + " 3");
+}
+
+const a = task("a");
+const b = task("b");
+Promise.resolve()
+  .then(() => console.log("t 1"))
+  .then(() => console.log("t 2"));
+await a;
+await b;
+console.log("end");
@@ -0,0 +1,48 @@
+use ts_std::*;
+#[allow(clippy::all)]
+fn sum(values: &[f64]) -> f64 {
+    let mut total = 0.0;
+    for i in 0..values.len() {
+        total += values[i];
+    }
+    return total;
+}
+#[allow(clippy::all)]
+fn describe(label: &str, values: &[f64]) -> String {
+    return format!(
+        "{}: {} values, total {}",
+        label,
+        values.len().to_js_string(),
+        sum(&values).to_js_string()
+    );
+}
+#[allow(clippy::all)]
+fn area(width: f64, height: f64) -> f64 {
+    return width * height;
+}
+#[allow(clippy::all)]
+fn main() {
+    console.assert(sum(&[]) == 0.0);
+    console.assert(sum(&[1.0, 2.0, 3.0]) == 6.0);
+    console.assert(describe("none", &[]) == "none: 0 values, total 0");
+    let scores = vec![3.0, 1.0, 4.0, 1.0, 5.0];
+    console.assert(sum(&scores) == 14.0);
+    console.assert(sum(&[&[10.0][..], &scores[..], &[20.0][..]].concat()) == 44.0);
+    console.assert(describe("scores", &scores) == "scores: 5 values, total 14");
+    let size = vec![3.0, 4.0];
+    console.assert(area(size[0], size[1]) == 12.0);
+    console.assert(area(2.0, 5.0) == 10.0);
+    let more = [&scores[..], &[9.0, 2.0][..]].concat();
+    console.assert(more.len() == 7);
+    console.assert(sum(&more) == 25.0);
+    console.assert(scores.iter().copied().fold(f64::NEG_INFINITY, number::max) == 5.0);
+    console.assert(
+        [&scores[..], &[0.5][..]]
+            .concat()
+            .into_iter()
+            .fold(f64::INFINITY, number::min)
+            == 0.5,
+    );
+    console.assert(number::max(number::max(1.0, 7.0), 3.0) == 7.0);
+    console.assert(f64::NEG_INFINITY == -Infinity);
+}
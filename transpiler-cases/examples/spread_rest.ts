@@ -0,0 +1,42 @@
+// Rest parameters collect the remaining arguments into a slice.
+function sum(...values: number[]): number {
+  let total = 0;
+  for (let i = 0; i < values.length; i++) {
+    total += values[i];
+  }
+  return total;
+}
+
+function describe(label: string, ...values: number[]): string {
+  return label + ": " + values.length + " values, total " + sum(...values);
+}
+
+console.assert(sum() === 0);
+console.assert(sum(1, 2, 3) === 6);
+console.assert(describe("none") === "none: 0 values, total 0");
+
+// Arrays spread into rest parameters are passed through.
+const scores = [3, 1, 4, 1, 5];
+console.assert(sum(...scores) === 14);
+console.assert(sum(10, ...scores, 20) === 44);
+console.assert(describe("scores", ...scores) === "scores: 5 values, total 14");
+
+// Arrays of known length spread into fixed parameters are expanded.
+function area(width: number, height: number): number {
+  return width * height;
+}
+
+const size = [3, 4];
+console.assert(area(...size) === 12);
+console.assert(area(...[2, 5]) === 10);
+
+// Arrays can be spread into other arrays.
+const more = [...scores, 9, 2];
+console.assert(more.length === 7);
+console.assert(sum(...more) === 25);
+
+// `Math.max` and `Math.min` fold over spread arrays.
+console.assert(Math.max(...scores) === 5);
+console.assert(Math.min(...scores, 0.5) === 0.5);
+console.assert(Math.max(1, 7, 3) === 7);
+console.assert(Math.max() === -Infinity);
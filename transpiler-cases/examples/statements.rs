@@ -25,7 +25,7 @@ fn main() {
         if !(d >= 0) {
             break;
         }
-    }
+    };
     console.log("for");
     for i in (0..=10).rev().step_by(2) {
         console.log(i);
@@ -0,0 +1,41 @@
+use ts_std::*;
+#[allow(clippy::all)]
+fn main() {
+    let greeting = "Hello";
+    let name = "world";
+    let message = format!("{}, {}!", greeting, name);
+    console.assert(message == "Hello, world!");
+    console.log(&message);
+    let count = 3;
+    console.assert(format!("count: {}", count.to_js_string()) == "count: 3");
+    console.assert(format!("half: {}", 0.5.to_js_string()) == "half: 0.5");
+    console.assert(format!("{}3", (1.0 + 2.0).to_js_string()) == "33");
+    console.assert(format!("1{}", (2.0 + 3.0).to_js_string()) == "15");
+    console.assert(format!("{}", 1000000000000000000000.0.to_js_string()) == "1e+21");
+    console.assert(format!("{}", 0.00000015.to_js_string()) == "1.5e-7");
+    console.assert(format!("{}", (-0.0).to_js_string()) == "0");
+    console.assert(format!("{}", 123456789012.0.to_js_string()) == "123456789012");
+    console.assert(format!("flag: {}", (count > 2).to_js_string()) == "flag: true");
+    console.assert(format!("{{braces}}{}", count.to_js_string()) == "{braces}3");
+    let ratio = 0.1 + 0.2;
+    console.assert(format!("{}", ratio.to_js_string()) == "0.30000000000000004");
+    console.assert(
+        format!("{}", NaN.to_js_string()) == "NaN"
+            && format!("{}", (-Infinity).to_js_string()) == "-Infinity",
+    );
+    let mut log = "start".to_string();
+    log.push_str("; ");
+    log.push_str(&count.to_js_string());
+    log.push_str(&format!("; {}", name));
+    console.assert(log == "start; 3; world");
+    log = greeting.to_string();
+    log.push_str("!");
+    console.assert(log == "Hello!");
+    console.assert(greeting == "Hello");
+    console.log(&log);
+    let snapshot = log.clone();
+    log.push_str("!");
+    console.assert(snapshot == "Hello!");
+    console.assert(string::length(&log) == 7);
+    console.log(&log);
+}
@@ -0,0 +1,41 @@
+// Strings that are never reassigned stay borrowed.
+const greeting = "Hello";
+const name = "world";
+
+// `+` on strings concatenates, converting other operands like `String(x)`.
+const message = greeting + ", " + name + "!";
+console.assert(message === "Hello, world!");
+console.log(message);
+const count = 3;
+console.assert("count: " + count === "count: 3");
+console.assert("half: " + 0.5 === "half: 0.5");
+console.assert(1 + 2 + "3" === "33");
+console.assert("1" + (2 + 3) === "15");
+console.assert("" + 1e21 === "1e+21");
+console.assert("" + 1.5e-7 === "1.5e-7");
+console.assert("" + -0 === "0");
+console.assert("" + 123456789012 === "123456789012");
+console.assert("flag: " + (count > 2) === "flag: true");
+console.assert("{braces}" + count === "{braces}3");
+const ratio = 0.1 + 0.2;
+console.assert("" + ratio === "0.30000000000000004");
+console.assert("" + NaN === "NaN" && "" + -Infinity === "-Infinity");
+
+// Reassigned strings own their value, so they can be appended to.
+let log = "start";
+log += "; ";
+log += count;
+log += "; " + name;
+console.assert(log === "start; 3; world");
+log = greeting;
+log += "!";
+console.assert(log === "Hello!");
+console.assert(greeting === "Hello");
+console.log(log);
+
+// Copies of owned strings are independent.
+const snapshot = log;
+log += "!";
+console.assert(snapshot === "Hello!");
+console.assert(log.length === 7);
+console.log(log);
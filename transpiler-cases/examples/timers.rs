@@ -0,0 +1,101 @@
+use ts_std::*;
+#[allow(clippy::all)]
+fn sleep(ms: f64) -> Promise<()> {
+    return Promise::new_void(|resolve, _| {
+        set_timeout(
+            {
+                let resolve = resolve.clone();
+                move || resolve()
+            },
+            ms,
+        );
+    });
+}
+#[allow(clippy::all)]
+fn main() {
+    run_main(async {
+        console.log("start");
+        set_timeout(
+            move || {
+                console.log("timeout 20");
+            },
+            20.0,
+        );
+        set_timeout(
+            move || {
+                console.log("timeout 0");
+            },
+            0.0,
+        );
+        set_timeout(
+            move || {
+                console.log("timeout 10");
+            },
+            10.0,
+        );
+        queue_microtask(move || {
+            console.log("microtask");
+        });
+        Promise::resolve(()).then(move |_| console.log("promise"));
+        set_timeout(
+            move || {
+                console.log("timeout 5");
+                queue_microtask(move || {
+                    console.log("microtask from timeout 5");
+                });
+            },
+            5.0,
+        );
+        set_timeout(
+            move || {
+                console.log("timeout 5 again");
+            },
+            5.0,
+        );
+        let cancelled = set_timeout(
+            move || {
+                console.log("cancelled");
+            },
+            1.0,
+        );
+        clear_timeout(cancelled);
+        let interval = set_interval(
+            move || {
+                console.log("tick");
+            },
+            30.0,
+        );
+        set_timeout(
+            move || {
+                clear_interval(interval);
+            },
+            100.0,
+        );
+        let count = Shared::new(0);
+        #[allow(unused_variables)]
+        let counter = {
+            let counter = Shared::default();
+            counter.set(set_interval(
+                {
+                    let count = count.clone();
+                    let counter = counter.clone();
+                    move || {
+                        count.set({
+                            let mut count = count.get();
+                            count += 1;
+                            count
+                        });
+                        console.log(count.get());
+                        if count.get() == 3 {
+                            clear_interval(counter.get());
+                        }
+                    }
+                },
+                10.0,
+            ));
+            counter
+        };
+        sleep(50.0).await;
+        console.log("slept");
+    });
+}
@@ -0,0 +1,41 @@
+// Timers run on the event loop after the top-level code, in the order they are due.
+console.log("start");
+
+setTimeout(() => console.log("timeout 20"), 20);
+setTimeout(() => console.log("timeout 0"));
+setTimeout(() => console.log("timeout 10"), 10);
+
+// Microtasks run before any timer.
+queueMicrotask(() => console.log("microtask"));
+Promise.resolve().then(() => console.log("promise"));
+
+// Microtasks that a timer queues run before the next timer, even if it is due at the same time.
+setTimeout(() => {
+  console.log("timeout 5");
+  queueMicrotask(() => console.log("microtask from timeout 5"));
+}, 5);
+setTimeout(() => console.log("timeout 5 again"), 5);
+
+// Cleared timers never fire.
+const cancelled = setTimeout(() => console.log("cancelled"), 1);
+clearTimeout(cancelled);
+
+// Intervals repeat until they are cleared.
+const interval = setInterval(() => console.log("tick"), 30);
+setTimeout(() => clearInterval(interval), 100);
+
+// Callbacks share the bindings they assign with the code around them, and can clear their own interval.
+let count = 0;
+const counter = setInterval(() => {
+  count++;
+  console.log(count);
+  if (count === 3) clearInterval(counter);
+}, 10);
+
+// Promises that a timer resolves can be awaited, like a sleep.
+function sleep(ms: number): Promise<void> {
+  return new Promise<void>((resolve) => setTimeout(resolve, ms));
+}
+
+await sleep(50);
+console.log("slept");
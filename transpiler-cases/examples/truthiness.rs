@@ -0,0 +1,70 @@
+use ts_std::*;
+#[allow(clippy::all)]
+fn main() {
+    let name = "";
+    let title = "Dr.";
+    let mut remaining = 3;
+    let mut rounds = 0;
+    while remaining.truthy() {
+        remaining -= 1;
+        rounds += 1;
+    }
+    console.assert(rounds == 3);
+    if name.truthy() {
+        console.assert(false);
+    }
+    if !name.truthy() && title.truthy() {
+        console.log(title);
+    }
+    if rounds > 1 && title.truthy() {
+        console.log("rounds");
+    }
+    let ratio = 0.5;
+    let mut checked = false;
+    if ratio.truthy() {
+        checked = true;
+    }
+    console.assert(checked);
+    if false || false {
+        console.assert(false);
+    }
+    let label = match name {
+        value if value.truthy() => value,
+        _ => "anonymous",
+    };
+    console.assert(label == "anonymous");
+    let honorific = match title {
+        value if value.truthy() => value,
+        _ => "anonymous",
+    };
+    console.assert(honorific == "Dr.");
+    let initials = match name {
+        value if !value.truthy() => value,
+        _ => "x",
+    };
+    console.assert(initials == "");
+    let fallback = match match remaining {
+        value if value.truthy() => value,
+        _ => rounds,
+    } {
+        value if value.truthy() => value,
+        _ => 10,
+    };
+    console.assert(fallback == 3);
+    let scaled = match ratio {
+        value if !value.truthy() => value,
+        _ => ratio * 4.0,
+    };
+    console.assert(scaled == 2.0);
+    let mut calls = 0;
+    if rounds.truthy() {
+        calls += 1;
+    }
+    if remaining.truthy() {
+        calls += 1;
+    }
+    if !remaining.truthy() {
+        calls += 1;
+    }
+    console.assert(calls == 2);
+}
@@ -0,0 +1,49 @@
+// Conditions convert values with `Truthy`.
+const name = "";
+const title = "Dr.";
+let remaining = 3;
+let rounds = 0;
+while (remaining) {
+  remaining--;
+  rounds++;
+}
+console.assert(rounds === 3);
+
+if (name) {
+  console.assert(false);
+}
+if (!name && title) {
+  console.log(title);
+}
+if (rounds > 1 && title) {
+  console.log("rounds");
+}
+
+const ratio = 0.5;
+let checked = false;
+if (ratio) {
+  checked = true;
+}
+console.assert(checked);
+if (0 || "") {
+  console.assert(false);
+}
+
+// `||` and `&&` evaluate to one of their operands.
+const label = name || "anonymous";
+console.assert(label === "anonymous");
+const honorific = title || "anonymous";
+console.assert(honorific === "Dr.");
+const initials = name && "x";
+console.assert(initials === "");
+const fallback = remaining || rounds || 10;
+console.assert(fallback === 3);
+const scaled = ratio && ratio * 4;
+console.assert(scaled === 2);
+
+// Used as statements, they only run their right operand conditionally.
+let calls = 0;
+rounds && calls++;
+remaining && calls++;
+remaining || calls++;
+console.assert(calls === 2);
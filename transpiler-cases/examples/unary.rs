@@ -0,0 +1,85 @@
+use ts_std::*;
+#[allow(clippy::all)]
+#[allow(dead_code)]
+#[derive(Clone, Debug)]
+struct Label {
+    width: f64,
+    height: Option<f64>,
+}
+impl OwnKeys for Label {
+    fn own_keys(&self) -> Vec<String> {
+        let mut keys = vec!["width"];
+        if self.height.is_some() {
+            keys.push("height");
+        }
+        ordered_keys(keys)
+    }
+}
+impl HasProperty for Label {
+    fn has_property(&self, key: &str) -> bool {
+        match key {
+            "width" => true,
+            "height" => self.height.is_some(),
+            _ => false,
+        }
+    }
+}
+impl Delete for Label {
+    fn delete(&mut self, key: &str) -> bool {
+        if key == "height" {
+            self.height = None;
+        }
+        true
+    }
+}
+#[allow(clippy::all)]
+fn main() {
+    let digits = "42";
+    let count = digits.to_number();
+    console.assert(count == 42.0);
+    console.assert(" 7 ".to_number() == 7.0);
+    console.assert("0x10".to_number() == 16.0);
+    console.assert(true.to_number() == 1.0);
+    let ratio = 0.25;
+    console.assert(ratio == 0.25);
+    let mask = number::bit_not(0.0) as i32;
+    console.assert(mask == -1);
+    console.assert(number::bit_not(ratio) == -1.0);
+    console.assert(number::bit_not(4294967296.5) == -1.0);
+    let mut bits = 5;
+    bits = bits & 6;
+    console.assert(!bits == -5);
+    let mut maybe: Option<f64> = None;
+    console.assert("number" == "number");
+    console.assert("string" == "string");
+    console.assert(type_of(&(count > 1.0)) == "boolean");
+    console.assert("undefined" == "undefined");
+    console.assert("object" == "object");
+    console.assert(type_of(&maybe) == "undefined");
+    maybe = Some(3.0);
+    console.assert(type_of(&maybe) == "number");
+    let mut evaluated = 0;
+    let nothing = {
+        evaluated += 1;
+        undefined
+    };
+    console.assert(strict_eq(&nothing, &undefined));
+    console.assert(evaluated == 1);
+    console.assert(strict_eq(&undefined, &undefined));
+    let mut label = Label {
+        width: 3.0,
+        height: Some(1.0),
+    };
+    console.assert(label.delete("height"));
+    console.assert(strict_eq(&label.height, &undefined));
+    console.assert(label.width == 3.0);
+    let mut totals = Object::from([("apples", 2.0), ("pears", 4.0)]);
+    console.assert(totals.delete("apples"));
+    console.assert(totals.delete("pears"));
+    console.assert(!(totals.has_property("pears")));
+    let mut bounds = Object::from([("Infinity", 10.0), ("1e+21", 5.0)]);
+    console.assert(bounds.has_property(&(1.0 / 0.0).to_js_string()));
+    console.assert(bounds["1e+21"] == 5.0);
+    console.assert(bounds.delete(&(1.0 / 0.0).to_js_string()));
+    console.assert(!(bounds.has_property("Infinity")));
+}
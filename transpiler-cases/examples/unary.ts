@@ -0,0 +1,57 @@
+// Unary plus converts its operand with `ToNumber`.
+const digits = "42";
+const count = +digits;
+console.assert(count === 42);
+console.assert(+" 7 " === 7);
+console.assert(+"0x10" === 16);
+console.assert(+true === 1);
+const ratio = 0.25;
+console.assert(+ratio === 0.25);
+
+// Bitwise not converts its operand with `ToInt32`.
+const mask = ~0;
+console.assert(mask === -1);
+console.assert(~ratio === -1);
+console.assert(~4294967296.5 === -1);
+let bits = 5;
+bits = bits & 6;
+console.assert(~bits === -5);
+
+// `typeof` is resolved statically for values of known type.
+let maybe: number | undefined = undefined;
+console.assert(typeof count === "number");
+console.assert(typeof digits === "string");
+console.assert(typeof (count > 1) === "boolean");
+console.assert(typeof undefined === "undefined");
+console.assert(typeof null === "object");
+console.assert(typeof maybe === "undefined");
+maybe = 3;
+console.assert(typeof maybe === "number");
+
+// `void` evaluates its operand and results in `undefined`.
+let evaluated = 0;
+const nothing = void (evaluated += 1);
+console.assert(nothing === undefined);
+console.assert(evaluated === 1);
+console.assert(void 0 === undefined);
+
+// `delete` removes the properties of records and resets the optional properties of interfaces.
+interface Label {
+    width: number;
+    height?: number;
+}
+const label: Label = { width: 3, height: 1 };
+console.assert(delete label.height);
+console.assert(label.height === undefined);
+console.assert(label.width === 3);
+const totals: Record<string, number> = { apples: 2, pears: 4 };
+console.assert(delete totals.apples);
+console.assert(delete totals["pears"]);
+console.assert(!("pears" in totals));
+
+// Numeric keys are converted to strings the way JavaScript does.
+const bounds: Record<string, number> = { Infinity: 10, 1e21: 5 };
+console.assert(1 / 0 in bounds);
+console.assert(bounds[1e21] === 5);
+console.assert(delete bounds[1 / 0]);
+console.assert(!("Infinity" in bounds));
@@ -0,0 +1,17 @@
+#[allow(unused_imports)]
+use ts_std::*;
+#[allow(clippy::all)]
+#[allow(non_upper_case_globals)]
+pub const CURRENCY: &str = "EUR";
+#[allow(clippy::all)]
+pub fn price(amount: f64) -> String {
+    return format!("{} {}", amount.to_js_string(), CURRENCY);
+}
+#[allow(clippy::all)]
+pub fn pad(text: &str, width: f64) -> String {
+    let mut padded = text.to_string();
+    while (string::length(&padded) as f64) < width {
+        padded = format!("{} ", padded);
+    }
+    return padded.clone();
+}
@@ -0,0 +1,17 @@
+#[allow(unused_imports)]
+use ts_std::*;
+#[allow(clippy::all)]
+pub fn take(count: f64, amount: f64) -> f64 {
+    if amount > count {
+        return 0.0;
+    }
+    return count - amount;
+}
+#[allow(clippy::all)]
+pub fn restock(count: f64, amount: f64) -> f64 {
+    return count + amount;
+}
+#[allow(clippy::all)]
+pub fn worth(count: f64, price: f64) -> f64 {
+    return count * price;
+}
@@ -0,0 +1,17 @@
+mod format;
+mod items;
+use crate::format::{pad, price};
+use crate::items::stock::{restock, take, worth};
+use ts_std::*;
+#[allow(clippy::all)]
+fn main() {
+    let mut apples = 12.0;
+    apples = take(apples, 5.0);
+    apples = restock(apples, 20.0);
+    console.log(format!("{}{}", pad("apples", 8.0), apples.to_js_string()));
+    console.log(format!(
+        "{}{}",
+        pad("value", 8.0),
+        price(worth(apples, 0.25))
+    ));
+}
@@ -0,0 +1,13 @@
+export const CURRENCY = "EUR";
+
+export function price(amount: number): string {
+  return amount + " " + CURRENCY;
+}
+
+export function pad(text: string, width: number): string {
+  let padded = text;
+  while (padded.length < width) {
+    padded = padded + " ";
+  }
+  return padded;
+}
@@ -0,0 +1,7 @@
+// Excluded by `tsconfig.json`, since packages like test frameworks can't be transpiled.
+import { expect, test } from "vitest";
+import { take } from "./stock.js";
+
+test("take", () => {
+  expect(take(3, 5)).toBe(0);
+});
@@ -0,0 +1,15 @@
+// Modules in directories become nested Rust modules, e.g. `items::stock`.
+export function take(count: number, amount: number): number {
+  if (amount > count) {
+    return 0;
+  }
+  return count - amount;
+}
+
+export function restock(count: number, amount: number): number {
+  return count + amount;
+}
+
+export function worth(count: number, price: number): number {
+  return count * price;
+}
@@ -0,0 +1,10 @@
+// The crate root has top-level statements, so the project is transpiled to a binary crate.
+import { restock, worth, take } from "./items/stock.js";
+import { price, pad } from "./format.js";
+
+let apples = 12;
+apples = take(apples, 5);
+apples = restock(apples, 20);
+
+console.log(pad("apples", 8) + apples);
+console.log(pad("value", 8) + price(worth(apples, 0.25)));
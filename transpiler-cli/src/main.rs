@@ -1,10 +1,15 @@
-use std::{error::Error, path::PathBuf};
+use std::{
+    error::Error,
+    path::{Path, PathBuf},
+};
 
 use clap::{
-    command, error::ErrorKind, value_parser, Arg, ArgMatches, Command, CommandFactory,
+    command, error::ErrorKind, value_parser, Arg, ArgAction, ArgMatches, Command, CommandFactory,
     FromArgMatches, Parser,
 };
-use typescript_transpiler::{parse_rust_file, parse_typescript_file, transpile_file};
+use typescript_transpiler::{
+    parse_rust_file, parse_typescript_file, transpile_file, OverloadStrategy, TranspileOptions,
+};
 
 fn main() -> Result<(), Box<dyn Error>> {
     let command = command!()
@@ -37,7 +42,32 @@ fn main() -> Result<(), Box<dyn Error>> {
                     Arg::new("output")
                         .required(false)
                         .value_parser(value_parser!(PathBuf)),
-                ),
+                )
+                .args(option_args()),
+        )
+        .subcommand(
+            Command::new("transpile-project")
+                .about("Transpile a TypeScript project to a Cargo crate")
+                .arg(
+                    Arg::new("project")
+                        .required(false)
+                        .default_value("tsconfig.json")
+                        .value_parser(value_parser!(PathBuf))
+                        .help("The tsconfig.json of the project, or its directory"),
+                )
+                .arg(
+                    Arg::new("out_dir")
+                        .long("out-dir")
+                        .value_parser(value_parser!(PathBuf))
+                        .help("The directory to write the crate to, instead of the outDir of the project"),
+                )
+                .arg(
+                    Arg::new("ts_std")
+                        .long("ts-std")
+                        .value_parser(value_parser!(PathBuf))
+                        .help("The directory of the ts-std crate that the crate depends on, instead of a `ts-std` directory next to the project or one of its parents"),
+                )
+                .args(option_args()),
         );
 
     let matches = command.get_matches();
@@ -46,10 +76,45 @@ fn main() -> Result<(), Box<dyn Error>> {
         Some(("parse-rs", sub_matches)) => parse_rs(sub_matches),
         Some(("parse-ts", sub_matches)) => parse_ts(sub_matches),
         Some(("transpile", sub_matches)) => transpile(sub_matches),
+        Some(("transpile-project", sub_matches)) => transpile_project(sub_matches),
         _ => unreachable!("Exhausted list of subcommands and subcommand_required prevents `None`"),
     }
 }
 
+/// The arguments of the options that change how TypeScript is transpiled, see `TranspileOptions`.
+fn option_args() -> [Arg; 3] {
+    [
+        Arg::new("no_integer_narrowing")
+            .long("no-integer-narrowing")
+            .action(ArgAction::SetTrue)
+            .help("Represent every number as f64, even if it only holds integers"),
+        Arg::new("overloads")
+            .long("overloads")
+            .value_parser(["split", "enum"])
+            .default_value("split")
+            .help("Transpile overloaded functions to a function per overload or to one function taking an argument enum"),
+        Arg::new("virtual_clock")
+            .long("virtual-clock")
+            .action(ArgAction::SetTrue)
+            .help("Run timers on a virtual clock that skips ahead to the next timer instead of waiting"),
+    ]
+}
+
+fn transpile_options(
+    no_integer_narrowing: bool,
+    overloads: &str,
+    virtual_clock: bool,
+) -> TranspileOptions {
+    TranspileOptions {
+        integer_narrowing: !no_integer_narrowing,
+        overloads: match overloads {
+            "enum" => OverloadStrategy::ArgumentEnum,
+            _ => OverloadStrategy::Split,
+        },
+        virtual_clock,
+    }
+}
+
 #[derive(Debug, Parser)]
 #[command(version, about, long_about = None)]
 struct ParseArgs {
@@ -97,6 +162,12 @@ fn parse_ts(matches: &ArgMatches) -> Result<(), Box<dyn Error>> {
 struct TranspileArgs {
     input: PathBuf,
     output: Option<PathBuf>,
+    #[arg(long)]
+    no_integer_narrowing: bool,
+    #[arg(long)]
+    overloads: String,
+    #[arg(long)]
+    virtual_clock: bool,
 }
 
 fn transpile(matches: &ArgMatches) -> Result<(), Box<dyn Error>> {
@@ -130,5 +201,91 @@ fn transpile(matches: &ArgMatches) -> Result<(), Box<dyn Error>> {
         .exit();
     }
 
-    transpile_file(args.input.as_path(), output.as_path()).map_err(Box::from)
+    let options = transpile_options(
+        args.no_integer_narrowing,
+        &args.overloads,
+        args.virtual_clock,
+    );
+
+    transpile_file(args.input.as_path(), output.as_path(), &options).map_err(Box::from)
+}
+
+#[derive(Debug, Parser)]
+#[command(version, about, long_about = None)]
+struct TranspileProjectArgs {
+    project: PathBuf,
+    #[arg(long)]
+    out_dir: Option<PathBuf>,
+    #[arg(long)]
+    ts_std: Option<PathBuf>,
+    #[arg(long)]
+    no_integer_narrowing: bool,
+    #[arg(long)]
+    overloads: String,
+    #[arg(long)]
+    virtual_clock: bool,
+}
+
+fn transpile_project(matches: &ArgMatches) -> Result<(), Box<dyn Error>> {
+    let args = TranspileProjectArgs::from_arg_matches(matches)?;
+
+    let tsconfig_file = match args.project.is_dir() {
+        true => args.project.join("tsconfig.json"),
+        false => args.project.clone(),
+    };
+
+    if !tsconfig_file.exists() {
+        let mut cmd = TranspileProjectArgs::command();
+        cmd.error(
+            ErrorKind::ValueValidation,
+            format!("Project file `{}` doesn't exist.", tsconfig_file.display()),
+        )
+        .exit();
+    }
+
+    let Some(ts_std_dir) = args.ts_std.or_else(|| find_ts_std(&tsconfig_file)) else {
+        let mut cmd = TranspileProjectArgs::command();
+        cmd.error(
+            ErrorKind::MissingRequiredArgument,
+            "Can't find the ts-std crate next to the project, pass its directory with `--ts-std`.",
+        )
+        .exit();
+    };
+
+    if !ts_std_dir.join("Cargo.toml").exists() {
+        let mut cmd = TranspileProjectArgs::command();
+        cmd.error(
+            ErrorKind::ValueValidation,
+            format!("ts-std crate `{}` doesn't exist.", ts_std_dir.display()),
+        )
+        .exit();
+    }
+
+    let options = transpile_options(
+        args.no_integer_narrowing,
+        &args.overloads,
+        args.virtual_clock,
+    );
+
+    let out_dir = typescript_transpiler::transpile_project(
+        &tsconfig_file,
+        args.out_dir.as_deref(),
+        &ts_std_dir,
+        &options,
+    )?;
+    println!("Wrote crate to `{}`.", out_dir.display());
+
+    Ok(())
+}
+
+/// Finds a `ts-std` crate in a directory containing the project, or containing the transpiler itself.
+fn find_ts_std(tsconfig_file: &Path) -> Option<PathBuf> {
+    let project_dir = tsconfig_file.canonicalize().ok()?;
+    let exe = std::env::current_exe().ok();
+
+    project_dir
+        .ancestors()
+        .chain(exe.iter().flat_map(|exe| exe.ancestors()))
+        .map(|dir| dir.join("ts-std"))
+        .find(|dir| dir.join("Cargo.toml").is_file())
 }
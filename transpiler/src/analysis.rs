@@ -0,0 +1,1314 @@
+use std::collections::{HashMap, HashSet};
+
+use swc_ecma_ast as swc;
+use swc_ecma_visit::{Visit, VisitWith};
+
+use crate::types::{annotation_type, NumberType, ValueType};
+
+/// Bindings that are possibly modified by a piece of code.
+#[derive(Clone, Debug, Default)]
+pub struct Modifications {
+    /// Bindings that are assigned or updated, e.g. `x = 1`, `x++` or `for (x of xs)`.
+    pub reassigned: HashSet<String>,
+    /// Bindings whose value may be changed in place, e.g. `x.y = 1`, `delete x.y`, `x.push(1)` or `f(x)`.
+    pub mutated: HashSet<String>,
+    /// Bindings whose properties are written directly, e.g. `x.y = 1`, `x.y++` or `delete x.y`.
+    pub property_written: HashSet<String>,
+    /// Bindings that methods changing their receiver in place are called on, e.g. `x.push(1)` or `x.y.sort()`.
+    pub method_receivers: HashSet<String>,
+}
+
+impl Modifications {
+    pub fn is_reassigned(&self, ident: &str) -> bool {
+        self.reassigned.contains(ident)
+    }
+
+    pub fn is_modified(&self, ident: &str) -> bool {
+        self.reassigned.contains(ident) || self.mutated.contains(ident)
+    }
+
+    fn extend_bindings<N: VisitWith<Bindings>>(&mut self, pat: &N) {
+        let mut bindings = Bindings::default();
+        pat.visit_with(&mut bindings);
+
+        self.reassigned.extend(bindings.idents);
+        self.mutated.extend(bindings.mutated.iter().cloned());
+        self.property_written.extend(bindings.mutated);
+    }
+
+    fn write_property(&mut self, ident: String) {
+        self.mutated.insert(ident.clone());
+        self.property_written.insert(ident);
+    }
+}
+
+impl Visit for Modifications {
+    fn visit_assign_expr(&mut self, assign: &swc::AssignExpr) {
+        match &assign.left {
+            swc::AssignTarget::Simple(swc::SimpleAssignTarget::Ident(ident)) => {
+                self.reassigned.insert(ident.id.sym.to_string());
+            }
+            swc::AssignTarget::Simple(swc::SimpleAssignTarget::Member(member)) => {
+                if let Some(ident) = root_ident(&member.obj) {
+                    self.write_property(ident);
+                }
+            }
+            swc::AssignTarget::Pat(pat) => {
+                self.extend_bindings(pat);
+            }
+            _ => {}
+        }
+
+        assign.visit_children_with(self);
+    }
+
+    fn visit_update_expr(&mut self, update: &swc::UpdateExpr) {
+        if update.arg.is_ident() {
+            if let Some(ident) = root_ident(&update.arg) {
+                self.reassigned.insert(ident);
+            }
+        } else if let Some(ident) = root_ident(&update.arg) {
+            self.write_property(ident);
+        }
+
+        update.visit_children_with(self);
+    }
+
+    fn visit_unary_expr(&mut self, unary: &swc::UnaryExpr) {
+        if unary.op == swc::UnaryOp::Delete {
+            if let Some(ident) = root_ident(&unary.arg) {
+                self.write_property(ident);
+            }
+        }
+
+        unary.visit_children_with(self);
+    }
+
+    fn visit_call_expr(&mut self, call: &swc::CallExpr) {
+        // Calling an element, e.g. `steps[i](x)`, doesn't change the array.
+        if let swc::Callee::Expr(callee) = &call.callee {
+            if let swc::Expr::Member(member) = &**callee {
+                if let Some(method) = member.prop.as_ident() {
+                    if let Some(ident) = mutable_root(&member.obj) {
+                        if is_in_place_method(method.sym.as_str()) {
+                            self.method_receivers.insert(ident.clone());
+                        }
+                        self.mutated.insert(ident);
+                    }
+                }
+            }
+        }
+
+        for arg in &call.args {
+            if let Some(ident) = mutable_root(&arg.expr) {
+                self.mutated.insert(ident);
+            }
+        }
+
+        call.visit_children_with(self);
+    }
+
+    fn visit_for_in_stmt(&mut self, for_in: &swc::ForInStmt) {
+        if let swc::ForHead::Pat(pat) = &for_in.left {
+            self.extend_bindings(pat);
+        }
+
+        for_in.visit_children_with(self);
+    }
+
+    fn visit_for_of_stmt(&mut self, for_of: &swc::ForOfStmt) {
+        if let swc::ForHead::Pat(pat) = &for_of.left {
+            self.extend_bindings(pat);
+        }
+
+        for_of.visit_children_with(self);
+    }
+}
+
+pub fn find_modifications<N: VisitWith<Modifications>>(node: &N) -> Modifications {
+    let mut modifications = Modifications::default();
+    node.visit_with(&mut modifications);
+    modifications
+}
+
+/// Identifiers bound by a pattern.
+#[derive(Default)]
+struct Bindings {
+    idents: HashSet<String>,
+    mutated: HashSet<String>,
+}
+
+impl Visit for Bindings {
+    fn visit_binding_ident(&mut self, ident: &swc::BindingIdent) {
+        self.idents.insert(ident.id.sym.to_string());
+    }
+
+    fn visit_expr(&mut self, expr: &swc::Expr) {
+        // Member targets in patterns (e.g. `[a.b] = c`) mutate an object instead of binding an identifier.
+        if let Some(ident) = root_ident(expr) {
+            self.mutated.insert(ident);
+        }
+    }
+}
+
+/// Callbacks that assign to bindings they capture, which are `FnMut` closures in Rust, see `captured_assignments`.
+///
+/// Assignments to shared bindings don't count, since they are assigned through a `Shared` cell.
+#[derive(Clone, Debug, Default)]
+pub struct MutatingCallbacks {
+    /// Bindings holding such a callback, with the captured bindings it assigns to, e.g. `increment` assigning `count`
+    /// in `const increment = () => count++`.
+    pub closures: HashMap<String, HashSet<String>>,
+    /// Parameters of functions that such a callback is passed to, by function name and parameter index.
+    pub params: HashSet<(String, usize)>,
+    /// Bindings holding callbacks with the captured bindings they may change in place, e.g. `add` changing `items` in
+    /// `const add = (item) => items.push(item)`.
+    pub mutations: HashMap<String, HashSet<String>>,
+    shared: HashSet<String>,
+}
+
+impl MutatingCallbacks {
+    fn unshared_assignments(&self, expr: &swc::Expr) -> HashSet<String> {
+        captured_assignments(expr)
+            .into_iter()
+            .filter(|ident| !self.shared.contains(ident))
+            .collect()
+    }
+}
+
+impl Visit for MutatingCallbacks {
+    fn visit_var_declarator(&mut self, declarator: &swc::VarDeclarator) {
+        if let (swc::Pat::Ident(ident), Some(init)) = (&declarator.name, &declarator.init) {
+            let assigned = self.unshared_assignments(init);
+
+            if !assigned.is_empty() {
+                self.closures.insert(ident.id.sym.to_string(), assigned);
+            }
+
+            let mutated = captured_mutations(init);
+
+            if !mutated.is_empty() {
+                self.mutations.insert(ident.id.sym.to_string(), mutated);
+            }
+        }
+
+        declarator.visit_children_with(self);
+    }
+
+    fn visit_call_expr(&mut self, call: &swc::CallExpr) {
+        if let Some(callee) = call.callee.as_expr().and_then(|callee| callee.as_ident()) {
+            for (index, arg) in call.args.iter().enumerate() {
+                let is_mutating = match &*arg.expr {
+                    swc::Expr::Ident(ident) => self.closures.contains_key(ident.sym.as_str()),
+                    expr => !self.unshared_assignments(expr).is_empty(),
+                };
+
+                if is_mutating {
+                    self.params.insert((callee.sym.to_string(), index));
+                }
+            }
+        }
+
+        call.visit_children_with(self);
+    }
+}
+
+pub fn find_mutating_callbacks<N: VisitWith<MutatingCallbacks>>(
+    node: &N,
+    shared: &HashSet<String>,
+) -> MutatingCallbacks {
+    let mut mutating_callbacks = MutatingCallbacks {
+        shared: shared.clone(),
+        ..MutatingCallbacks::default()
+    };
+    node.visit_with(&mut mutating_callbacks);
+    mutating_callbacks
+}
+
+/// The bindings a function expression assigns to without declaring them itself, e.g. `count` for `() => count++`.
+pub fn captured_assignments(expr: &swc::Expr) -> HashSet<String> {
+    captured_modifications(expr, |modifications| modifications.reassigned)
+}
+
+/// The bindings a function expression may change in place without declaring them itself, e.g. `items` for
+/// `() => items.push(1)`.
+fn captured_mutations(expr: &swc::Expr) -> HashSet<String> {
+    captured_modifications(expr, |modifications| modifications.mutated)
+}
+
+fn captured_modifications(
+    expr: &swc::Expr,
+    select: fn(Modifications) -> HashSet<String>,
+) -> HashSet<String> {
+    let mut declarations = Declarations::default();
+    let modifications = match expr {
+        swc::Expr::Paren(paren) => return captured_modifications(&paren.expr, select),
+        swc::Expr::Arrow(arrow) => {
+            arrow.visit_with(&mut declarations);
+            find_modifications(arrow)
+        }
+        swc::Expr::Fn(fn_expr) => {
+            fn_expr.visit_with(&mut declarations);
+            find_modifications(fn_expr)
+        }
+        _ => return HashSet::new(),
+    };
+
+    select(modifications)
+        .into_iter()
+        .filter(|ident| !declarations.idents.contains(ident))
+        .collect()
+}
+
+/// The bindings a function expression uses without declaring them itself.
+fn captured_idents(expr: &swc::Expr) -> HashSet<String> {
+    let mut declarations = Declarations::default();
+    expr.visit_with(&mut declarations);
+
+    find_idents(expr)
+        .into_iter()
+        .filter(|ident| !declarations.idents.contains(ident))
+        .collect()
+}
+
+/// Bindings that closures share with the code around them, which are `Shared` cells from ts-std, see
+/// `find_shared_bindings`.
+#[derive(Default)]
+pub struct SharedBindings {
+    reassigned: HashSet<String>,
+    /// Closures held by bindings, with the captured bindings they assign to.
+    closures: HashMap<String, HashSet<String>>,
+    /// The bindings whose initializers are being visited, innermost last.
+    declaring: Vec<String>,
+    shared: HashSet<String>,
+}
+
+impl SharedBindings {
+    /// Shares the bindings that a callback run by the event loop captures, since it is moved there.
+    fn share_runtime_callback(&mut self, callback: &swc::Expr) {
+        match callback {
+            swc::Expr::Paren(paren) => self.share_runtime_callback(&paren.expr),
+            swc::Expr::Ident(ident) => {
+                if let Some(assigned) = self.closures.get(ident.sym.as_str()) {
+                    self.shared.extend(assigned.iter().cloned());
+                }
+            }
+            swc::Expr::Arrow(_) | swc::Expr::Fn(_) => {
+                for ident in captured_idents(callback) {
+                    // Bindings that are never reassigned can be moved as they are, unless the callback refers to the
+                    // binding that its timer is assigned to, which doesn't exist yet.
+                    if self.reassigned.contains(&ident) || self.declaring.contains(&ident) {
+                        self.shared.insert(ident);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Shares the bindings that closures assign to while the code around them still uses them, since a closure
+    /// holds a mutable borrow of those bindings from its declaration to its last call.
+    fn share_closure_assignments(&mut self, stmts: &[&swc::Stmt]) {
+        let idents: Vec<HashSet<String>> = stmts.iter().map(|stmt| find_idents(*stmt)).collect();
+
+        for (index, stmt) in stmts.iter().enumerate() {
+            let swc::Stmt::Decl(swc::Decl::Var(var)) = stmt else {
+                continue;
+            };
+
+            for declarator in &var.decls {
+                let (swc::Pat::Ident(ident), Some(init)) = (&declarator.name, &declarator.init)
+                else {
+                    continue;
+                };
+                let assigned = captured_assignments(init);
+                let name = ident.id.sym.as_str();
+                let last_use = (index + 1..stmts.len())
+                    .rev()
+                    .find(|later| idents[*later].contains(name));
+
+                if let Some(last_use) = last_use {
+                    for later in &idents[index + 1..=last_use] {
+                        self.shared.extend(
+                            assigned
+                                .iter()
+                                .filter(|ident| later.contains(*ident))
+                                .cloned(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Visit for SharedBindings {
+    fn visit_module_items(&mut self, items: &[swc::ModuleItem]) {
+        let stmts: Vec<&swc::Stmt> = items.iter().filter_map(|item| item.as_stmt()).collect();
+        self.share_closure_assignments(&stmts);
+
+        items.visit_children_with(self);
+    }
+
+    fn visit_stmts(&mut self, stmts: &[swc::Stmt]) {
+        let stmts_refs: Vec<&swc::Stmt> = stmts.iter().collect();
+        self.share_closure_assignments(&stmts_refs);
+
+        stmts.visit_children_with(self);
+    }
+
+    fn visit_var_declarator(&mut self, declarator: &swc::VarDeclarator) {
+        match (&declarator.name, &declarator.init) {
+            (swc::Pat::Ident(ident), Some(init)) => {
+                let assigned = captured_assignments(init);
+
+                if !assigned.is_empty() {
+                    self.closures.insert(ident.id.sym.to_string(), assigned);
+                }
+
+                self.declaring.push(ident.id.sym.to_string());
+                declarator.visit_children_with(self);
+                self.declaring.pop();
+            }
+            _ => declarator.visit_children_with(self),
+        }
+    }
+
+    fn visit_call_expr(&mut self, call: &swc::CallExpr) {
+        let is_runtime_call = match call.callee.as_expr().map(|callee| &**callee) {
+            Some(swc::Expr::Ident(ident)) => {
+                matches!(
+                    ident.sym.as_str(),
+                    "setTimeout" | "setInterval" | "queueMicrotask"
+                )
+            }
+            Some(swc::Expr::Member(member)) => member
+                .prop
+                .as_ident()
+                .is_some_and(|prop| matches!(prop.sym.as_str(), "then" | "catch" | "finally")),
+            _ => false,
+        };
+
+        if is_runtime_call {
+            for arg in &call.args {
+                self.share_runtime_callback(&arg.expr);
+            }
+        }
+
+        call.visit_children_with(self);
+    }
+
+    fn visit_new_expr(&mut self, new: &swc::NewExpr) {
+        if new
+            .callee
+            .as_ident()
+            .is_some_and(|callee| callee.sym.as_str() == "Promise")
+        {
+            for arg in new.args.iter().flatten() {
+                self.share_runtime_callback(&arg.expr);
+            }
+        }
+
+        new.visit_children_with(self);
+    }
+}
+
+/// Finds the bindings that closures share with the code around them, which are bindings that callbacks run by the
+/// event loop capture and that are reassigned, and bindings that closures assign to while they are also used outside
+/// of them.
+pub fn find_shared_bindings(module: &swc::Module) -> HashSet<String> {
+    let mut shared_bindings = SharedBindings {
+        reassigned: find_modifications(module).reassigned,
+        ..SharedBindings::default()
+    };
+    module.visit_with(&mut shared_bindings);
+    shared_bindings.shared
+}
+
+/// Identifiers declared by a piece of code, as variables, parameters or functions.
+#[derive(Default)]
+struct Declarations {
+    idents: HashSet<String>,
+}
+
+impl Declarations {
+    fn extend_bindings<N: VisitWith<Bindings>>(&mut self, pat: &N) {
+        let mut bindings = Bindings::default();
+        pat.visit_with(&mut bindings);
+
+        self.idents.extend(bindings.idents);
+    }
+}
+
+impl Visit for Declarations {
+    fn visit_var_declarator(&mut self, declarator: &swc::VarDeclarator) {
+        self.extend_bindings(&declarator.name);
+
+        declarator.visit_children_with(self);
+    }
+
+    fn visit_param(&mut self, param: &swc::Param) {
+        self.extend_bindings(&param.pat);
+
+        param.visit_children_with(self);
+    }
+
+    fn visit_arrow_expr(&mut self, arrow: &swc::ArrowExpr) {
+        for param in &arrow.params {
+            self.extend_bindings(param);
+        }
+
+        arrow.visit_children_with(self);
+    }
+
+    fn visit_fn_decl(&mut self, fn_decl: &swc::FnDecl) {
+        self.idents.insert(fn_decl.ident.sym.to_string());
+
+        fn_decl.visit_children_with(self);
+    }
+}
+
+/// Whether a module uses async functions, top-level `await`, promises or timers, so its top-level code runs as a
+/// future on the event loop.
+pub fn is_async_module(module: &swc::Module) -> bool {
+    let mut async_usage = AsyncUsage::default();
+    module.visit_with(&mut async_usage);
+    async_usage.found
+}
+
+#[derive(Default)]
+struct AsyncUsage {
+    found: bool,
+}
+
+impl Visit for AsyncUsage {
+    fn visit_await_expr(&mut self, _: &swc::AwaitExpr) {
+        self.found = true;
+    }
+
+    fn visit_function(&mut self, function: &swc::Function) {
+        self.found |= function.is_async;
+
+        function.visit_children_with(self);
+    }
+
+    fn visit_arrow_expr(&mut self, arrow: &swc::ArrowExpr) {
+        self.found |= arrow.is_async;
+
+        arrow.visit_children_with(self);
+    }
+
+    fn visit_ident(&mut self, ident: &swc::Ident) {
+        self.found |= matches!(
+            ident.sym.as_str(),
+            "Promise" | "setTimeout" | "setInterval" | "queueMicrotask"
+        );
+    }
+}
+
+/// Whether a statement or expression yields, outside of nested functions, which are generators of their own.
+pub fn contains_yield<N: VisitWith<YieldUsage>>(node: &N) -> bool {
+    let mut yield_usage = YieldUsage::default();
+    node.visit_with(&mut yield_usage);
+    yield_usage.found
+}
+
+#[derive(Default)]
+pub struct YieldUsage {
+    found: bool,
+}
+
+impl Visit for YieldUsage {
+    fn visit_yield_expr(&mut self, _: &swc::YieldExpr) {
+        self.found = true;
+    }
+
+    fn visit_function(&mut self, _: &swc::Function) {}
+
+    fn visit_arrow_expr(&mut self, _: &swc::ArrowExpr) {}
+}
+
+/// The names of all identifiers in a function body or statement, so generated names can avoid them.
+pub fn find_idents<N: VisitWith<Idents>>(node: &N) -> HashSet<String> {
+    let mut idents = Idents::default();
+    node.visit_with(&mut idents);
+    idents.names
+}
+
+#[derive(Default)]
+pub struct Idents {
+    names: HashSet<String>,
+}
+
+impl Visit for Idents {
+    fn visit_ident(&mut self, ident: &swc::Ident) {
+        self.names.insert(ident.sym.to_string());
+    }
+}
+
+/// The identifier an expression is rooted at, e.g. `a` for `a`, `a.b.c` and `(a as T).b`.
+pub fn root_ident(expr: &swc::Expr) -> Option<String> {
+    match expr {
+        swc::Expr::Ident(ident) => Some(ident.sym.to_string()),
+        swc::Expr::Member(member) => root_ident(&member.obj),
+        swc::Expr::Paren(paren) => root_ident(&paren.expr),
+        swc::Expr::TsAs(ts_as) => root_ident(&ts_as.expr),
+        swc::Expr::TsNonNull(ts_non_null) => root_ident(&ts_non_null.expr),
+        swc::Expr::TsSatisfies(ts_satisfies) => root_ident(&ts_satisfies.expr),
+        swc::Expr::TsTypeAssertion(ts_type_assertion) => root_ident(&ts_type_assertion.expr),
+        _ => None,
+    }
+}
+
+/// Whether a method of arrays, maps or sets changes its receiver in place, e.g. `push` or `sort`.
+fn is_in_place_method(method: &str) -> bool {
+    matches!(
+        method,
+        "push"
+            | "pop"
+            | "shift"
+            | "unshift"
+            | "splice"
+            | "sort"
+            | "reverse"
+            | "fill"
+            | "copyWithin"
+            | "set"
+            | "delete"
+            | "clear"
+            | "add"
+    )
+}
+
+/// The binding whose value an expression refers to rather than reads from, so passing it on may change the binding in
+/// place, e.g. `xs` for `xs` or `a` for `a.b`, but not for `xs[i]` or `xs.length`.
+fn mutable_root(expr: &swc::Expr) -> Option<String> {
+    match expr {
+        swc::Expr::Ident(ident) => Some(ident.sym.to_string()),
+        swc::Expr::Member(member) => match &member.prop {
+            swc::MemberProp::Ident(prop) if prop.sym.as_str() != "length" => {
+                mutable_root(&member.obj)
+            }
+            _ => None,
+        },
+        swc::Expr::Paren(paren) => mutable_root(&paren.expr),
+        swc::Expr::TsAs(ts_as) => mutable_root(&ts_as.expr),
+        swc::Expr::TsNonNull(ts_non_null) => mutable_root(&ts_non_null.expr),
+        swc::Expr::TsSatisfies(ts_satisfies) => mutable_root(&ts_satisfies.expr),
+        swc::Expr::TsTypeAssertion(ts_type_assertion) => mutable_root(&ts_type_assertion.expr),
+        _ => None,
+    }
+}
+
+/// `Number.MAX_SAFE_INTEGER`, the largest integer a `number` can represent exactly.
+pub const MAX_SAFE_INTEGER: f64 = 9007199254740991.0;
+
+/// The value of a safe integer literal, including negated literals like `-1`.
+pub fn integer_lit(expr: &swc::Expr) -> Option<i64> {
+    match expr {
+        swc::Expr::Lit(swc::Lit::Num(num))
+            if num.value.trunc() == num.value && num.value.abs() <= MAX_SAFE_INTEGER =>
+        {
+            Some(num.value as i64)
+        }
+        swc::Expr::Unary(unary) if unary.op == swc::UnaryOp::Minus => {
+            integer_lit(&unary.arg).map(|value| -value)
+        }
+        swc::Expr::Paren(paren) => integer_lit(&paren.expr),
+        _ => None,
+    }
+}
+
+/// A way a binding gets its value, collected for integer narrowing.
+#[derive(Clone, Debug)]
+enum Definition {
+    /// `let x = value` or `x = value`.
+    Value(swc::Expr),
+    /// Compound assignments like `x += value`.
+    Assign(swc::AssignOp, swc::Expr),
+    /// `x++` and `x--`.
+    Update(swc::UpdateOp),
+    /// Bindings whose value can't be analyzed, e.g. parameters, destructured and loop bindings.
+    Unknown,
+}
+
+/// Definitions of all bindings in a piece of code by name, merging bindings that shadow each other.
+#[derive(Default)]
+pub struct Definitions {
+    definitions: HashMap<String, Vec<Definition>>,
+}
+
+impl Definitions {
+    fn define(&mut self, ident: &str, definition: Definition) {
+        self.definitions
+            .entry(ident.to_string())
+            .or_default()
+            .push(definition);
+    }
+}
+
+impl Visit for Definitions {
+    fn visit_var_declarator(&mut self, declarator: &swc::VarDeclarator) {
+        let swc::Pat::Ident(ident) = &declarator.name else {
+            declarator.visit_children_with(self);
+            return;
+        };
+
+        let is_number = ident.type_ann.as_ref().is_none_or(|type_ann| {
+            matches!(annotation_type(&type_ann.type_ann), ValueType::Number(_))
+        });
+        let definition = match &declarator.init {
+            Some(init) if is_number => Definition::Value(*init.clone()),
+            _ => Definition::Unknown,
+        };
+
+        self.define(ident.id.sym.as_str(), definition);
+        declarator.init.visit_with(self);
+    }
+
+    fn visit_binding_ident(&mut self, ident: &swc::BindingIdent) {
+        self.define(ident.id.sym.as_str(), Definition::Unknown);
+    }
+
+    fn visit_assign_expr(&mut self, assign: &swc::AssignExpr) {
+        let swc::AssignTarget::Simple(swc::SimpleAssignTarget::Ident(ident)) = &assign.left else {
+            assign.visit_children_with(self);
+            return;
+        };
+
+        let definition = match assign.op {
+            swc::AssignOp::Assign => Definition::Value(*assign.right.clone()),
+            op => Definition::Assign(op, *assign.right.clone()),
+        };
+
+        self.define(ident.id.sym.as_str(), definition);
+        assign.right.visit_with(self);
+    }
+
+    fn visit_update_expr(&mut self, update: &swc::UpdateExpr) {
+        if let swc::Expr::Ident(ident) = &*update.arg {
+            self.define(ident.sym.as_str(), Definition::Update(update.op));
+        }
+
+        update.visit_children_with(self);
+    }
+}
+
+/// The largest magnitude narrowed integers may have, so that `i64` arithmetic on them can't overflow. Results that
+/// could be larger are computed as floats, like in TypeScript.
+pub const MAX_INTEGER_MAGNITUDE: u64 = 1 << 62;
+
+/// The magnitude that counters, i.e. bindings that only change by small steps, are assumed to stay below, since
+/// counting past the safe integer range takes more than 2^49 updates.
+const COUNTER_MAGNITUDE: u64 = MAX_SAFE_INTEGER as u64;
+
+/// The largest step of a counter, see `COUNTER_MAGNITUDE`.
+const MAX_COUNTER_STEP: u64 = 16;
+
+/// The magnitude of `i32::MIN`, the largest of an `i32`.
+pub const I32_MAGNITUDE: u64 = 1 << 31;
+
+/// The largest magnitude of the result of `+`, `-` or `*` on integers with the given magnitudes, saturating on
+/// overflow.
+///
+/// Small steps of a counter keep it at `COUNTER_MAGNITUDE`.
+pub fn arithmetic_magnitude(op: swc::BinaryOp, left: u64, right: u64) -> u64 {
+    match op {
+        swc::BinaryOp::Add | swc::BinaryOp::Sub
+            if left.max(right) == COUNTER_MAGNITUDE && left.min(right) <= MAX_COUNTER_STEP =>
+        {
+            COUNTER_MAGNITUDE
+        }
+        swc::BinaryOp::Add | swc::BinaryOp::Sub => left.saturating_add(right),
+        swc::BinaryOp::Mul => left.saturating_mul(right),
+        _ => u64::MAX,
+    }
+}
+
+/// The largest magnitude of `dividend % divisor` for a dividend with the given magnitude, if the divisor is a
+/// non-zero integer literal. Other divisors may be zero, which makes the remainder NaN.
+pub fn remainder_magnitude(dividend: u64, divisor: &swc::Expr) -> Option<u64> {
+    match integer_lit(divisor) {
+        Some(divisor) if divisor != 0 => Some(dividend.min(divisor.unsigned_abs() - 1)),
+        _ => None,
+    }
+}
+
+/// Integer type of a value during narrowing, from most to least specific, with the largest magnitude it can have.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum IntegerType {
+    /// A non-negative integer, which fits any integer type that its magnitude fits.
+    Literal(u64),
+    Integer(NumberType, u64),
+    NotInteger,
+}
+
+impl IntegerType {
+    /// An integer of the given type, widened to `i64` if the type can't hold the magnitude. Magnitudes that could
+    /// overflow `i64` aren't integers.
+    fn integer(number_type: NumberType, magnitude: u64) -> IntegerType {
+        match number_type {
+            _ if magnitude > MAX_INTEGER_MAGNITUDE => IntegerType::NotInteger,
+            NumberType::I32 if magnitude > I32_MAGNITUDE => {
+                IntegerType::Integer(NumberType::I64, magnitude)
+            }
+            number_type => IntegerType::Integer(number_type, magnitude),
+        }
+    }
+
+    fn literal(magnitude: u64) -> IntegerType {
+        if magnitude > MAX_INTEGER_MAGNITUDE {
+            IntegerType::NotInteger
+        } else {
+            IntegerType::Literal(magnitude)
+        }
+    }
+
+    /// Whether values of this type are never negative.
+    fn is_non_negative(self) -> bool {
+        matches!(
+            self,
+            IntegerType::Literal(_) | IntegerType::Integer(NumberType::Usize, _)
+        )
+    }
+
+    fn magnitude(self) -> u64 {
+        match self {
+            IntegerType::Literal(magnitude) | IntegerType::Integer(_, magnitude) => magnitude,
+            IntegerType::NotInteger => u64::MAX,
+        }
+    }
+
+    fn with_magnitude(self, magnitude: u64) -> IntegerType {
+        match self {
+            IntegerType::Literal(_) => IntegerType::literal(magnitude),
+            IntegerType::Integer(number_type, _) => IntegerType::integer(number_type, magnitude),
+            IntegerType::NotInteger => self,
+        }
+    }
+
+    fn join(self, other: IntegerType) -> IntegerType {
+        let magnitude = self.magnitude().max(other.magnitude());
+
+        match (self, other) {
+            (IntegerType::NotInteger, _) | (_, IntegerType::NotInteger) => IntegerType::NotInteger,
+            (IntegerType::Literal(_), IntegerType::Literal(_)) => IntegerType::literal(magnitude),
+            (IntegerType::Literal(_), IntegerType::Integer(number_type, _))
+            | (IntegerType::Integer(number_type, _), IntegerType::Literal(_)) => {
+                IntegerType::integer(number_type, magnitude)
+            }
+            (IntegerType::Integer(left, _), IntegerType::Integer(right, _)) if left == right => {
+                IntegerType::integer(left, magnitude)
+            }
+            (IntegerType::Integer(..), IntegerType::Integer(..)) => {
+                IntegerType::integer(NumberType::I64, magnitude)
+            }
+        }
+    }
+
+    /// The type of `self + step` for a non-negative step, where incrementing a literal binding keeps it non-negative.
+    fn increment(self, step: u64) -> IntegerType {
+        let magnitude = arithmetic_magnitude(swc::BinaryOp::Add, self.magnitude(), step);
+
+        match self {
+            IntegerType::Literal(_) | IntegerType::Integer(NumberType::Usize, _) => {
+                self.with_magnitude(magnitude)
+            }
+            IntegerType::Integer(..) => IntegerType::integer(NumberType::I64, magnitude),
+            IntegerType::NotInteger => self,
+        }
+    }
+
+    /// The type of `+`, `-` or `*` on integers.
+    ///
+    /// Results are widened to `i64`, unless they could overflow it. `usize` is kept for operators that can't make a
+    /// number negative.
+    fn arithmetic(op: swc::BinaryOp, left: IntegerType, right: IntegerType) -> IntegerType {
+        let magnitude = arithmetic_magnitude(op, left.magnitude(), right.magnitude());
+
+        match (op, left.join(right)) {
+            (_, IntegerType::NotInteger) => IntegerType::NotInteger,
+            (
+                swc::BinaryOp::Add | swc::BinaryOp::Mul,
+                IntegerType::Integer(NumberType::Usize, _),
+            ) => IntegerType::integer(NumberType::Usize, magnitude),
+            (swc::BinaryOp::Add | swc::BinaryOp::Mul | swc::BinaryOp::Sub, _) => {
+                IntegerType::integer(NumberType::I64, magnitude)
+            }
+            _ => IntegerType::NotInteger,
+        }
+    }
+
+    /// The type of `self % divisor`, which has the sign of `self`.
+    fn remainder(self, divisor: &swc::Expr) -> IntegerType {
+        match remainder_magnitude(self.magnitude(), divisor) {
+            Some(magnitude) => self.with_magnitude(magnitude),
+            None => IntegerType::NotInteger,
+        }
+    }
+}
+
+/// A `number` binding that provably only holds integers, see `find_integer_bindings`.
+#[derive(Clone, Copy, Debug)]
+pub struct IntegerBinding {
+    /// The integer type to represent the binding.
+    pub number_type: NumberType,
+    /// The largest magnitude the binding can have.
+    pub magnitude: u64,
+    /// Whether the binding is never negative, e.g. a counter counting up from zero.
+    pub non_negative: bool,
+}
+
+/// Finds the `number` bindings that provably only hold integers, together with the integer type to represent them
+/// and the largest magnitude they can have.
+///
+/// A binding is integral if every definition of it is, starting from integer literals, `.length`, bitwise operators
+/// like the `x | 0` idiom, `Math.floor` and friends of finite values, and integral bindings combined with `+`, `-`,
+/// `*` and `%` by a non-zero literal. Bitwise results are `i32`, lengths and bindings only counting up from them
+/// `usize`, and everything else `i64`.
+///
+/// Integers can't represent `-0`, which `1 / x` tells apart from `0`, so definitions that may be `-0` aren't
+/// integral, like `-x`, `x % 2` for a negative `x` or `Math.round(-0.2)`, see `may_be_negative_zero`.
+///
+/// Results that could overflow `i64` aren't integral. Bindings whose definitions depend on themselves, like
+/// `x = x * 3` in a loop, would grow without bound, so they are only integral if they are counters, see
+/// `COUNTER_MAGNITUDE`.
+pub fn find_integer_bindings<N: VisitWith<Definitions>>(
+    node: &N,
+) -> HashMap<String, IntegerBinding> {
+    let mut definitions = Definitions::default();
+    node.visit_with(&mut definitions);
+
+    let cyclic = cyclic_definitions(&definitions);
+    let mut types: HashMap<String, IntegerType> = definitions
+        .definitions
+        .keys()
+        .map(|ident| (ident.clone(), IntegerType::Literal(0)))
+        .collect();
+
+    // Without cycles, every binding is final after as many passes as there are bindings. Bindings on a cycle that
+    // still grow afterwards are widened to counters once, and aren't integral if they grow any further, so types only
+    // get less specific and this reaches a fixpoint.
+    let widening_pass = definitions.definitions.len() + 1;
+
+    for pass in 0.. {
+        let previous = types.clone();
+        let mut changed = false;
+
+        for (ident, ident_definitions) in &definitions.definitions {
+            let current = previous[ident];
+            let mut integer_type =
+                ident_definitions
+                    .iter()
+                    .fold(current, |integer_type, definition| {
+                        integer_type.join(definition_integer_type(&previous, current, definition))
+                    });
+
+            if pass >= widening_pass
+                && cyclic.contains(ident)
+                && integer_type.magnitude() > current.magnitude()
+            {
+                integer_type = if integer_type.magnitude() <= COUNTER_MAGNITUDE {
+                    integer_type.with_magnitude(COUNTER_MAGNITUDE)
+                } else {
+                    IntegerType::NotInteger
+                };
+            }
+
+            if integer_type != current {
+                types.insert(ident.clone(), integer_type);
+                changed = true;
+            }
+        }
+
+        if !changed {
+            break;
+        }
+    }
+
+    types
+        .into_iter()
+        .filter_map(|(ident, integer_type)| {
+            let number_type = match integer_type {
+                IntegerType::Literal(_) => NumberType::I64,
+                IntegerType::Integer(number_type, _) => number_type,
+                IntegerType::NotInteger => return None,
+            };
+            let binding = IntegerBinding {
+                number_type,
+                magnitude: integer_type.magnitude(),
+                non_negative: integer_type.is_non_negative(),
+            };
+
+            Some((ident, binding))
+        })
+        .collect()
+}
+
+/// The bindings whose definitions depend on themselves, directly like `x++` or through other bindings.
+fn cyclic_definitions(definitions: &Definitions) -> HashSet<String> {
+    let dependencies: HashMap<&String, HashSet<String>> = definitions
+        .definitions
+        .iter()
+        .map(|(ident, ident_definitions)| {
+            let mut dependencies = HashSet::new();
+
+            for definition in ident_definitions {
+                match definition {
+                    Definition::Value(expr) => dependencies.extend(find_idents(expr)),
+                    Definition::Assign(_, expr) => {
+                        dependencies.insert(ident.clone());
+                        dependencies.extend(find_idents(expr));
+                    }
+                    Definition::Update(_) => {
+                        dependencies.insert(ident.clone());
+                    }
+                    Definition::Unknown => {}
+                }
+            }
+
+            (ident, dependencies)
+        })
+        .collect();
+
+    dependencies
+        .keys()
+        .filter(|ident| {
+            let mut visited = HashSet::new();
+            let mut pending: Vec<&String> = dependencies[*ident].iter().collect();
+
+            while let Some(dependency) = pending.pop() {
+                if dependency == **ident {
+                    return true;
+                }
+
+                if visited.insert(dependency) {
+                    if let Some(next) = dependencies.get(dependency) {
+                        pending.extend(next);
+                    }
+                }
+            }
+
+            false
+        })
+        .map(|ident| (*ident).clone())
+        .collect()
+}
+
+fn definition_integer_type(
+    types: &HashMap<String, IntegerType>,
+    current: IntegerType,
+    definition: &Definition,
+) -> IntegerType {
+    match definition {
+        Definition::Value(expr) => expr_integer_type(types, expr),
+        Definition::Assign(op, expr) => {
+            let value = expr_integer_type(types, expr);
+
+            match (op, value) {
+                (swc::AssignOp::AddAssign, IntegerType::Literal(step)) => current.increment(step),
+                (swc::AssignOp::AddAssign, _) => {
+                    IntegerType::arithmetic(swc::BinaryOp::Add, current, value)
+                }
+                (swc::AssignOp::SubAssign, _) => {
+                    IntegerType::arithmetic(swc::BinaryOp::Sub, current, value)
+                }
+                // Zero times a negative number is `-0`.
+                (swc::AssignOp::MulAssign, _)
+                    if !value.is_non_negative()
+                        || (!current.is_non_negative() && may_be_zero(expr)) =>
+                {
+                    IntegerType::NotInteger
+                }
+                (swc::AssignOp::MulAssign, _) => {
+                    IntegerType::arithmetic(swc::BinaryOp::Mul, current, value)
+                }
+                // The remainder of a negative multiple is `-0`.
+                (swc::AssignOp::ModAssign, _)
+                    if value != IntegerType::NotInteger && current.is_non_negative() =>
+                {
+                    current.remainder(expr)
+                }
+                (
+                    swc::AssignOp::BitAndAssign
+                    | swc::AssignOp::BitOrAssign
+                    | swc::AssignOp::BitXorAssign
+                    | swc::AssignOp::LShiftAssign
+                    | swc::AssignOp::RShiftAssign,
+                    _,
+                ) => IntegerType::Integer(NumberType::I32, I32_MAGNITUDE),
+                (swc::AssignOp::ZeroFillRShiftAssign, _) => {
+                    IntegerType::Integer(NumberType::I64, u32::MAX as u64)
+                }
+                _ => IntegerType::NotInteger,
+            }
+        }
+        Definition::Update(swc::UpdateOp::PlusPlus) => current.increment(1),
+        Definition::Update(swc::UpdateOp::MinusMinus) => {
+            IntegerType::arithmetic(swc::BinaryOp::Sub, current, IntegerType::Literal(1))
+        }
+        Definition::Unknown => IntegerType::NotInteger,
+    }
+}
+
+fn expr_integer_type(types: &HashMap<String, IntegerType>, expr: &swc::Expr) -> IntegerType {
+    if may_be_negative_zero(types, expr) {
+        return IntegerType::NotInteger;
+    }
+
+    if let Some(value) = integer_lit(expr) {
+        return if value >= 0 {
+            IntegerType::literal(value.unsigned_abs())
+        } else {
+            IntegerType::integer(NumberType::I64, value.unsigned_abs())
+        };
+    }
+
+    match expr {
+        swc::Expr::Ident(ident) => types
+            .get(ident.sym.as_str())
+            .copied()
+            .unwrap_or(IntegerType::NotInteger),
+        swc::Expr::Paren(paren) => expr_integer_type(types, &paren.expr),
+        swc::Expr::Unary(unary) => match unary.op {
+            swc::UnaryOp::Minus => IntegerType::arithmetic(
+                swc::BinaryOp::Sub,
+                IntegerType::Literal(0),
+                expr_integer_type(types, &unary.arg),
+            ),
+            swc::UnaryOp::Plus => expr_integer_type(types, &unary.arg),
+            swc::UnaryOp::Tilde => IntegerType::Integer(NumberType::I32, I32_MAGNITUDE),
+            _ => IntegerType::NotInteger,
+        },
+        swc::Expr::Bin(bin) => match bin.op {
+            swc::BinaryOp::BitAnd
+            | swc::BinaryOp::BitOr
+            | swc::BinaryOp::BitXor
+            | swc::BinaryOp::LShift
+            | swc::BinaryOp::RShift => IntegerType::Integer(NumberType::I32, I32_MAGNITUDE),
+            // Results are in the `u32` range.
+            swc::BinaryOp::ZeroFillRShift => IntegerType::Integer(NumberType::I64, u32::MAX as u64),
+            swc::BinaryOp::LogicalOr | swc::BinaryOp::LogicalAnd => {
+                expr_integer_type(types, &bin.left).join(expr_integer_type(types, &bin.right))
+            }
+            swc::BinaryOp::Mod => match expr_integer_type(types, &bin.right) {
+                IntegerType::NotInteger => IntegerType::NotInteger,
+                _ => expr_integer_type(types, &bin.left).remainder(&bin.right),
+            },
+            op => IntegerType::arithmetic(
+                op,
+                expr_integer_type(types, &bin.left),
+                expr_integer_type(types, &bin.right),
+            ),
+        },
+        swc::Expr::Cond(cond) => {
+            expr_integer_type(types, &cond.cons).join(expr_integer_type(types, &cond.alt))
+        }
+        swc::Expr::Seq(seq) => expr_integer_type(types, seq.exprs.last().expect("Seq has Exprs.")),
+        swc::Expr::Member(member)
+            if member
+                .prop
+                .as_ident()
+                .is_some_and(|prop| prop.sym.as_str() == "length") =>
+        {
+            IntegerType::Integer(NumberType::Usize, u32::MAX as u64)
+        }
+        swc::Expr::Call(call) if is_math_rounding_call(call) => {
+            match call.args.first().filter(|arg| arg.spread.is_none()) {
+                Some(arg) => match finite_magnitude(types, &arg.expr) {
+                    Some(magnitude) => IntegerType::integer(NumberType::I64, magnitude),
+                    None => IntegerType::NotInteger,
+                },
+                None => IntegerType::NotInteger,
+            }
+        }
+        _ => IntegerType::NotInteger,
+    }
+}
+
+/// Whether a value may be `-0`, e.g. for `-0`, `-x`, `x * -2` or `x % 2` when `x` may be zero or negative, and for
+/// `Math.ceil`, `Math.round` and `Math.trunc` of negative fractions. Integer bindings are never `-0`.
+fn may_be_negative_zero(types: &HashMap<String, IntegerType>, expr: &swc::Expr) -> bool {
+    let may_be_negative_zero = |expr| may_be_negative_zero(types, expr);
+    let non_negative = |expr| non_negative(types, expr);
+
+    match expr {
+        swc::Expr::Lit(_) => false,
+        swc::Expr::Ident(ident) => types
+            .get(ident.sym.as_str())
+            .is_none_or(|integer_type| *integer_type == IntegerType::NotInteger),
+        swc::Expr::Paren(paren) => may_be_negative_zero(&paren.expr),
+        swc::Expr::Unary(unary) => match unary.op {
+            swc::UnaryOp::Minus => may_be_zero(&unary.arg),
+            swc::UnaryOp::Plus => may_be_negative_zero(&unary.arg),
+            _ => false,
+        },
+        swc::Expr::Bin(bin) => match bin.op {
+            swc::BinaryOp::Add => {
+                may_be_negative_zero(&bin.left) && may_be_negative_zero(&bin.right)
+            }
+            swc::BinaryOp::Sub => may_be_negative_zero(&bin.left),
+            // Zero times or divided by a negative number is `-0`.
+            swc::BinaryOp::Mul | swc::BinaryOp::Div => {
+                (may_be_zero(&bin.left) && !non_negative(&bin.right))
+                    || (!non_negative(&bin.left) && may_be_zero(&bin.right))
+                    || may_be_negative_zero(&bin.left)
+                    || may_be_negative_zero(&bin.right)
+            }
+            // The remainder of a negative multiple is `-0`.
+            swc::BinaryOp::Mod => !non_negative(&bin.left),
+            swc::BinaryOp::BitAnd
+            | swc::BinaryOp::BitOr
+            | swc::BinaryOp::BitXor
+            | swc::BinaryOp::LShift
+            | swc::BinaryOp::RShift
+            | swc::BinaryOp::ZeroFillRShift => false,
+            swc::BinaryOp::LogicalOr | swc::BinaryOp::LogicalAnd => {
+                may_be_negative_zero(&bin.left) || may_be_negative_zero(&bin.right)
+            }
+            _ => true,
+        },
+        swc::Expr::Cond(cond) => {
+            may_be_negative_zero(&cond.cons) || may_be_negative_zero(&cond.alt)
+        }
+        swc::Expr::Seq(seq) => may_be_negative_zero(seq.exprs.last().expect("Seq has Exprs.")),
+        swc::Expr::Member(member)
+            if member
+                .prop
+                .as_ident()
+                .is_some_and(|prop| prop.sym.as_str() == "length") =>
+        {
+            false
+        }
+        swc::Expr::Call(call) if is_math_rounding_call(call) => {
+            let Some(arg) = call.args.first().filter(|arg| arg.spread.is_none()) else {
+                return false;
+            };
+
+            match (math_method(call), number_lit(&arg.expr)) {
+                (Some("floor"), _) => may_be_negative_zero(&arg.expr),
+                // `Math.round` rounds halves up, so `-0.5` rounds to `-0`.
+                (Some("round"), Some(value)) => value.is_sign_negative() && value >= -0.5,
+                (_, Some(value)) => value.is_sign_negative() && value > -1.0,
+                _ => {
+                    !non_negative(&arg.expr)
+                        && expr_integer_type(types, &arg.expr) == IntegerType::NotInteger
+                }
+            }
+        }
+        swc::Expr::Call(call) if matches!(math_method(call), Some("random" | "abs")) => false,
+        _ => true,
+    }
+}
+
+/// Whether a value is never negative nor `-0`, e.g. a positive literal, a length or a counter counting up from zero.
+fn non_negative(types: &HashMap<String, IntegerType>, expr: &swc::Expr) -> bool {
+    if let Some(value) = number_lit(expr) {
+        return value.is_sign_positive();
+    }
+
+    match expr {
+        swc::Expr::Ident(ident) => types
+            .get(ident.sym.as_str())
+            .is_some_and(|integer_type| integer_type.is_non_negative()),
+        swc::Expr::Paren(paren) => non_negative(types, &paren.expr),
+        swc::Expr::Bin(bin) => match bin.op {
+            swc::BinaryOp::Add | swc::BinaryOp::Mul | swc::BinaryOp::Div => {
+                non_negative(types, &bin.left) && non_negative(types, &bin.right)
+            }
+            swc::BinaryOp::Mod => non_negative(types, &bin.left),
+            swc::BinaryOp::ZeroFillRShift => true,
+            _ => false,
+        },
+        swc::Expr::Member(member) => member
+            .prop
+            .as_ident()
+            .is_some_and(|prop| prop.sym.as_str() == "length"),
+        swc::Expr::Call(call) => match math_method(call) {
+            Some("random" | "abs") => true,
+            Some("floor" | "ceil" | "round" | "trunc") => call
+                .args
+                .first()
+                .is_some_and(|arg| arg.spread.is_none() && non_negative(types, &arg.expr)),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Whether a value may be zero, which only number literals other than `0` rule out.
+fn may_be_zero(expr: &swc::Expr) -> bool {
+    number_lit(expr).is_none_or(|value| value == 0.0)
+}
+
+/// The value of a number literal, including negated literals like `-0.5`.
+pub fn number_lit(expr: &swc::Expr) -> Option<f64> {
+    match expr {
+        swc::Expr::Lit(swc::Lit::Num(num)) => Some(num.value),
+        swc::Expr::Unary(unary) if unary.op == swc::UnaryOp::Minus => {
+            number_lit(&unary.arg).map(|value| -value)
+        }
+        swc::Expr::Paren(paren) => number_lit(&paren.expr),
+        _ => None,
+    }
+}
+
+/// The largest magnitude of a value that is provably finite, i.e. a number literal, an integer, an integer divided by
+/// a non-zero literal, or `Math.random()` times an integer. Other values may be NaN or infinite, which `Math.floor`
+/// and friends keep.
+fn finite_magnitude(types: &HashMap<String, IntegerType>, expr: &swc::Expr) -> Option<u64> {
+    let integer_magnitude = |expr: &swc::Expr| match expr_integer_type(types, expr) {
+        IntegerType::NotInteger => None,
+        integer_type => Some(integer_type.magnitude()),
+    };
+
+    match expr {
+        swc::Expr::Lit(swc::Lit::Num(num)) if num.value.is_finite() => {
+            Some(num.value.abs().ceil() as u64)
+        }
+        swc::Expr::Unary(unary) if unary.op == swc::UnaryOp::Minus => {
+            finite_magnitude(types, &unary.arg)
+        }
+        swc::Expr::Paren(paren) => finite_magnitude(types, &paren.expr),
+        swc::Expr::Bin(bin) if bin.op == swc::BinaryOp::Div => {
+            integer_lit(&bin.right).filter(|divisor| *divisor != 0)?;
+            integer_magnitude(&bin.left)
+        }
+        swc::Expr::Bin(bin)
+            if bin.op == swc::BinaryOp::Mul && is_math_call(&bin.left, "random") =>
+        {
+            integer_magnitude(&bin.right)
+        }
+        swc::Expr::Bin(bin)
+            if bin.op == swc::BinaryOp::Mul && is_math_call(&bin.right, "random") =>
+        {
+            integer_magnitude(&bin.left)
+        }
+        expr => integer_magnitude(expr),
+    }
+}
+
+/// Whether a call is `Math.floor`, `Math.ceil`, `Math.round` or `Math.trunc`.
+fn is_math_rounding_call(call: &swc::CallExpr) -> bool {
+    math_method(call).is_some_and(|method| matches!(method, "floor" | "ceil" | "round" | "trunc"))
+}
+
+fn is_math_call(expr: &swc::Expr, name: &str) -> bool {
+    expr.as_call()
+        .and_then(math_method)
+        .is_some_and(|method| method == name)
+}
+
+/// The name of the `Math` method a call calls, e.g. `floor` for `Math.floor(x)`.
+fn math_method(call: &swc::CallExpr) -> Option<&str> {
+    let swc::Expr::Member(member) = &**call.callee.as_expr()? else {
+        return None;
+    };
+
+    member
+        .obj
+        .as_ident()
+        .is_some_and(|obj| obj.sym.as_str() == "Math")
+        .then(|| member.prop.as_ident().map(|prop| prop.sym.as_str()))
+        .flatten()
+}
@@ -0,0 +1,362 @@
+use std::collections::{HashMap, HashSet};
+
+use swc_ecma_ast as swc;
+
+use crate::{
+    analysis::{
+        find_integer_bindings, find_modifications, find_mutating_callbacks, find_shared_bindings,
+        IntegerBinding,
+    },
+    types::{FnSignature, NumberType, Overload, ValueType},
+    OverloadStrategy, TranspileOptions,
+};
+
+/// State shared while transpiling a module, such as the types of the bindings in scope.
+#[derive(Clone, Debug)]
+pub struct Context {
+    scopes: Vec<HashMap<String, ValueType>>,
+    /// The largest magnitudes of integer bindings per scope, where they are known from their declaration.
+    magnitudes: Vec<HashMap<String, u64>>,
+    /// `number` bindings proven to only hold integers, with their largest magnitude, see `find_integer_bindings`.
+    integer_bindings: HashMap<String, IntegerBinding>,
+    /// Bindings whose properties are written somewhere, so they need to be `mut` even if they are `const`.
+    property_written: HashSet<String>,
+    /// Bindings that are assigned or updated somewhere after their declaration.
+    reassigned: HashSet<String>,
+    /// Bindings that methods changing them in place are called on, e.g. `push`.
+    method_receivers: HashSet<String>,
+    /// `string` bindings that hold a `String` rather than a `&str`, see `declare_string`.
+    owned_strings: HashSet<String>,
+    /// Function bindings that hold a closure rather than an `Rc<dyn Fn>`, see `declare_closure`.
+    closures: HashSet<String>,
+    /// Bindings that closures share with the code around them as `Shared` cells, see `find_shared_bindings`.
+    shared_bindings: HashSet<String>,
+    /// Bindings that are declared as `Shared` cells, see `declare_shared`.
+    shared: HashSet<String>,
+    /// Bindings holding callbacks that assign to captured bindings, see `find_mutating_callbacks`.
+    mutating_closures: HashMap<String, HashSet<String>>,
+    /// Bindings holding callbacks with the captured bindings they assign to, including `Shared` ones.
+    closure_assignments: HashMap<String, HashSet<String>>,
+    /// Bindings holding callbacks with the captured bindings they may change in place, e.g. by calling `push`.
+    closure_mutations: HashMap<String, HashSet<String>>,
+    /// Function parameters that are passed such callbacks, so they need to be `FnMut`.
+    mutating_callback_params: HashSet<(String, usize)>,
+    /// Declared functions, which are hoisted so they can be called before their declaration.
+    functions: HashMap<String, FnSignature>,
+    /// Declared functions with overload signatures, see `declare_overloads`.
+    overloads: HashMap<String, Vec<Overload>>,
+    overload_strategy: OverloadStrategy,
+    /// The fields of the structs generated for interfaces and object type aliases, by name, see `declare_struct`.
+    structs: HashMap<String, Vec<(String, ValueType)>>,
+    /// Array bindings whose length never changes, see `declare_array_length`.
+    array_lengths: HashMap<String, usize>,
+    /// Return types of the functions being transpiled, innermost last.
+    return_types: Vec<Option<ValueType>>,
+    /// The `Yielder` bindings of the async generator bodies being transpiled, with their yield types.
+    yielders: Vec<(String, ValueType)>,
+}
+
+impl Context {
+    pub fn new() -> Self {
+        Context {
+            scopes: vec![HashMap::new()],
+            magnitudes: vec![HashMap::new()],
+            integer_bindings: HashMap::new(),
+            property_written: HashSet::new(),
+            reassigned: HashSet::new(),
+            method_receivers: HashSet::new(),
+            owned_strings: HashSet::new(),
+            closures: HashSet::new(),
+            shared_bindings: HashSet::new(),
+            shared: HashSet::new(),
+            mutating_closures: HashMap::new(),
+            closure_assignments: HashMap::new(),
+            closure_mutations: HashMap::new(),
+            mutating_callback_params: HashSet::new(),
+            functions: HashMap::new(),
+            overloads: HashMap::new(),
+            overload_strategy: OverloadStrategy::Split,
+            structs: HashMap::new(),
+            array_lengths: HashMap::new(),
+            return_types: vec![],
+            yielders: vec![],
+        }
+    }
+
+    /// Creates the context for a module, analyzing how its bindings are used.
+    pub fn for_module(module: &swc::Module, options: &TranspileOptions) -> Self {
+        let modifications = find_modifications(module);
+        let shared_bindings = find_shared_bindings(module);
+        let mutating_callbacks = find_mutating_callbacks(module, &shared_bindings);
+        let callbacks = find_mutating_callbacks(module, &HashSet::new());
+
+        Context {
+            integer_bindings: if options.integer_narrowing {
+                find_integer_bindings(module)
+            } else {
+                HashMap::new()
+            },
+            property_written: modifications.property_written,
+            reassigned: modifications.reassigned,
+            method_receivers: modifications.method_receivers,
+            shared_bindings,
+            mutating_closures: mutating_callbacks.closures,
+            closure_assignments: callbacks.closures,
+            closure_mutations: callbacks.mutations,
+            mutating_callback_params: mutating_callbacks.params,
+            overload_strategy: options.overloads,
+            ..Context::new()
+        }
+    }
+
+    /// Runs `f` in a new block scope, discarding the bindings it declares afterwards.
+    pub fn with_scope<T, F: FnOnce(&mut Context) -> T>(&mut self, f: F) -> T {
+        self.scopes.push(HashMap::new());
+        self.magnitudes.push(HashMap::new());
+        let result = f(self);
+        self.scopes.pop();
+        self.magnitudes.pop();
+        result
+    }
+
+    pub fn declare(&mut self, ident: &str, value_type: ValueType) {
+        self.shared.remove(ident);
+        self.magnitudes
+            .last_mut()
+            .expect("Context has a scope.")
+            .remove(ident);
+        self.scopes
+            .last_mut()
+            .expect("Context has a scope.")
+            .insert(ident.to_string(), value_type);
+    }
+
+    pub fn lookup(&self, ident: &str) -> Option<&ValueType> {
+        self.scopes.iter().rev().find_map(|scope| scope.get(ident))
+    }
+
+    /// Records the largest magnitude of an integer binding declared in the current scope, e.g. of a loop variable
+    /// counting up to a bound.
+    pub fn declare_magnitude(&mut self, ident: &str, magnitude: u64) {
+        self.magnitudes
+            .last_mut()
+            .expect("Context has a scope.")
+            .insert(ident.to_string(), magnitude);
+    }
+
+    /// Whether an integer binding is never negative, e.g. a counter counting up from zero.
+    pub fn is_non_negative_integer(&self, ident: &str) -> bool {
+        self.integer_bindings
+            .get(ident)
+            .is_some_and(|binding| binding.non_negative)
+    }
+
+    pub fn integer_binding(&self, ident: &str) -> Option<NumberType> {
+        self.integer_bindings
+            .get(ident)
+            .map(|binding| binding.number_type)
+    }
+
+    /// The largest magnitude of an integer binding, from its declaration or from narrowing.
+    pub fn integer_magnitude(&self, ident: &str) -> Option<u64> {
+        let declared = self
+            .scopes
+            .iter()
+            .zip(&self.magnitudes)
+            .rev()
+            .find(|(scope, _)| scope.contains_key(ident))
+            .and_then(|(_, magnitudes)| magnitudes.get(ident).copied());
+
+        declared.or_else(|| {
+            self.integer_bindings
+                .get(ident)
+                .map(|binding| binding.magnitude)
+        })
+    }
+
+    pub fn is_property_written(&self, ident: &str) -> bool {
+        self.property_written.contains(ident)
+    }
+
+    pub fn is_reassigned(&self, ident: &str) -> bool {
+        self.reassigned.contains(ident)
+    }
+
+    pub fn is_method_receiver(&self, ident: &str) -> bool {
+        self.method_receivers.contains(ident)
+    }
+
+    /// Records whether a `string` binding owns its value as a `String` or borrows it as a `&str`.
+    pub fn declare_string(&mut self, ident: &str, owned: bool) {
+        if owned {
+            self.owned_strings.insert(ident.to_string());
+        } else {
+            self.owned_strings.remove(ident);
+        }
+    }
+
+    pub fn is_owned_string(&self, ident: &str) -> bool {
+        self.lookup(ident) == Some(&ValueType::String) && self.owned_strings.contains(ident)
+    }
+
+    /// Records whether a function binding holds a closure or an `Rc<dyn Fn>`, which closures are converted to when
+    /// they are stored in arrays or reassigned.
+    pub fn declare_closure(&mut self, ident: &str, closure: bool) {
+        if closure {
+            self.closures.insert(ident.to_string());
+        } else {
+            self.closures.remove(ident);
+        }
+    }
+
+    pub fn is_closure(&self, ident: &str) -> bool {
+        matches!(self.lookup(ident), Some(ValueType::Function(_))) && self.closures.contains(ident)
+    }
+
+    /// Whether closures share a binding with the code around them, so it should be declared as a `Shared` cell.
+    pub fn is_shared_binding(&self, ident: &str) -> bool {
+        self.shared_bindings.contains(ident)
+    }
+
+    /// Records that a binding is declared as a `Shared` cell, which is read with `get` and assigned with `set`.
+    pub fn declare_shared(&mut self, ident: &str) {
+        self.shared.insert(ident.to_string());
+    }
+
+    pub fn is_shared(&self, ident: &str) -> bool {
+        self.shared.contains(ident)
+    }
+
+    /// Runs `f` with a `Shared` binding treated as a plain local, for a block that copies the cell's value into it.
+    pub fn with_unshared<T, F: FnOnce(&mut Context) -> T>(&mut self, ident: &str, f: F) -> T {
+        self.shared.remove(ident);
+        let result = f(self);
+        self.shared.insert(ident.to_string());
+        result
+    }
+
+    pub fn is_mutating_closure(&self, ident: &str) -> bool {
+        self.mutating_closures.contains_key(ident)
+    }
+
+    /// The captured bindings that the callback held by a binding assigns to when it is called.
+    pub fn closure_assignments(&self, ident: &str) -> Option<&HashSet<String>> {
+        self.closure_assignments.get(ident)
+    }
+
+    /// The captured bindings that the callback held by a binding may change in place when it is called.
+    pub fn closure_mutations(&self, ident: &str) -> Option<&HashSet<String>> {
+        self.closure_mutations.get(ident)
+    }
+
+    /// Whether a parameter of a function is passed callbacks that assign to captured bindings.
+    pub fn is_mutating_callback_param(&self, function: &str, index: usize) -> bool {
+        self.mutating_callback_params
+            .contains(&(function.to_string(), index))
+    }
+
+    pub fn declare_function(&mut self, ident: &str, signature: FnSignature) {
+        self.overloads.remove(ident);
+        self.functions.insert(ident.to_string(), signature);
+    }
+
+    /// Records the fields of the struct generated for an interface or object type alias, with the types of their
+    /// properties, where optional properties are `Option`s.
+    pub fn declare_struct(&mut self, name: &str, fields: Vec<(String, ValueType)>) {
+        self.structs.insert(name.to_string(), fields);
+    }
+
+    pub fn struct_fields(&self, name: &str) -> Option<&[(String, ValueType)]> {
+        self.structs.get(name).map(Vec::as_slice)
+    }
+
+    /// The type of a field of a struct, if the struct has it.
+    pub fn struct_field(&self, name: &str, key: &str) -> Option<&ValueType> {
+        self.struct_fields(name)?
+            .iter()
+            .find(|(field, _)| field == key)
+            .map(|(_, value_type)| value_type)
+    }
+
+    /// Records the overloads of a function, which calls are resolved to by the types of their arguments.
+    pub fn declare_overloads(&mut self, ident: &str, overloads: Vec<Overload>) {
+        self.functions.remove(ident);
+        self.overloads.insert(ident.to_string(), overloads);
+    }
+
+    pub fn function(&self, ident: &str) -> Option<&FnSignature> {
+        // Bindings declared later shadow functions of the same name.
+        if self.lookup(ident).is_some() {
+            return None;
+        }
+
+        self.functions.get(ident)
+    }
+
+    pub fn overloads(&self, ident: &str) -> Option<&[Overload]> {
+        if self.lookup(ident).is_some() {
+            return None;
+        }
+
+        self.overloads.get(ident).map(Vec::as_slice)
+    }
+
+    pub fn overload_strategy(&self) -> OverloadStrategy {
+        self.overload_strategy
+    }
+
+    /// Records the length of an array binding, if it is statically known and never changes.
+    pub fn declare_array_length(&mut self, ident: &str, length: Option<usize>) {
+        match length {
+            Some(length) => self.array_lengths.insert(ident.to_string(), length),
+            None => self.array_lengths.remove(ident),
+        };
+    }
+
+    pub fn array_length(&self, ident: &str) -> Option<usize> {
+        self.array_lengths.get(ident).copied()
+    }
+
+    /// Runs `f` for the body of a function that returns `return_type`.
+    pub fn with_function<T, F: FnOnce(&mut Context) -> T>(
+        &mut self,
+        return_type: Option<ValueType>,
+        f: F,
+    ) -> T {
+        self.return_types.push(return_type);
+        let result = self.with_scope(f);
+        self.return_types.pop();
+        result
+    }
+
+    /// The return type of the function being transpiled, `None` if it doesn't return a value.
+    pub fn return_type(&self) -> Option<&ValueType> {
+        self.return_types.last().and_then(Option::as_ref)
+    }
+
+    /// Runs `f` for the body of an async generator, which yields values of `yield_type` through `yielder`.
+    pub fn with_yielder<T, F: FnOnce(&mut Context) -> T>(
+        &mut self,
+        yielder: &str,
+        yield_type: ValueType,
+        f: F,
+    ) -> T {
+        self.yielders.push((yielder.to_string(), yield_type));
+        let result = f(self);
+        self.yielders.pop();
+        result
+    }
+
+    /// The `Yielder` binding of the async generator being transpiled, with its yield type.
+    pub fn yielder(&self) -> Option<(&str, &ValueType)> {
+        self.yielders
+            .last()
+            .map(|(yielder, yield_type)| (yielder.as_str(), yield_type))
+    }
+}
+
+impl Default for Context {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,23 +1,48 @@
+use std::collections::HashMap;
+
+use quote::quote;
 use swc_ecma_ast as swc;
-use syn::*;
+use syn::{punctuated::Punctuated, *};
 
 use crate::{
-    expr::transpile_expr,
+    analysis::find_idents,
+    context::Context,
+    expr::{
+        is_nullish_lit, is_owned_string, transpile_closure, transpile_owned_string,
+        transpile_owned_value, transpile_value,
+    },
+    generator::{transpile_async_generator, transpile_generator},
+    module::generate_allow_clippy_all_attribute,
+    stmt::transpile_stmts,
+    types::{
+        annotation_type, borrowed_type, function_expr_parts, generate_fn_bound, infer_type,
+        owned_type, param_type, return_annotation_type, struct_field_name, struct_fields,
+        FnSignature, NumberType, Overload, ValueType,
+    },
     util::{dummy_span, ExprOrStmt},
+    OverloadStrategy,
 };
 
-pub fn transpile_decl(decl: swc::Decl) -> Vec<ExprOrStmt> {
+pub fn transpile_decl(ctx: &mut Context, decl: swc::Decl) -> Vec<ExprOrStmt> {
     if decl.is_class() {
         todo!("decl class")
     } else if decl.is_fn_decl() {
-        todo!("decl fn")
+        transpile_fn_decl(ctx, decl.fn_decl().expect("Decl is FnDecl."))
+            .into_iter()
+            .map(|item| ExprOrStmt::Stmt(Stmt::Item(item)))
+            .collect()
     } else if decl.is_var() {
-        transpile_var(*decl.var().expect("Decl is Var."))
+        transpile_var(ctx, *decl.var().expect("Decl is Var."))
             .into_iter()
-            .map(ExprOrStmt::Expr)
+            .map(ExprOrStmt::Stmt)
             .collect()
     } else if decl.is_using() {
         todo!("decl using")
+    } else if let Some((name, _)) = struct_decl(&decl) {
+        transpile_struct_decl(ctx, &name)
+            .into_iter()
+            .map(|item| ExprOrStmt::Stmt(Stmt::Item(item)))
+            .collect()
     } else if decl.is_ts_interface() {
         todo!("decl ts interface")
     } else if decl.is_ts_type_alias() {
@@ -31,7 +56,7 @@ pub fn transpile_decl(decl: swc::Decl) -> Vec<ExprOrStmt> {
     }
 }
 
-pub fn transpile_var(var: swc::VarDecl) -> Vec<Expr> {
+pub fn transpile_var(ctx: &mut Context, var: swc::VarDecl) -> Vec<Stmt> {
     if var.declare {
         todo!("var declare")
     }
@@ -39,41 +64,142 @@ pub fn transpile_var(var: swc::VarDecl) -> Vec<Expr> {
     var.decls
         .clone()
         .into_iter()
-        .map(|declarator| transpile_var_declarator(&var, declarator))
+        .map(|declarator| transpile_var_declarator(ctx, &var, declarator))
         .collect()
 }
 
-pub fn transpile_var_declarator(var: &swc::VarDecl, declarator: swc::VarDeclarator) -> Expr {
+pub fn transpile_var_declarator(
+    ctx: &mut Context,
+    var: &swc::VarDecl,
+    declarator: swc::VarDeclarator,
+) -> Stmt {
     if declarator.init.is_none() {
         todo!("declarator init is none")
     }
 
     if declarator.name.is_ident() {
-        Expr::Let(ExprLet {
-            attrs: vec![],
+        let ident = declarator.name.clone().ident().expect("Pat is Ident.");
+        let init = *declarator.init.expect("Init expected.");
+        let array_length = static_array_length(ctx, var, ident.id.sym.as_str(), &init);
+
+        let value_type = match (
+            ctx.integer_binding(ident.id.sym.as_str()),
+            &ident.type_ann,
+            infer_type(ctx, &init),
+        ) {
+            (Some(number_type), _, _) => ValueType::Number(number_type),
+            (None, Some(type_ann), _) => annotation_type(&type_ann.type_ann),
+            // Bindings that can be reassigned may hold a float later on, so integers are only kept for constants.
+            (None, None, ValueType::Number(number_type))
+                if number_type.is_integer() && var.kind != swc::VarDeclKind::Const =>
+            {
+                ValueType::Number(NumberType::F64)
+            }
+            (None, None, value_type) => value_type,
+        };
+
+        // Functions that are never reassigned are kept as closures, others are shared as `Rc<dyn Fn>`.
+        let closure = function_expr_parts(&init).is_some()
+            && (var.kind == swc::VarDeclKind::Const || !ctx.is_reassigned(ident.id.sym.as_str()));
+        let mutating_closure = closure && ctx.is_mutating_closure(ident.id.sym.as_str());
+        // Bindings that closures share with the code around them are `Shared` cells. Callbacks in the initializer
+        // may refer to the binding itself, e.g. to clear their own timer, so then the cell is created first.
+        let shared = !closure && ctx.is_shared_binding(ident.id.sym.as_str());
+        let self_referencing = shared && find_idents(&init).contains(ident.id.sym.as_str());
+
+        // Strings are borrowed `&str`s, unless they are reassigned or built at runtime, which needs a `String`.
+        let owned_string = value_type == ValueType::String
+            && (shared
+                || (var.kind != swc::VarDeclKind::Const
+                    && ctx.is_reassigned(ident.id.sym.as_str()))
+                || is_owned_string(ctx, &init));
+
+        if self_referencing {
+            ctx.declare_shared(ident.id.sym.as_str());
+        }
+
+        let annotation = match is_nullish_lit(ctx, &init) && !shared {
+            true => nullish_binding_type(&value_type, ctx.is_reassigned(ident.id.sym.as_str())),
+            false => None,
+        };
+
+        let init = if closure {
+            let expected = match &value_type {
+                ValueType::Function(signature) => Some((**signature).clone()),
+                _ => None,
+            };
+
+            transpile_closure(ctx, init, expected.as_ref(), false)
+        } else if owned_string {
+            transpile_owned_string(ctx, init)
+        } else {
+            transpile_value(ctx, init, &value_type)
+        };
+
+        ctx.declare(ident.id.sym.as_str(), value_type);
+        ctx.declare_string(ident.id.sym.as_str(), owned_string);
+        ctx.declare_array_length(ident.id.sym.as_str(), array_length);
+        ctx.declare_closure(ident.id.sym.as_str(), closure);
+
+        let ident_token = Ident::new(ident.id.sym.as_str(), dummy_span());
+        let init = if self_referencing {
+            parse_quote! {
+                {
+                    let #ident_token = Shared::default();
+                    #ident_token.set(#init);
+                    #ident_token
+                }
+            }
+        } else if shared {
+            parse_quote! { Shared::new(#init) }
+        } else {
+            init
+        };
+
+        if shared {
+            ctx.declare_shared(ident.id.sym.as_str());
+        }
+
+        Stmt::Local(Local {
+            // The callbacks may be the only ones that use the binding, e.g. an interval that clears itself.
+            attrs: if self_referencing {
+                vec![parse_quote!(#[allow(unused_variables)])]
+            } else {
+                vec![]
+            },
             let_token: token::Let(dummy_span()),
-            pat: Box::new(Pat::Ident(PatIdent {
-                attrs: vec![],
-                by_ref: None,
-                mutability: match var.kind {
-                    swc::VarDeclKind::Var => Some(token::Mut(dummy_span())),
-                    swc::VarDeclKind::Let => Some(token::Mut(dummy_span())),
-                    swc::VarDeclKind::Const => None,
-                },
-                ident: Ident::new(
-                    declarator
-                        .name
-                        .ident()
-                        .expect("Pat is Ident.")
-                        .id
-                        .sym
-                        .as_str(),
-                    dummy_span(),
-                ),
-                subpat: None,
-            })),
-            eq_token: token::Eq(dummy_span()),
-            expr: Box::new(transpile_expr(*declarator.init.expect("Init expected."))),
+            pat: generate_annotated_pat(
+                Pat::Ident(PatIdent {
+                    attrs: vec![],
+                    by_ref: None,
+                    mutability: match var.kind {
+                        // Cells are assigned through a shared reference.
+                        _ if shared => None,
+                        swc::VarDeclKind::Var => Some(token::Mut(dummy_span())),
+                        swc::VarDeclKind::Let => Some(token::Mut(dummy_span())),
+                        // Constant bindings can't be reassigned, but the object they hold can still be changed.
+                        swc::VarDeclKind::Const if mutating_closure => {
+                            Some(token::Mut(dummy_span()))
+                        }
+                        swc::VarDeclKind::Const
+                            if ctx.is_property_written(ident.id.sym.as_str())
+                                || ctx.is_method_receiver(ident.id.sym.as_str()) =>
+                        {
+                            Some(token::Mut(dummy_span()))
+                        }
+                        swc::VarDeclKind::Const => None,
+                    },
+                    ident: ident_token,
+                    subpat: None,
+                }),
+                annotation,
+            ),
+            init: Some(LocalInit {
+                eq_token: token::Eq(dummy_span()),
+                expr: Box::new(init),
+                diverge: None,
+            }),
+            semi_token: token::Semi(dummy_span()),
         })
     } else if declarator.name.is_array() {
         todo!("var delcarator array")
@@ -91,3 +217,792 @@ pub fn transpile_var_declarator(var: &swc::VarDecl, declarator: swc::VarDeclarat
         unreachable!("Unknown VarDeclarator.")
     }
 }
+
+/// The type of an optional binding that is initialized with `null` or `undefined`, e.g. `Option<f64>`, since Rust
+/// can't infer it from `None` if the binding is never assigned a value, or is used before.
+///
+/// Strings are borrowed unless they are reassigned, where the assigned values decide, and functions are left to
+/// inference, since they may be closures.
+fn nullish_binding_type(value_type: &ValueType, reassigned: bool) -> Option<Type> {
+    fn is_plain(value_type: &ValueType) -> bool {
+        match value_type {
+            ValueType::Boolean | ValueType::Number(_) | ValueType::Struct(_) => true,
+            ValueType::Array(value_type) | ValueType::Object(value_type) => is_plain(value_type),
+            _ => false,
+        }
+    }
+
+    match value_type {
+        ValueType::Option(inner) if **inner == ValueType::String && !reassigned => {
+            Some(parse_quote!(Option<&str>))
+        }
+        ValueType::Option(inner) if is_plain(inner) => Some(owned_type(value_type)),
+        _ => None,
+    }
+}
+
+/// Adds a type annotation to a pattern, if there is one.
+fn generate_annotated_pat(pat: Pat, annotation: Option<Type>) -> Pat {
+    match annotation {
+        Some(ty) => Pat::Type(PatType {
+            attrs: vec![],
+            pat: Box::new(pat),
+            colon_token: token::Colon(dummy_span()),
+            ty: Box::new(ty),
+        }),
+        None => pat,
+    }
+}
+
+/// The length of a `const` array that is initialized with a literal and never changed, e.g. `const a = [1, 2]`.
+fn static_array_length(
+    ctx: &Context,
+    var: &swc::VarDecl,
+    ident: &str,
+    init: &swc::Expr,
+) -> Option<usize> {
+    let array = init.as_array()?;
+
+    if var.kind != swc::VarDeclKind::Const
+        || ctx.is_property_written(ident)
+        || ctx.is_method_receiver(ident)
+        || array.elems.iter().any(|element| {
+            element
+                .as_ref()
+                .is_none_or(|element| element.spread.is_some())
+        })
+    {
+        return None;
+    }
+
+    Some(array.elems.len())
+}
+
+/// Declares the signatures of the functions declared in a block, since they can be called before their declaration.
+/// The structs of interfaces are declared as well, since they can be used before their declaration too.
+///
+/// The overload signatures before the implementation of a function are declared as its overloads instead.
+pub fn declare_functions<'a, I: IntoIterator<Item = &'a swc::Stmt>>(ctx: &mut Context, stmts: I) {
+    let mut overload_signatures: HashMap<&str, Vec<FnSignature>> = HashMap::new();
+
+    for stmt in stmts {
+        if let Some((name, fields)) = stmt.as_decl().and_then(struct_decl) {
+            ctx.declare_struct(&name, fields);
+        }
+
+        if let swc::Stmt::Decl(swc::Decl::Fn(fn_decl)) = stmt {
+            let name = fn_decl.ident.sym.as_str();
+            let signature = fn_signature(&fn_decl.function);
+
+            if fn_decl.function.body.is_none() {
+                overload_signatures.entry(name).or_default().push(signature);
+            } else if let Some(signatures) = overload_signatures.remove(name) {
+                let overloads = overload_names(name, &signatures, ctx.overload_strategy())
+                    .into_iter()
+                    .zip(signatures)
+                    .map(|(name, signature)| Overload { name, signature })
+                    .collect();
+
+                ctx.declare_overloads(name, overloads);
+            } else {
+                ctx.declare_function(name, signature);
+            }
+        }
+    }
+}
+
+/// Names the overloads of a function after their parameter types, e.g. `parse_string` for functions and `String`
+/// for enum variants.
+fn overload_names(
+    name: &str,
+    signatures: &[FnSignature],
+    strategy: OverloadStrategy,
+) -> Vec<String> {
+    let mut names: Vec<String> = vec![];
+
+    for (index, signature) in signatures.iter().enumerate() {
+        let mut words: Vec<String> = signature
+            .params
+            .iter()
+            .chain(signature.rest.iter())
+            .flat_map(type_name_words)
+            .collect();
+
+        if words.is_empty() {
+            words.push("empty".to_string());
+        }
+
+        let overload_name = match strategy {
+            OverloadStrategy::Split => format!("{}_{}", name, words.join("_")),
+            OverloadStrategy::ArgumentEnum => words.iter().map(|word| pascal_case(word)).collect(),
+        };
+
+        // Overloads whose parameters only differ in types that Rust doesn't distinguish are numbered.
+        names.push(if names.contains(&overload_name) {
+            format!("{}{}", overload_name, index)
+        } else {
+            overload_name
+        });
+    }
+
+    names
+}
+
+fn type_name_words(value_type: &ValueType) -> Vec<String> {
+    match value_type {
+        ValueType::Boolean => vec!["boolean".to_string()],
+        ValueType::Number(_) => vec!["number".to_string()],
+        ValueType::String => vec!["string".to_string()],
+        ValueType::Option(inner) => type_name_words(inner),
+        ValueType::Array(element) => {
+            let mut words = type_name_words(element);
+            words.push("array".to_string());
+            words
+        }
+        ValueType::Object(value_type) => {
+            let mut words = type_name_words(value_type);
+            words.push("object".to_string());
+            words
+        }
+        ValueType::Function(_) => vec!["function".to_string()],
+        ValueType::Promise(_) => vec!["promise".to_string()],
+        ValueType::Generator(..) => vec!["generator".to_string()],
+        ValueType::AsyncGenerator(..) => vec!["async".to_string(), "generator".to_string()],
+        // The words of the name, e.g. `tree` and `node` for `TreeNode`.
+        ValueType::Struct(name) => {
+            let mut words: Vec<String> = vec![];
+
+            for char in name.chars() {
+                match words.last_mut() {
+                    Some(word) if !char.is_uppercase() => word.push(char),
+                    _ => words.push(char.to_lowercase().collect()),
+                }
+            }
+
+            words
+        }
+        ValueType::Unknown => vec!["any".to_string()],
+    }
+}
+
+pub fn pascal_case(name: &str) -> String {
+    let mut chars = name.chars();
+
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Name of the enum of the arguments of an overloaded function, e.g. `ParseArgs` for `parse`.
+pub fn overload_enum_name(name: &str) -> String {
+    format!("{}Args", pascal_case(name))
+}
+
+/// The signature of a function from its annotations, where a missing or `void` return type means no return value.
+pub fn fn_signature(function: &swc::Function) -> FnSignature {
+    let mut params = vec![];
+    let mut rest = None;
+
+    for param in &function.params {
+        match &param.pat {
+            swc::Pat::Rest(rest_pat) => {
+                rest = Some(match type_ann_type(rest_pat.type_ann.as_deref()) {
+                    ValueType::Array(element_type) => *element_type,
+                    _ => ValueType::Unknown,
+                })
+            }
+            pat => params.push(param_signature_type(pat)),
+        }
+    }
+
+    let return_type = function
+        .return_type
+        .as_ref()
+        .and_then(|type_ann| return_annotation_type(&type_ann.type_ann));
+    // Async functions are annotated with the promise they return, but their body returns its value.
+    let return_type = match return_type {
+        Some(ValueType::Promise(value_type)) if function.is_async => {
+            value_type.map(|value_type| *value_type)
+        }
+        return_type => return_type,
+    };
+
+    FnSignature {
+        params,
+        rest,
+        return_type,
+        is_async: function.is_async,
+        is_generator: function.is_generator,
+    }
+}
+
+/// The type of a parameter as seen by callers, where optional and default parameters are `Option`s.
+fn param_signature_type(pat: &swc::Pat) -> ValueType {
+    let (value_type, optional) = match pat {
+        swc::Pat::Ident(ident) => (type_ann_type(ident.type_ann.as_deref()), ident.id.optional),
+        swc::Pat::Assign(assign) => match &*assign.left {
+            swc::Pat::Ident(ident) if ident.type_ann.is_some() => {
+                (type_ann_type(ident.type_ann.as_deref()), true)
+            }
+            // Without an annotation, the parameter has the type of its default value.
+            _ => (infer_type(&Context::new(), &assign.right), true),
+        },
+        _ => todo!("function param pattern"),
+    };
+
+    match value_type {
+        ValueType::Option(_) => value_type,
+        value_type if optional => ValueType::Option(Box::new(value_type)),
+        value_type => value_type,
+    }
+}
+
+fn type_ann_type(type_ann: Option<&swc::TsTypeAnn>) -> ValueType {
+    type_ann
+        .map(|type_ann| annotation_type(&type_ann.type_ann))
+        .unwrap_or(ValueType::Unknown)
+}
+
+/// Transpiles a function declaration to a Rust function.
+///
+/// Strings and arrays are borrowed as `&str` and `&[T]` parameters, and copied into owned bindings if the function
+/// reassigns them. Rest parameters become a slice of the remaining arguments, and optional and default parameters
+/// become `Option`s.
+///
+/// Overload signatures don't produce anything themselves, instead the implementation is transpiled once for each
+/// overload, see `transpile_overloaded_fn`. Generator functions return a state machine, see `transpile_generator`,
+/// and async generator functions a stream, see `transpile_async_generator`.
+pub fn transpile_fn_decl(ctx: &mut Context, fn_decl: swc::FnDecl) -> Vec<Item> {
+    let function = *fn_decl.function;
+    let name = fn_decl.ident.sym.to_string();
+
+    if function.body.is_none() {
+        return vec![];
+    }
+
+    match ctx.overloads(&name) {
+        Some(_) if function.is_async => todo!("async overloads"),
+        Some(_) if function.is_generator => todo!("generator overloads"),
+        Some(overloads) => {
+            let overloads = overloads.to_vec();
+
+            transpile_overloaded_fn(ctx, &name, function, overloads)
+        }
+        None => {
+            let signature = fn_signature(&function);
+
+            ctx.declare_function(&name, signature.clone());
+
+            vec![Item::Fn(transpile_function(
+                ctx, &name, function, &signature,
+            ))]
+        }
+    }
+}
+
+/// Transpiles the implementation of an overloaded function for each of its overloads.
+///
+/// The parameters of the implementation have the types of the overload, and branches on their types like
+/// `typeof x === "string"` only keep the branch that the overload takes, see `static_condition`. The overloads become
+/// separate functions or one function matching on an enum of their arguments, depending on the `OverloadStrategy`.
+fn transpile_overloaded_fn(
+    ctx: &mut Context,
+    name: &str,
+    function: swc::Function,
+    overloads: Vec<Overload>,
+) -> Vec<Item> {
+    let functions: Vec<ItemFn> = overloads
+        .iter()
+        .map(|overload| {
+            let mut signature = overload.signature.clone();
+            let param_count = signature.params.len() + signature.rest.iter().count();
+
+            signature.params.extend(
+                function
+                    .params
+                    .iter()
+                    .skip(param_count)
+                    .filter(|param| !param.pat.is_rest())
+                    .map(|param| param_signature_type(&param.pat)),
+            );
+
+            let mut item_fn = transpile_function(ctx, &overload.name, function.clone(), &signature);
+
+            // Parameters of the implementation that the overload omits are always `undefined`.
+            let omitted: Vec<Stmt> = item_fn
+                .sig
+                .inputs
+                .iter()
+                .skip(param_count)
+                .map(|input| match input {
+                    FnArg::Typed(PatType { pat, ty, .. }) => parse_quote! { let #pat: #ty = None; },
+                    FnArg::Receiver(_) => unreachable!("Function has no receiver."),
+                })
+                .collect();
+
+            item_fn.sig.inputs = item_fn.sig.inputs.into_iter().take(param_count).collect();
+            item_fn.block.stmts.splice(0..0, omitted);
+            // Parameters may only be used in the branches for other overloads.
+            item_fn.attrs.push(parse_quote!(#[allow(unused_variables)]));
+
+            item_fn
+        })
+        .collect();
+
+    match ctx.overload_strategy() {
+        OverloadStrategy::Split => functions.into_iter().map(Item::Fn).collect(),
+        OverloadStrategy::ArgumentEnum => generate_overload_enum_fn(name, &overloads, functions),
+    }
+}
+
+/// Generates the enum of the arguments of an overloaded function, and the function matching on it, e.g.
+/// `enum ParseArgs<'a> { String(&'a str), Number(f64) }` and `fn parse(args: ParseArgs) -> f64`.
+fn generate_overload_enum_fn(
+    name: &str,
+    overloads: &[Overload],
+    functions: Vec<ItemFn>,
+) -> Vec<Item> {
+    let return_type = &overloads[0].signature.return_type;
+
+    if overloads
+        .iter()
+        .any(|overload| &overload.signature.return_type != return_type)
+    {
+        todo!("overloads with different return types")
+    }
+
+    let enum_name = Ident::new(&overload_enum_name(name), dummy_span());
+    let lifetime: Lifetime = parse_quote!('a);
+    // Strings and arrays are borrowed like parameters, which needs a lifetime.
+    let is_borrowed = overloads.iter().any(|overload| {
+        overload_field_types(overload, None) != overload_field_types(overload, Some(&lifetime))
+    });
+    let generics: Option<Generics> = is_borrowed.then(|| parse_quote!(<#lifetime>));
+    let variants = overloads.iter().map(|overload| {
+        let variant = Ident::new(&overload.name, dummy_span());
+        let types = overload_field_types(overload, is_borrowed.then_some(&lifetime));
+
+        if types.is_empty() {
+            quote! { #variant }
+        } else {
+            quote! { #variant(#(#types),*) }
+        }
+    });
+    let arms = overloads.iter().zip(functions).map(|(overload, function)| {
+        let variant = Ident::new(&overload.name, dummy_span());
+        let pats: Vec<Pat> = function
+            .sig
+            .inputs
+            .into_iter()
+            .map(|input| match input {
+                FnArg::Typed(pat_type) => *pat_type.pat,
+                FnArg::Receiver(_) => unreachable!("Function has no receiver."),
+            })
+            .collect();
+        let block = function.block;
+
+        if pats.is_empty() {
+            quote! { #enum_name::#variant => #block }
+        } else {
+            quote! { #enum_name::#variant(#(#pats),*) => #block }
+        }
+    });
+    let fn_name = Ident::new(name, dummy_span());
+    let args_type: Type = if is_borrowed {
+        parse_quote!(#enum_name<'_>)
+    } else {
+        parse_quote!(#enum_name)
+    };
+    let output = generate_return_type(return_type);
+    let allow_clippy_all = generate_allow_clippy_all_attribute();
+
+    vec![
+        parse_quote! {
+            enum #enum_name #generics {
+                #(#variants),*
+            }
+        },
+        parse_quote! {
+            #allow_clippy_all
+            #[allow(unused_variables)]
+            fn #fn_name(args: #args_type) #output {
+                match args {
+                    #(#arms)*
+                }
+            }
+        },
+    ]
+}
+
+/// Types of the fields of the enum variant of an overload, which are the types of its parameters.
+fn overload_field_types(overload: &Overload, lifetime: Option<&Lifetime>) -> Vec<Type> {
+    overload
+        .signature
+        .params
+        .iter()
+        .cloned()
+        .chain(
+            overload
+                .signature
+                .rest
+                .clone()
+                .map(|rest| ValueType::Array(Box::new(rest))),
+        )
+        .map(|value_type| borrowed_type(&value_type, lifetime))
+        .collect()
+}
+
+/// Transpiles a function with the parameter and return types of `signature`.
+fn transpile_function(
+    ctx: &mut Context,
+    name: &str,
+    function: swc::Function,
+    signature: &FnSignature,
+) -> ItemFn {
+    let body = function.body.expect("Function has a body.");
+
+    let params: Vec<(String, ValueType, Option<swc::Expr>)> = function
+        .params
+        .into_iter()
+        .zip(
+            signature.params.iter().cloned().chain(
+                signature
+                    .rest
+                    .clone()
+                    .map(|rest| ValueType::Array(Box::new(rest))),
+            ),
+        )
+        .map(|(param, value_type)| {
+            let (ident, default) = match param.pat {
+                swc::Pat::Ident(ident) => (ident.id, None),
+                swc::Pat::Rest(rest) => (rest.arg.ident().expect("Rest param is Ident.").id, None),
+                swc::Pat::Assign(assign) => (
+                    assign.left.ident().expect("Default param is Ident.").id,
+                    Some(*assign.right),
+                ),
+                _ => todo!("function param pattern"),
+            };
+
+            (ident.sym.to_string(), value_type, default)
+        })
+        .collect();
+
+    let param_names: Vec<String> = params.iter().map(|(ident, ..)| ident.clone()).collect();
+    // The body of a generator function returns the return value of its generator.
+    let body_return_type =
+        match &signature.return_type {
+            Some(
+                ValueType::Generator(_, return_type) | ValueType::AsyncGenerator(_, return_type),
+            ) if signature.is_generator => return_type.as_deref().cloned(),
+            return_type => return_type.clone(),
+        };
+
+    let (inputs, stmts) = ctx.with_function(body_return_type, |ctx| {
+        let mut inputs = Punctuated::new();
+        let mut stmts = vec![];
+
+        for (index, (ident, param_value_type, default)) in params.into_iter().enumerate() {
+            // Default values are applied in the body, so the binding has the type of the value instead of `Option`.
+            let value_type = match (&default, &param_value_type) {
+                (Some(_), ValueType::Option(inner)) => (**inner).clone(),
+                _ => param_value_type.clone(),
+            };
+            // Generator functions move their parameters into the generator, which is where they are changed, while
+            // async generators capture them in their body.
+            let reassigned =
+                ctx.is_reassigned(&ident) && (!signature.is_generator || signature.is_async);
+            // Borrowed parameters are copied into an owned binding with the same name before they can be changed.
+            let copied = reassigned
+                && !signature.takes_owned_args()
+                && matches!(value_type, ValueType::String | ValueType::Array(_));
+            // Callbacks that assign to their captures are `FnMut`, which needs a mutable binding to be called.
+            let mutating_callback = matches!(value_type, ValueType::Function(_))
+                && !signature.takes_owned_args()
+                && ctx.is_mutating_callback_param(name, index);
+            let mutable = (reassigned && !copied) || mutating_callback;
+            let ident_token = Ident::new(&ident, dummy_span());
+
+            inputs.push(FnArg::Typed(PatType {
+                attrs: vec![],
+                pat: Box::new(Pat::Ident(PatIdent {
+                    attrs: vec![],
+                    by_ref: None,
+                    mutability: (mutable && default.is_none()).then(|| token::Mut(dummy_span())),
+                    ident: ident_token.clone(),
+                    subpat: None,
+                })),
+                colon_token: token::Colon(dummy_span()),
+                ty: Box::new(match &param_value_type {
+                    ValueType::Function(signature) if mutating_callback => {
+                        let bound = generate_fn_bound(signature, "FnMut");
+
+                        parse_quote!(impl #bound)
+                    }
+                    param_value_type if signature.takes_owned_args() => {
+                        owned_type(param_value_type)
+                    }
+                    param_value_type => param_type(param_value_type),
+                }),
+            }));
+
+            if let Some(default) = default {
+                // The default value is evaluated for each call without the argument, like in TypeScript.
+                let default = if signature.takes_owned_args() {
+                    transpile_owned_value(ctx, default, &value_type)
+                } else {
+                    transpile_value(ctx, default, &value_type)
+                };
+                let mutability = mutable.then(|| token::Mut(dummy_span()));
+
+                stmts.push(parse_quote! {
+                    let #mutability #ident_token = match #ident_token {
+                        Some(#ident_token) => #ident_token,
+                        None => #default,
+                    };
+                });
+            }
+
+            if copied {
+                let method = match value_type {
+                    ValueType::String => "to_string",
+                    _ => "to_vec",
+                };
+                let method = Ident::new(method, dummy_span());
+
+                stmts.push(parse_quote! { let mut #ident_token = #ident_token.#method(); });
+            }
+
+            ctx.declare_closure(
+                &ident,
+                !signature.takes_owned_args() && matches!(value_type, ValueType::Function(_)),
+            );
+            ctx.declare_string(
+                &ident,
+                (copied || signature.takes_owned_args()) && value_type == ValueType::String,
+            );
+            ctx.declare_array_length(&ident, None);
+            ctx.declare(&ident, value_type);
+        }
+
+        if signature.is_generator && signature.is_async {
+            stmts.push(transpile_async_generator(ctx, body, signature));
+        } else if signature.is_generator {
+            stmts.extend(transpile_generator(
+                ctx,
+                name,
+                &param_names,
+                body,
+                signature,
+            ));
+        } else {
+            declare_functions(ctx, &body.stmts);
+            stmts.extend(transpile_stmts(ctx, body.stmts));
+        }
+
+        (inputs, stmts)
+    });
+
+    ItemFn {
+        attrs: vec![generate_allow_clippy_all_attribute()],
+        vis: Visibility::Inherited,
+        sig: Signature {
+            constness: None,
+            asyncness: signature
+                .returns_promise()
+                .then(|| token::Async(dummy_span())),
+            unsafety: None,
+            abi: None,
+            fn_token: token::Fn(dummy_span()),
+            ident: Ident::new(name, dummy_span()),
+            generics: Generics::default(),
+            paren_token: token::Paren(dummy_span()),
+            inputs,
+            variadic: None,
+            output: generate_return_type(&signature.return_type),
+        },
+        block: Box::new(Block {
+            brace_token: token::Brace(dummy_span()),
+            stmts,
+        }),
+    }
+}
+
+fn generate_return_type(return_type: &Option<ValueType>) -> ReturnType {
+    match return_type {
+        Some(return_type) => ReturnType::Type(
+            token::RArrow(dummy_span()),
+            Box::new(owned_type(return_type)),
+        ),
+        None => ReturnType::Default,
+    }
+}
+
+/// The name and fields of the struct of an interface or an object type alias, e.g. `type Point = { x: number }`.
+fn struct_decl(decl: &swc::Decl) -> Option<(String, Vec<(String, ValueType)>)> {
+    match decl {
+        swc::Decl::TsInterface(interface) => {
+            if !interface.extends.is_empty() {
+                todo!("interface extending other interfaces")
+            }
+
+            let fields = struct_fields(&interface.body.body)
+                .unwrap_or_else(|| todo!("interface member other than a property"));
+
+            Some((interface.id.sym.to_string(), fields))
+        }
+        swc::Decl::TsTypeAlias(alias) => match &*alias.type_ann {
+            swc::TsType::TsTypeLit(type_lit) => {
+                struct_fields(&type_lit.members).map(|fields| (alias.id.sym.to_string(), fields))
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Transpiles an interface or object type alias to its struct, with the implementations of the traits that
+/// operators on objects use, see `struct_decl`. Fields don't need to be read, like properties in TypeScript.
+fn transpile_struct_decl(ctx: &Context, name: &str) -> Vec<Item> {
+    let fields = ctx
+        .struct_fields(name)
+        .expect("Struct is declared.")
+        .to_vec();
+    let ident = Ident::new(name, dummy_span());
+    let field_idents = fields.iter().map(|(key, _)| generate_field_ident(key));
+    let field_types = fields.iter().map(|(_, value_type)| owned_type(value_type));
+    let allow_clippy_all = generate_allow_clippy_all_attribute();
+
+    let mut items = vec![
+        parse_quote! {
+            #allow_clippy_all
+            #[allow(dead_code)]
+            #[derive(Clone, Debug)]
+            struct #ident {
+                #(#field_idents: #field_types,)*
+            }
+        },
+        generate_own_keys_impl(ident.clone(), &fields),
+        generate_has_property_impl(ident.clone(), &fields),
+    ];
+
+    if fields
+        .iter()
+        .any(|(_, value_type)| matches!(value_type, ValueType::Option(_)))
+    {
+        items.push(generate_delete_impl(ident, &fields));
+    }
+
+    items
+}
+
+/// The field of a struct for a property key, see `struct_field_name`.
+pub fn generate_field_ident(key: &str) -> Ident {
+    Ident::new(
+        &struct_field_name(key).expect("Key is a field name."),
+        dummy_span(),
+    )
+}
+
+/// Generates the `OwnKeys` implementation of a struct, so `for...in` can enumerate its fields in JavaScript order,
+/// which is the order of their declaration after integer keys, where optional fields are only enumerated while they
+/// are set.
+fn generate_own_keys_impl(ident: Ident, fields: &[(String, ValueType)]) -> Item {
+    let (optional, required): (Vec<_>, Vec<_>) = fields
+        .iter()
+        .partition(|(_, value_type)| matches!(value_type, ValueType::Option(_)));
+    let required = required
+        .iter()
+        .map(|(key, _)| LitStr::new(key, dummy_span()));
+
+    if optional.is_empty() {
+        return parse_quote! {
+            impl OwnKeys for #ident {
+                fn own_keys(&self) -> Vec<String> {
+                    ordered_keys([#(#required),*])
+                }
+            }
+        };
+    }
+
+    let optional_fields = optional.iter().map(|(key, _)| generate_field_ident(key));
+    let optional_keys = optional
+        .iter()
+        .map(|(key, _)| LitStr::new(key, dummy_span()));
+
+    parse_quote! {
+        impl OwnKeys for #ident {
+            fn own_keys(&self) -> Vec<String> {
+                let mut keys = vec![#(#required),*];
+                #(
+                    if self.#optional_fields.is_some() {
+                        keys.push(#optional_keys);
+                    }
+                )*
+                ordered_keys(keys)
+            }
+        }
+    }
+}
+
+/// Generates the `Delete` implementation of a struct, where `delete` resets its optional fields to `None`.
+fn generate_delete_impl(ident: Ident, fields: &[(String, ValueType)]) -> Item {
+    let optional = fields
+        .iter()
+        .filter(|(_, value_type)| matches!(value_type, ValueType::Option(_)))
+        .map(|(key, _)| key);
+    let keys = optional.clone().map(|key| LitStr::new(key, dummy_span()));
+    let fields = optional.map(|key| generate_field_ident(key));
+
+    parse_quote! {
+        impl Delete for #ident {
+            fn delete(&mut self, key: &str) -> bool {
+                #(
+                    if key == #keys {
+                        self.#fields = None;
+                    }
+                )*
+
+                true
+            }
+        }
+    }
+}
+
+/// Generates the `HasProperty` implementation of a struct, for `in` with computed keys, where its optional fields are
+/// only found while they are set.
+fn generate_has_property_impl(ident: Ident, fields: &[(String, ValueType)]) -> Item {
+    let (optional, required): (Vec<_>, Vec<_>) = fields
+        .iter()
+        .partition(|(_, value_type)| matches!(value_type, ValueType::Option(_)));
+    let required = required
+        .iter()
+        .map(|(key, _)| LitStr::new(key, dummy_span()));
+
+    if optional.is_empty() {
+        return parse_quote! {
+            impl HasProperty for #ident {
+                fn has_property(&self, key: &str) -> bool {
+                    matches!(key, #(#required)|*)
+                }
+            }
+        };
+    }
+
+    let optional_fields = optional.iter().map(|(key, _)| generate_field_ident(key));
+    let optional_keys = optional
+        .iter()
+        .map(|(key, _)| LitStr::new(key, dummy_span()));
+
+    parse_quote! {
+        impl HasProperty for #ident {
+            fn has_property(&self, key: &str) -> bool {
+                match key {
+                    #(#required => true,)*
+                    #(#optional_keys => self.#optional_fields.is_some(),)*
+                    _ => false,
+                }
+            }
+        }
+    }
+}
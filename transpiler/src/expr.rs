@@ -1,37 +1,75 @@
+use swc_common::DUMMY_SP;
 use swc_ecma_ast as swc;
 use syn::{punctuated::Punctuated, *};
 
-use crate::util::dummy_span;
+use crate::{
+    analysis::{captured_assignments, find_idents, find_modifications, integer_lit, number_lit},
+    context::Context,
+    decl::{declare_functions, generate_field_ident, overload_enum_name},
+    stmt::{transpile_expr_stmt, transpile_stmts},
+    types::{
+        self, arithmetic_number_type, array_method, bitwise_number_type, call_overload,
+        call_signature, exp_number_type, function_expr_parts, function_expr_signature,
+        generate_fn_bound, infer_type, is_field_name, is_math_call, is_promise_constructor,
+        literal_key, member_prop_key, operand_number_type, promise_call, promise_callback_type,
+        FnSignature, NumberType, Overload, PromiseCall, ValueType,
+    },
+    util::dummy_span,
+    OverloadStrategy,
+};
+use ts_std::ToJsString;
 
-pub fn transpile_expr(expr: swc::Expr) -> Expr {
+pub fn transpile_expr(ctx: &mut Context, expr: swc::Expr) -> Expr {
     if expr.is_this() {
         todo!("expr this")
     } else if expr.is_array() {
-        todo!("expr array")
+        let array = expr.array().expect("Expr is Array.");
+        let element_type = match infer_type(ctx, &swc::Expr::Array(array.clone())) {
+            ValueType::Array(element_type) => *element_type,
+            _ => unreachable!("Arrays have an array type."),
+        };
+
+        transpile_array(ctx, array, &element_type)
     } else if expr.is_object() {
-        todo!("expr object")
+        let object = expr.object().expect("Expr is Object.");
+        let value_type = match infer_type(ctx, &swc::Expr::Object(object.clone())) {
+            ValueType::Object(value_type) => *value_type,
+            _ => unreachable!("Objects have an object type."),
+        };
+
+        transpile_object(ctx, object, &value_type)
     } else if expr.is_fn_expr() {
-        todo!("expr fn")
+        transpile_closure(ctx, expr, None, false)
     } else if expr.is_unary() {
-        transpile_unary(expr.unary().expect("Expr is Unary."))
+        transpile_unary(ctx, expr.unary().expect("Expr is Unary."))
     } else if expr.is_update() {
-        transpile_update(expr.update().expect("Expr is Update."))
+        transpile_update(ctx, expr.update().expect("Expr is Update."))
     } else if expr.is_bin() {
-        transpile_bin(expr.bin().expect("Expr is Bin."))
+        transpile_bin(ctx, expr.bin().expect("Expr is Bin."))
     } else if expr.is_assign() {
-        transpile_assign(expr.assign().expect("Expr is Assign."))
+        transpile_assign(ctx, expr.assign().expect("Expr is Assign."))
     } else if expr.is_member() {
-        todo!("expr member")
+        transpile_member(ctx, expr.member().expect("Expr is Member."))
     } else if expr.is_super_prop() {
         todo!("expr super prop")
     } else if expr.is_cond() {
-        todo!("expr cond")
+        transpile_cond(ctx, expr.cond().expect("Expr is Cond."))
     } else if expr.is_call() {
-        transpile_call(expr.call().expect("Expr is Call."))
+        transpile_call(ctx, expr.call().expect("Expr is Call."))
+    } else if expr
+        .as_new()
+        .is_some_and(|new| is_promise_constructor(ctx, &new.callee))
+    {
+        transpile_promise_constructor(ctx, expr.new().expect("Expr is New."))
     } else if expr.is_new() {
         todo!("expr new")
     } else if expr.is_seq() {
-        todo!("expr seq")
+        transpile_seq(ctx, expr.seq().expect("Expr is Seq."))
+    } else if expr
+        .as_ident()
+        .is_some_and(|ident| ctx.is_shared(ident.sym.as_str()))
+    {
+        transpile_shared_read(expr.ident().expect("Expr is Ident."))
     } else if expr.is_ident() {
         transpile_ident(expr.ident().expect("Expr is Ident."))
     } else if expr.is_lit() {
@@ -41,20 +79,26 @@ pub fn transpile_expr(expr: swc::Expr) -> Expr {
     } else if expr.is_tagged_tpl() {
         todo!("expr tagged tpl")
     } else if expr.is_arrow() {
-        todo!("expr arror")
+        transpile_closure(ctx, expr, None, false)
     } else if expr.is_class() {
         todo!("expr class")
     } else if expr.is_yield_expr() {
-        todo!("expr yield")
+        transpile_yield(ctx, expr.yield_expr().expect("Expr is YieldExpr."))
     } else if expr.is_meta_prop() {
         todo!("expr meta prop")
     } else if expr.is_await_expr() {
-        todo!("expr await")
+        transpile_await(ctx, expr.await_expr().expect("Expr is AwaitExpr."))
+    } else if expr.as_paren().is_some_and(|paren| paren.expr.is_seq()) {
+        // Sequences already become blocks, which don't need parentheses.
+        transpile_expr(ctx, *expr.paren().expect("Expr is Paren.").expr)
     } else if expr.is_paren() {
         Expr::Paren(ExprParen {
             attrs: vec![],
             paren_token: token::Paren(dummy_span()),
-            expr: Box::new(transpile_expr(*expr.paren().expect("Expr is Paren.").expr)),
+            expr: Box::new(transpile_expr(
+                ctx,
+                *expr.paren().expect("Expr is Paren.").expr,
+            )),
         })
     } else if expr.is_jsx_member() {
         todo!("expr jsx member")
@@ -89,35 +133,380 @@ pub fn transpile_expr(expr: swc::Expr) -> Expr {
     }
 }
 
-pub fn transpile_unary(unary: swc::UnaryExpr) -> Expr {
+/// Transpiles `test ? cons : alt` to `if test { cons } else { alt }`.
+pub fn transpile_cond(ctx: &mut Context, cond: swc::CondExpr) -> Expr {
+    match static_condition(ctx, &cond.test) {
+        Some(true) => return transpile_expr(ctx, *cond.cons),
+        Some(false) => return transpile_expr(ctx, *cond.alt),
+        None => {}
+    }
+
+    match operand_number_type(ctx, &cond.cons, &cond.alt, false) {
+        Some(number_type) => transpile_number_cond(ctx, cond, number_type),
+        None => {
+            let test = transpile_condition(ctx, *cond.test);
+            let cons = transpile_expr(ctx, *cond.cons);
+            let alt = transpile_expr(ctx, *cond.alt);
+
+            generate_if_else(test, cons, alt)
+        }
+    }
+}
+
+/// Transpiles a conditional expression with both branches converted to `number_type`.
+fn transpile_number_cond(ctx: &mut Context, cond: swc::CondExpr, number_type: NumberType) -> Expr {
+    let test = transpile_condition(ctx, *cond.test);
+    let cons = transpile_number(ctx, *cond.cons, number_type);
+    let alt = transpile_number(ctx, *cond.alt, number_type);
+
+    generate_if_else(test, cons, alt)
+}
+
+/// Generates `if cond { then } else { otherwise }`, chaining nested `if`s as `else if`.
+fn generate_if_else(cond: Expr, then: Expr, otherwise: Expr) -> Expr {
+    let otherwise = match otherwise {
+        otherwise @ Expr::If(_) => otherwise,
+        otherwise => Expr::Block(ExprBlock {
+            attrs: vec![],
+            label: None,
+            block: Block {
+                brace_token: token::Brace(dummy_span()),
+                stmts: vec![Stmt::Expr(otherwise, None)],
+            },
+        }),
+    };
+
+    Expr::If(ExprIf {
+        attrs: vec![],
+        if_token: token::If(dummy_span()),
+        cond: Box::new(cond),
+        then_branch: Block {
+            brace_token: token::Brace(dummy_span()),
+            stmts: vec![Stmt::Expr(then, None)],
+        },
+        else_branch: Some((token::Else(dummy_span()), Box::new(otherwise))),
+    })
+}
+
+/// Transpiles `a, b, c` to `{ a; b; c }`, which evaluates each expression and results in the last one.
+pub fn transpile_seq(ctx: &mut Context, seq: swc::SeqExpr) -> Expr {
+    let count = seq.exprs.len();
+    let stmts = seq
+        .exprs
+        .into_iter()
+        .enumerate()
+        .map(|(index, expr)| {
+            let expr = transpile_expr(ctx, *expr);
+
+            if index + 1 < count {
+                Stmt::Expr(expr, Some(token::Semi(dummy_span())))
+            } else {
+                Stmt::Expr(expr, None)
+            }
+        })
+        .collect();
+
+    Expr::Block(ExprBlock {
+        attrs: vec![],
+        label: None,
+        block: Block {
+            brace_token: token::Brace(dummy_span()),
+            stmts,
+        },
+    })
+}
+
+pub fn transpile_unary(ctx: &mut Context, unary: swc::UnaryExpr) -> Expr {
+    let expr = match unary.op {
+        swc::UnaryOp::Plus => return transpile_unary_plus(ctx, *unary.arg),
+        swc::UnaryOp::Tilde => return transpile_bit_not(ctx, *unary.arg),
+        swc::UnaryOp::TypeOf => return transpile_type_of(ctx, *unary.arg),
+        swc::UnaryOp::Void => return transpile_void(ctx, *unary.arg),
+        swc::UnaryOp::Delete => return transpile_delete(ctx, *unary.arg),
+        swc::UnaryOp::Bang => transpile_condition(ctx, *unary.arg),
+        swc::UnaryOp::Minus => transpile_number(ctx, *unary.arg, NumberType::F64),
+    };
+
     Expr::Unary(ExprUnary {
         attrs: vec![],
         op: transpile_unary_op(unary.op),
-        expr: Box::new(transpile_expr(*unary.arg)),
+        expr: Box::new(expr),
     })
 }
 
 pub fn transpile_unary_op(op: swc::UnaryOp) -> UnOp {
     match op {
         swc::UnaryOp::Minus => UnOp::Neg(token::Minus(dummy_span())),
-        swc::UnaryOp::Plus => todo!("unary op plus"),
         swc::UnaryOp::Bang => UnOp::Not(token::Not(dummy_span())),
-        swc::UnaryOp::Tilde => todo!("unary op tilde"),
-        swc::UnaryOp::TypeOf => todo!("unary op type of"),
-        swc::UnaryOp::Void => todo!("unary op void"),
-        swc::UnaryOp::Delete => todo!("unary op delete"),
+        swc::UnaryOp::Plus
+        | swc::UnaryOp::Tilde
+        | swc::UnaryOp::TypeOf
+        | swc::UnaryOp::Void
+        | swc::UnaryOp::Delete => panic!("UnaryOp {:?} can't be transpiled to UnOp.", op),
+    }
+}
+
+/// Transpiles `+x`, which is `x` for numbers and `x.to_number()` otherwise.
+fn transpile_unary_plus(ctx: &mut Context, arg: swc::Expr) -> Expr {
+    match infer_type(ctx, &arg) {
+        ValueType::Number(number_type) => transpile_number(ctx, arg, number_type),
+        _ => generate_method_call(transpile_expr(ctx, arg), "to_number", vec![]),
+    }
+}
+
+/// Transpiles `~x`, which can use Rust's `!` for `i32` and goes through `number::bit_not` otherwise.
+fn transpile_bit_not(ctx: &mut Context, arg: swc::Expr) -> Expr {
+    if infer_type(ctx, &arg).number_type() == Some(NumberType::I32) {
+        return Expr::Unary(ExprUnary {
+            attrs: vec![],
+            op: UnOp::Not(token::Not(dummy_span())),
+            expr: Box::new(transpile_expr(ctx, arg)),
+        });
+    }
+
+    generate_path_call(
+        &["number", "bit_not"],
+        vec![transpile_number(ctx, arg, NumberType::F64)],
+    )
+}
+
+/// Transpiles `typeof x` to a string literal if the type of `x` is known, and to `type_of(&x)` otherwise.
+fn transpile_type_of(ctx: &mut Context, arg: swc::Expr) -> Expr {
+    let is_pure = matches!(arg, swc::Expr::Ident(_) | swc::Expr::Lit(_));
+
+    match static_type_of(ctx, &arg) {
+        Some(type_of) if is_pure => Expr::Lit(ExprLit {
+            attrs: vec![],
+            lit: Lit::Str(LitStr::new(type_of, dummy_span())),
+        }),
+        _ => {
+            let arg = transpile_expr(ctx, arg);
+
+            parse_quote! { type_of(&#arg) }
+        }
+    }
+}
+
+/// The result of `typeof expr` if the type of `expr` decides it.
+fn static_type_of(ctx: &Context, expr: &swc::Expr) -> Option<&'static str> {
+    match infer_type(ctx, expr) {
+        _ if is_nullish_lit(ctx, expr) => Some(match expr {
+            swc::Expr::Lit(swc::Lit::Null(_)) => "object",
+            _ => "undefined",
+        }),
+        ValueType::Boolean => Some("boolean"),
+        ValueType::Number(_) => Some("number"),
+        ValueType::String => Some("string"),
+        ValueType::Array(_)
+        | ValueType::Object(_)
+        | ValueType::Struct(_)
+        | ValueType::Promise(_)
+        | ValueType::Generator(..)
+        | ValueType::AsyncGenerator(..) => Some("object"),
+        ValueType::Function(_) => Some("function"),
+        ValueType::Option(_) | ValueType::Unknown => None,
+    }
+}
+
+/// The value of a condition that the types of its operands decide, like `typeof x === "string"` for a `string` or
+/// `x instanceof Array` for an array.
+///
+/// The branches that such conditions rule out are left out, since they would use the operands as a type they don't
+/// have, e.g. in the implementation of an overloaded function.
+pub fn static_condition(ctx: &Context, expr: &swc::Expr) -> Option<bool> {
+    match expr {
+        swc::Expr::Paren(paren) => static_condition(ctx, &paren.expr),
+        swc::Expr::Unary(unary) if unary.op == swc::UnaryOp::Bang => {
+            static_condition(ctx, &unary.arg).map(|value| !value)
+        }
+        swc::Expr::Bin(bin) => match bin.op {
+            swc::BinaryOp::LogicalAnd => match static_condition(ctx, &bin.left)? {
+                true => static_condition(ctx, &bin.right),
+                false => Some(false),
+            },
+            swc::BinaryOp::LogicalOr => match static_condition(ctx, &bin.left)? {
+                true => Some(true),
+                false => static_condition(ctx, &bin.right),
+            },
+            swc::BinaryOp::EqEqEq | swc::BinaryOp::EqEq => static_eq(ctx, bin),
+            swc::BinaryOp::NotEqEq | swc::BinaryOp::NotEq => {
+                static_eq(ctx, bin).map(|value| !value)
+            }
+            swc::BinaryOp::In if bin.right.is_ident() => match static_in(ctx, bin)? {
+                ValueType::Option(_) => None,
+                ValueType::Unknown => Some(false),
+                _ => Some(true),
+            },
+            swc::BinaryOp::InstanceOf if bin.left.is_ident() => static_instance_of(ctx, bin),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Whether `typeof x == "type"` or `x == undefined` holds, if the type of the identifier `x` decides it.
+fn static_eq(ctx: &Context, bin: &swc::BinExpr) -> Option<bool> {
+    match (&*bin.left, &*bin.right) {
+        (swc::Expr::Unary(unary), swc::Expr::Lit(swc::Lit::Str(lit)))
+        | (swc::Expr::Lit(swc::Lit::Str(lit)), swc::Expr::Unary(unary))
+            if unary.op == swc::UnaryOp::TypeOf && unary.arg.is_ident() =>
+        {
+            Some(static_type_of(ctx, &unary.arg)? == &*lit.value)
+        }
+        // Only optional values can be `null` or `undefined`.
+        (swc::Expr::Ident(ident), nullish) | (nullish, swc::Expr::Ident(ident))
+            if is_nullish_lit(ctx, nullish)
+                && !is_nullish_lit(ctx, &swc::Expr::Ident(ident.clone())) =>
+        {
+            match ctx.lookup(ident.sym.as_str())? {
+                ValueType::Option(_) | ValueType::Unknown => None,
+                _ => Some(false),
+            }
+        }
+        _ => None,
+    }
+}
+
+/// The declared type of the field that `"key" in x` looks up, if `x` is a struct and the key is static, where
+/// `Unknown` is a key the struct doesn't have.
+fn static_in(ctx: &Context, bin: &swc::BinExpr) -> Option<ValueType> {
+    let ValueType::Struct(name) = infer_type(ctx, &bin.right) else {
+        return None;
+    };
+    let key = literal_key(&bin.left)?;
+
+    Some(
+        ctx.struct_field(&name, &key)
+            .cloned()
+            .unwrap_or(ValueType::Unknown),
+    )
+}
+
+/// Whether `x instanceof Class` holds, if the type of `x` decides it.
+fn static_instance_of(ctx: &Context, bin: &swc::BinExpr) -> Option<bool> {
+    let class = instance_of_class(bin);
+
+    Some(instance_classes(&infer_type(ctx, &bin.left))?.contains(&class.as_str()))
+}
+
+fn instance_of_class(bin: &swc::BinExpr) -> String {
+    match &*bin.right {
+        swc::Expr::Ident(ident) => ident.sym.to_string(),
+        _ => todo!("instanceof non-ident class"),
+    }
+}
+
+/// The built-in classes that values of a type are instances of, if the type decides it. Primitives aren't instances
+/// of any class, and interfaces and records are plain objects.
+fn instance_classes(value_type: &ValueType) -> Option<&'static [&'static str]> {
+    match value_type {
+        ValueType::Boolean | ValueType::Number(_) | ValueType::String => Some(&[]),
+        ValueType::Array(_) => Some(&["Array", "Object"]),
+        ValueType::Function(_) => Some(&["Function", "Object"]),
+        ValueType::Promise(_) => Some(&["Promise", "Object"]),
+        ValueType::Object(_)
+        | ValueType::Struct(_)
+        | ValueType::Generator(..)
+        | ValueType::AsyncGenerator(..) => Some(&["Object"]),
+        ValueType::Option(_) | ValueType::Unknown => None,
+    }
+}
+
+/// Transpiles `void x`, which evaluates `x` and results in `undefined`.
+fn transpile_void(ctx: &mut Context, arg: swc::Expr) -> Expr {
+    if let swc::Expr::Paren(paren) = arg {
+        return transpile_void(ctx, *paren.expr);
+    }
+
+    if arg.is_lit() {
+        return generate_ident_expr("undefined");
+    }
+
+    let arg = transpile_expr(ctx, arg);
+
+    parse_quote! {
+        {
+            #arg;
+            undefined
+        }
+    }
+}
+
+/// Transpiles `delete a.b` and `delete a[b]` to `a.delete("b")`, see `Delete`.
+///
+/// Only the optional fields of structs can be deleted, which resets them to `None`.
+fn transpile_delete(ctx: &mut Context, arg: swc::Expr) -> Expr {
+    let member = match arg {
+        swc::Expr::Member(member) => member,
+        swc::Expr::Paren(paren) => return transpile_delete(ctx, *paren.expr),
+        _ => todo!("delete non-member"),
+    };
+
+    if let ValueType::Struct(name) = infer_type(ctx, &member.obj) {
+        let key = member_prop_key(&member.prop).expect("Deleted struct property is static.");
+
+        match ctx.struct_field(&name, &key) {
+            Some(ValueType::Option(_)) => {}
+            Some(_) => panic!(
+                "Property {:?} of {} is required and can't be deleted.",
+                key, name
+            ),
+            None => panic!("Struct {} has no property {:?}.", name, key),
+        }
+    }
+
+    let base = match *member.obj {
+        swc::Expr::Member(obj) => transpile_member_place(ctx, obj),
+        obj => transpile_expr(ctx, obj),
+    };
+    let key = match member.prop {
+        swc::MemberProp::Ident(ident) => Expr::Lit(ExprLit {
+            attrs: vec![],
+            lit: Lit::Str(LitStr::new(ident.sym.as_str(), dummy_span())),
+        }),
+        swc::MemberProp::Computed(computed) => transpile_property_key(ctx, *computed.expr),
+        swc::MemberProp::PrivateName(_) => todo!("delete private name"),
+    };
+
+    generate_method_call(base, "delete", vec![key])
+}
+
+/// Transpiles a computed property key to a `&str`, converting other values to strings with `ToJsString`, e.g. `1 / 0`
+/// is the key `"Infinity"`.
+pub fn transpile_property_key(ctx: &mut Context, key: swc::Expr) -> Expr {
+    match key {
+        swc::Expr::Lit(swc::Lit::Num(num)) => Expr::Lit(ExprLit {
+            attrs: vec![],
+            lit: Lit::Str(LitStr::new(&num.value.to_js_string(), dummy_span())),
+        }),
+        key if is_owned_string(ctx, &key) => generate_ref(transpile_expr(ctx, key)),
+        key if infer_type(ctx, &key) == ValueType::String => transpile_expr(ctx, key),
+        key => {
+            let key = transpile_expr(ctx, key);
+
+            generate_ref(generate_method_call(key, "to_js_string", vec![]))
+        }
     }
 }
 
-pub fn transpile_update(update: swc::UpdateExpr) -> Expr {
+pub fn transpile_update(ctx: &mut Context, update: swc::UpdateExpr) -> Expr {
+    if let swc::Expr::Ident(ident) = &*update.arg {
+        if ctx.is_shared(ident.sym.as_str()) {
+            let ident = ident.clone();
+
+            return generate_shared_update(ctx, ident, |ctx| transpile_update(ctx, update));
+        }
+    }
+
+    let number_type = infer_type(ctx, &update.arg)
+        .number_type()
+        .unwrap_or(NumberType::F64);
+
     Expr::Binary(ExprBinary {
         attrs: vec![],
-        left: Box::new(transpile_expr(*update.arg)),
+        left: Box::new(transpile_expr(ctx, *update.arg)),
         op: transpile_update_op(update.op),
-        right: Box::new(Expr::Lit(ExprLit {
-            attrs: vec![],
-            lit: Lit::Int(LitInt::new("1", dummy_span())),
-        })),
+        right: Box::new(transpile_number_lit(1.0, number_type)),
     })
 }
 
@@ -128,224 +517,2784 @@ pub fn transpile_update_op(op: swc::UpdateOp) -> BinOp {
     }
 }
 
-pub fn transpile_bin(bin: swc::BinExpr) -> Expr {
+pub fn transpile_bin(ctx: &mut Context, bin: swc::BinExpr) -> Expr {
+    let number_type = match bin.op {
+        swc::BinaryOp::BitAnd | swc::BinaryOp::BitOr | swc::BinaryOp::BitXor => {
+            match bitwise_number_type(ctx, &bin.left, &bin.right) {
+                NumberType::F64 => {
+                    return transpile_number_helper(
+                        ctx,
+                        bitwise_helper(bin.op),
+                        *bin.left,
+                        *bin.right,
+                    )
+                }
+                number_type => Some(number_type),
+            }
+        }
+        swc::BinaryOp::LShift | swc::BinaryOp::RShift | swc::BinaryOp::ZeroFillRShift => {
+            return transpile_number_helper(ctx, bitwise_helper(bin.op), *bin.left, *bin.right);
+        }
+        swc::BinaryOp::Exp => return transpile_exp(ctx, *bin.left, *bin.right),
+        swc::BinaryOp::Add if is_string_concat(ctx, &bin) => {
+            return transpile_string_concat(ctx, bin)
+        }
+        swc::BinaryOp::Add => operand_number_type(ctx, &bin.left, &bin.right, false)
+            .map(|number_type| arithmetic_number_type(ctx, &bin, number_type)),
+        swc::BinaryOp::Div => operand_number_type(ctx, &bin.left, &bin.right, true)
+            // Integer division truncates, unlike in TypeScript.
+            .map(|_| NumberType::F64),
+        swc::BinaryOp::Sub | swc::BinaryOp::Mul | swc::BinaryOp::Mod => {
+            operand_number_type(ctx, &bin.left, &bin.right, true)
+                .map(|number_type| arithmetic_number_type(ctx, &bin, number_type))
+        }
+        swc::BinaryOp::Lt | swc::BinaryOp::LtEq | swc::BinaryOp::Gt | swc::BinaryOp::GtEq => {
+            operand_number_type(ctx, &bin.left, &bin.right, true)
+        }
+        swc::BinaryOp::NullishCoalescing => return transpile_nullish_coalescing(ctx, bin),
+        swc::BinaryOp::In => return transpile_in(ctx, bin),
+        swc::BinaryOp::InstanceOf => return transpile_instance_of(ctx, bin),
+        swc::BinaryOp::LogicalOr | swc::BinaryOp::LogicalAnd => {
+            if infer_type(ctx, &bin.left) == ValueType::Boolean
+                && infer_type(ctx, &bin.right) == ValueType::Boolean
+            {
+                None
+            } else {
+                return transpile_logical(ctx, bin);
+            }
+        }
+        swc::BinaryOp::EqEq
+        | swc::BinaryOp::NotEq
+        | swc::BinaryOp::EqEqEq
+        | swc::BinaryOp::NotEqEq => match operand_number_type(ctx, &bin.left, &bin.right, false) {
+            Some(number_type) => Some(number_type),
+            None if is_native_eq(ctx, &bin.left, &bin.right) => None,
+            None => return transpile_eq_helper(ctx, bin),
+        },
+    };
+
+    let (left, right) = match number_type {
+        Some(number_type) => (
+            transpile_number(ctx, *bin.left, number_type),
+            transpile_number(ctx, *bin.right, number_type),
+        ),
+        None => (
+            transpile_expr(ctx, *bin.left),
+            transpile_expr(ctx, *bin.right),
+        ),
+    };
+
     Expr::Binary(ExprBinary {
         attrs: vec![],
-        left: Box::new(transpile_expr(*bin.left)),
+        left: Box::new(left),
         op: transpile_bin_op(bin.op),
-        right: Box::new(transpile_expr(*bin.right)),
+        right: Box::new(right),
     })
 }
 
-pub fn transpile_bin_op(op: swc::BinaryOp) -> BinOp {
-    match op {
-        // TODO: equality works very differently in TS than Rust, so these simple conversion won't work
-        swc::BinaryOp::EqEq => BinOp::Eq(token::EqEq(dummy_span())),
-        swc::BinaryOp::NotEq => BinOp::Ne(token::Ne(dummy_span())),
-        swc::BinaryOp::EqEqEq => BinOp::Eq(token::EqEq(dummy_span())),
-        swc::BinaryOp::NotEqEq => BinOp::Ne(token::Ne(dummy_span())),
-        swc::BinaryOp::Lt => BinOp::Lt(token::Lt(dummy_span())),
-        swc::BinaryOp::LtEq => BinOp::Le(token::Le(dummy_span())),
-        swc::BinaryOp::Gt => BinOp::Gt(token::Gt(dummy_span())),
-        swc::BinaryOp::GtEq => BinOp::Ge(token::Ge(dummy_span())),
-        swc::BinaryOp::LShift => BinOp::Shl(token::Shl(dummy_span())),
-        swc::BinaryOp::RShift => BinOp::Shr(token::Shr(dummy_span())),
-        swc::BinaryOp::ZeroFillRShift => todo!("bin op zero fill right shift"),
-        swc::BinaryOp::Add => BinOp::Add(token::Plus(dummy_span())),
-        swc::BinaryOp::Sub => BinOp::Sub(token::Minus(dummy_span())),
-        swc::BinaryOp::Mul => BinOp::Mul(token::Star(dummy_span())),
-        swc::BinaryOp::Div => BinOp::Div(token::Slash(dummy_span())),
-        swc::BinaryOp::Mod => BinOp::Rem(token::Percent(dummy_span())),
-        swc::BinaryOp::BitOr => BinOp::BitOr(token::Or(dummy_span())),
-        swc::BinaryOp::BitXor => BinOp::BitXor(token::Caret(dummy_span())),
-        swc::BinaryOp::BitAnd => BinOp::BitAnd(token::And(dummy_span())),
-        swc::BinaryOp::LogicalOr => BinOp::Or(token::OrOr(dummy_span())),
-        swc::BinaryOp::LogicalAnd => BinOp::And(token::AndAnd(dummy_span())),
-        swc::BinaryOp::In => todo!("bin op in"),
-        swc::BinaryOp::InstanceOf => todo!("bin op instanceof"),
-        // TODO: transpile to <int type>::pow() or <float type>::powf() or <float type>::powi()
-        swc::BinaryOp::Exp => todo!("bin op exp"),
-        swc::BinaryOp::NullishCoalescing => todo!("bin op nullish coalescing"),
-    }
+fn is_string_concat(ctx: &Context, bin: &swc::BinExpr) -> bool {
+    bin.op == swc::BinaryOp::Add
+        && (infer_type(ctx, &bin.left) == ValueType::String
+            || infer_type(ctx, &bin.right) == ValueType::String)
 }
 
-pub fn transpile_assign(assign: swc::AssignExpr) -> Expr {
-    // TODO: mut option can be removed if every branch defines left
-    let mut left: Option<Expr> = None;
-
-    if assign.left.is_simple() {
-        let simple = assign.left.simple().expect("AssignTarget is Simple.");
+/// Transpiles `+` on strings to `format!`, converting other operands with `ToJsString`.
+///
+/// Chains like `"a" + b + c` become a single `format!("a{}{}", b, c)`.
+fn transpile_string_concat(ctx: &mut Context, bin: swc::BinExpr) -> Expr {
+    let mut operands = vec![];
+    collect_concat_operands(ctx, swc::Expr::Bin(bin), &mut operands);
 
-        if simple.is_ident() {
-            let ident = simple.ident().expect("SimpleAssignTarget is Ident.");
+    let mut format = String::new();
+    let mut args = vec![];
 
-            left = Some(Expr::Path(ExprPath {
-                attrs: vec![],
-                qself: None,
-                path: Path::from(PathSegment {
-                    ident: Ident::new(ident.id.sym.as_str(), dummy_span()),
-                    arguments: PathArguments::None,
-                }),
-            }));
-        } else if simple.is_member() {
-            todo!("simple assign target member")
-        } else if simple.is_super_prop() {
-            todo!("simple assign target super prop")
-        } else if simple.is_paren() {
-            todo!("simple assign target paren")
-        } else if simple.is_opt_chain() {
-            todo!("simple assign target opt chain")
-        } else if simple.is_ts_as() {
-            todo!("simple assign target ts as")
-        } else if simple.is_ts_satisfies() {
-            todo!("simple assign target ts satisfies")
-        } else if simple.is_ts_non_null() {
-            todo!("simple assign target ts non null")
-        } else if simple.is_ts_type_assertion() {
-            todo!("simple assign target ts type assertion")
-        } else if simple.is_ts_instantiation() {
-            todo!("simple assign target ts type instantiation")
-        } else if simple.is_invalid() {
-            todo!("simple assign target invalid")
-        } else {
-            unreachable!("Unknown SimpleAssignTarget.")
+    for operand in operands {
+        match operand {
+            swc::Expr::Lit(swc::Lit::Str(str)) => {
+                format.push_str(&str.value.replace('{', "{{").replace('}', "}}"))
+            }
+            operand => {
+                format.push_str("{}");
+                args.push(transpile_string(ctx, operand));
+            }
         }
-    } else if assign.left.is_pat() {
-        let pat = assign.left.pat().expect("AssignTarget is Pat.");
+    }
 
-        if pat.is_array() {
-            todo!("pat assign target array")
-        } else if pat.is_object() {
-            todo!("pat assign target array")
-        } else if pat.is_invalid() {
-            todo!("pat assign target invalid")
-        } else {
-            unreachable!("Unknown AssignTargetPat.")
+    let format = LitStr::new(&format, dummy_span());
+
+    parse_quote! { format!(#format, #(#args),*) }
+}
+
+fn collect_concat_operands(ctx: &Context, expr: swc::Expr, operands: &mut Vec<swc::Expr>) {
+    match expr {
+        swc::Expr::Bin(bin) if is_string_concat(ctx, &bin) => {
+            collect_concat_operands(ctx, *bin.left, operands);
+            collect_concat_operands(ctx, *bin.right, operands);
         }
+        expr => operands.push(expr),
     }
+}
 
-    let left = Box::new(left.expect("Left should be defined."));
-    let right = Box::new(transpile_expr(*assign.right));
+/// Transpiles a value that is used as a string, converting non-strings with `ToJsString`.
+fn transpile_string(ctx: &mut Context, expr: swc::Expr) -> Expr {
+    if infer_type(ctx, &expr) == ValueType::String {
+        transpile_expr(ctx, expr)
+    } else {
+        let expr = transpile_expr(ctx, expr);
 
-    match assign.op {
-        swc::AssignOp::Assign => Expr::Assign(ExprAssign {
-            attrs: vec![],
-            left,
-            eq_token: token::Eq(dummy_span()),
-            right,
-        }),
-        _ => Expr::Binary(ExprBinary {
-            attrs: vec![],
-            left,
-            op: transpile_assign_op(assign.op),
-            right,
-        }),
+        generate_method_call(expr, "to_js_string", vec![])
     }
 }
 
-pub fn transpile_assign_op(op: swc::AssignOp) -> BinOp {
-    match op {
-        swc::AssignOp::Assign => panic!("Assign can't be transpiled to BinOp."),
-        swc::AssignOp::AddAssign => BinOp::AddAssign(token::PlusEq(dummy_span())),
-        swc::AssignOp::SubAssign => BinOp::SubAssign(token::MinusEq(dummy_span())),
-        swc::AssignOp::MulAssign => BinOp::MulAssign(token::StarEq(dummy_span())),
-        swc::AssignOp::DivAssign => BinOp::DivAssign(token::SlashEq(dummy_span())),
-        swc::AssignOp::ModAssign => BinOp::RemAssign(token::PercentEq(dummy_span())),
-        swc::AssignOp::LShiftAssign => BinOp::ShlAssign(token::ShlEq(dummy_span())),
-        swc::AssignOp::RShiftAssign => BinOp::ShrAssign(token::ShrEq(dummy_span())),
-        swc::AssignOp::ZeroFillRShiftAssign => todo!("assign op zero fill right shift"),
-        swc::AssignOp::BitOrAssign => BinOp::BitOrAssign(token::OrEq(dummy_span())),
-        swc::AssignOp::BitXorAssign => BinOp::BitXorAssign(token::CaretEq(dummy_span())),
-        swc::AssignOp::BitAndAssign => BinOp::BitAndAssign(token::AndEq(dummy_span())),
-        swc::AssignOp::ExpAssign => todo!("assign op exp"),
-        swc::AssignOp::AndAssign => todo!("assign op and"),
-        swc::AssignOp::OrAssign => todo!("assign op or"),
-        swc::AssignOp::NullishAssign => todo!("assign op nullish"),
+/// Whether an expression evaluates to an owned `String`, i.e. a concatenation or a `String` binding.
+pub fn is_owned_string(ctx: &Context, expr: &swc::Expr) -> bool {
+    match expr {
+        swc::Expr::Ident(ident) => ctx.is_owned_string(ident.sym.as_str()),
+        swc::Expr::Paren(paren) => is_owned_string(ctx, &paren.expr),
+        swc::Expr::Bin(bin) => is_string_concat(ctx, bin),
+        swc::Expr::Cond(cond) => {
+            is_owned_string(ctx, &cond.cons) || is_owned_string(ctx, &cond.alt)
+        }
+        // Functions return owned strings, and arrays own their elements.
+        swc::Expr::Call(_) | swc::Expr::Member(_) | swc::Expr::Await(_) => {
+            infer_type(ctx, expr) == ValueType::String
+        }
+        _ => false,
     }
 }
 
-pub fn transpile_call(call: swc::CallExpr) -> Expr {
-    let args: Punctuated<Expr, token::Comma> =
-        Punctuated::from_iter(call.args.into_iter().map(|arg| {
-            if arg.spread.is_some() {
-                todo!()
-            } else {
-                transpile_expr(*arg.expr)
-            }
-        }));
+/// Transpiles a `string` stored in a `String` binding, converting borrowed strings and cloning other bindings.
+pub fn transpile_owned_string(ctx: &mut Context, expr: swc::Expr) -> Expr {
+    match expr {
+        swc::Expr::Paren(paren) => transpile_owned_string(ctx, *paren.expr),
+        swc::Expr::Ident(ident)
+            if ctx.is_owned_string(ident.sym.as_str()) && !ctx.is_shared(ident.sym.as_str()) =>
+        {
+            let ident = transpile_ident(ident);
 
-    if call.callee.is_super_() {
-        todo!("call super")
-    } else if call.callee.is_import() {
-        todo!("call import")
-    } else if call.callee.is_expr() {
-        let expr = call.callee.expr().expect("Callee is Expr.");
+            generate_method_call(ident, "clone", vec![])
+        }
+        expr @ swc::Expr::Member(_) if is_owned_string(ctx, &expr) => {
+            let member = transpile_expr(ctx, expr);
 
-        if expr.is_member() {
-            let member = expr.member().expect("Expr is Member.");
+            generate_method_call(member, "clone", vec![])
+        }
+        // Both branches are converted, since `if` needs them to have the same type.
+        swc::Expr::Cond(cond) => match static_condition(ctx, &cond.test) {
+            Some(true) => transpile_owned_string(ctx, *cond.cons),
+            Some(false) => transpile_owned_string(ctx, *cond.alt),
+            None => {
+                let test = transpile_condition(ctx, *cond.test);
+                let cons = transpile_owned_string(ctx, *cond.cons);
+                let alt = transpile_owned_string(ctx, *cond.alt);
 
-            if member.obj.is_ident() && member.prop.is_ident() {
-                Expr::MethodCall(ExprMethodCall {
-                    attrs: vec![],
-                    receiver: Box::new(Expr::Path(ExprPath {
-                        attrs: vec![],
-                        qself: None,
-                        path: Path::from(PathSegment {
-                            ident: Ident::new(
-                                member.obj.ident().expect("Expr is Ident.").sym.as_str(),
-                                dummy_span(),
-                            ),
-                            arguments: PathArguments::None,
-                        }),
-                    })),
-                    dot_token: token::Dot(dummy_span()),
-                    method: Ident::new(
-                        member.prop.ident().expect("Prop is Ident.").sym.as_str(),
-                        dummy_span(),
-                    ),
-                    turbofish: None,
-                    paren_token: token::Paren(dummy_span()),
-                    args,
-                })
-            } else {
-                todo!("call expr member non-ident-prop/non-ident-prop")
+                generate_if_else(test, cons, alt)
             }
-        } else {
-            todo!("call expr non-member")
+        },
+        expr if is_owned_string(ctx, &expr) => transpile_expr(ctx, expr),
+        expr => {
+            let expr = transpile_expr(ctx, expr);
+
+            generate_method_call(expr, "to_string", vec![])
         }
-    } else {
-        unreachable!("Unknown Callee.")
     }
 }
 
-pub fn transpile_ident(ident: swc::Ident) -> Expr {
-    Expr::Path(ExprPath {
-        attrs: vec![],
-        qself: None,
-        path: Path::from(PathSegment {
-            ident: Ident::new(ident.sym.as_str(), dummy_span()),
-            arguments: PathArguments::None,
-        }),
-    })
-}
+/// Transpiles `||` and `&&` on non-boolean operands, which evaluate to one of their operands.
+///
+/// `a || b` becomes `match a { value if value.truthy() => value, _ => b }`, so `a` is only evaluated once and `b`
+/// only if needed.
+fn transpile_logical(ctx: &mut Context, bin: swc::BinExpr) -> Expr {
+    let (left, right) = match operand_number_type(ctx, &bin.left, &bin.right, false) {
+        Some(number_type) => (
+            transpile_number(ctx, *bin.left, number_type),
+            transpile_number(ctx, *bin.right, number_type),
+        ),
+        None => (
+            transpile_expr(ctx, *bin.left),
+            transpile_expr(ctx, *bin.right),
+        ),
+    };
 
-pub fn transpile_lit(lit: swc::Lit) -> Expr {
-    match lit {
-        swc::Lit::Str(str) => Expr::Lit(ExprLit {
+    let truthy = generate_truthy_call(generate_ident_expr("value"));
+    let guard = match bin.op {
+        swc::BinaryOp::LogicalOr => truthy,
+        swc::BinaryOp::LogicalAnd => Expr::Unary(ExprUnary {
             attrs: vec![],
-            lit: Lit::Str(LitStr::new(str.value.as_str(), dummy_span())),
+            op: UnOp::Not(token::Not(dummy_span())),
+            expr: Box::new(truthy),
         }),
-        swc::Lit::Bool(bool) => Expr::Lit(ExprLit {
-            attrs: vec![],
-            lit: Lit::Bool(LitBool::new(bool.value, dummy_span())),
+        _ => unreachable!("BinaryOp is not a logical operator."),
+    };
+
+    Expr::Match(ExprMatch {
+        attrs: vec![],
+        match_token: token::Match(dummy_span()),
+        expr: Box::new(left),
+        brace_token: token::Brace(dummy_span()),
+        arms: vec![
+            Arm {
+                attrs: vec![],
+                pat: Pat::Ident(PatIdent {
+                    attrs: vec![],
+                    by_ref: None,
+                    mutability: None,
+                    ident: Ident::new("value", dummy_span()),
+                    subpat: None,
+                }),
+                guard: Some((token::If(dummy_span()), Box::new(guard))),
+                fat_arrow_token: token::FatArrow(dummy_span()),
+                body: Box::new(generate_ident_expr("value")),
+                comma: Some(token::Comma(dummy_span())),
+            },
+            Arm {
+                attrs: vec![],
+                pat: Pat::Wild(PatWild {
+                    attrs: vec![],
+                    underscore_token: token::Underscore(dummy_span()),
+                }),
+                guard: None,
+                fat_arrow_token: token::FatArrow(dummy_span()),
+                body: Box::new(right),
+                comma: Some(token::Comma(dummy_span())),
+            },
+        ],
+    })
+}
+
+/// Transpiles `key in object` to `object.has_property(key)`, see `HasProperty`.
+///
+/// Static keys of structs are resolved from their declaration, where optional fields are only present while set.
+fn transpile_in(ctx: &mut Context, bin: swc::BinExpr) -> Expr {
+    if let Some(field_type) = static_in(ctx, &bin) {
+        let key = literal_key(&bin.left).expect("Key is static.");
+
+        return match field_type {
+            ValueType::Option(_) => {
+                let object = transpile_expr(ctx, *bin.right);
+                let field = generate_field_ident(&key);
+
+                parse_quote! { #object.#field.is_some() }
+            }
+            ValueType::Unknown => transpile_static_result(ctx, *bin.right, false),
+            _ => transpile_static_result(ctx, *bin.right, true),
+        };
+    }
+
+    let key = transpile_property_key(ctx, *bin.left);
+    let object = transpile_expr(ctx, *bin.right);
+
+    generate_method_call(object, "has_property", vec![key])
+}
+
+/// Transpiles `value instanceof Class` to `value.instance_of("Class")`, see `InstanceOf`.
+///
+/// The check is resolved statically for values of known types, and for optional values it checks whether they are
+/// set.
+fn transpile_instance_of(ctx: &mut Context, bin: swc::BinExpr) -> Expr {
+    let class = instance_of_class(&bin);
+
+    match infer_type(ctx, &bin.left) {
+        ValueType::Option(value_type) => match instance_classes(&value_type) {
+            Some(classes) if classes.contains(&class.as_str()) => {
+                let value = transpile_expr(ctx, *bin.left);
+
+                return generate_method_call(value, "is_some", vec![]);
+            }
+            Some(_) => return transpile_static_result(ctx, *bin.left, false),
+            None => {}
+        },
+        value_type => {
+            if let Some(classes) = instance_classes(&value_type) {
+                return transpile_static_result(ctx, *bin.left, classes.contains(&class.as_str()));
+            }
+        }
+    }
+
+    let value = transpile_expr(ctx, *bin.left);
+    let class = Expr::Lit(ExprLit {
+        attrs: vec![],
+        lit: Lit::Str(LitStr::new(&class, dummy_span())),
+    });
+
+    generate_method_call(value, "instance_of", vec![class])
+}
+
+/// Transpiles an operand whose type decides the result of its operator, which is still evaluated unless it's an
+/// identifier, e.g. `{ f(); true }`.
+fn transpile_static_result(ctx: &mut Context, operand: swc::Expr, value: bool) -> Expr {
+    let value = Expr::Lit(ExprLit {
+        attrs: vec![],
+        lit: Lit::Bool(LitBool::new(value, dummy_span())),
+    });
+
+    if operand.is_ident() {
+        return value;
+    }
+
+    let operand = transpile_expr(ctx, operand);
+
+    parse_quote! {
+        {
+            #operand;
+            #value
+        }
+    }
+}
+
+/// Transpiles `a ?? b` for optional values to `match a { Some(value) => value, None => b }`.
+fn transpile_nullish_coalescing(ctx: &mut Context, bin: swc::BinExpr) -> Expr {
+    if is_nullish_lit(ctx, &bin.left) {
+        return transpile_expr(ctx, *bin.right);
+    }
+
+    let inner = match infer_type(ctx, &bin.left) {
+        ValueType::Option(inner) => *inner,
+        ValueType::Unknown => ValueType::Unknown,
+        // Other values are never nullish, so `b` is never evaluated.
+        _ => return transpile_expr(ctx, *bin.left),
+    };
+    let right_is_option = matches!(infer_type(ctx, &bin.right), ValueType::Option(_));
+    let left = transpile_expr(ctx, *bin.left);
+
+    if right_is_option {
+        let right = transpile_expr(ctx, *bin.right);
+
+        parse_quote! {
+            match #left {
+                None => #right,
+                value => value,
+            }
+        }
+    } else {
+        let right = transpile_value(ctx, *bin.right, &inner);
+
+        parse_quote! {
+            match #left {
+                Some(value) => value,
+                None => #right,
+            }
+        }
+    }
+}
+
+/// Whether an expression is the `null` or `undefined` literal.
+pub fn is_nullish_lit(ctx: &Context, expr: &swc::Expr) -> bool {
+    match expr {
+        swc::Expr::Lit(swc::Lit::Null(_)) => true,
+        swc::Expr::Ident(ident) => {
+            ident.sym.as_str() == "undefined" && ctx.lookup("undefined").is_none()
+        }
+        swc::Expr::Paren(paren) => is_nullish_lit(ctx, &paren.expr),
+        _ => false,
+    }
+}
+
+/// Transpiles an expression to a value of the given type, converting numbers and wrapping optional values in
+/// `Some`, where `null` and `undefined` become `None`.
+pub fn transpile_value(ctx: &mut Context, expr: swc::Expr, value_type: &ValueType) -> Expr {
+    match value_type {
+        ValueType::Number(number_type) => transpile_number(ctx, expr, *number_type),
+        ValueType::Option(_) if is_nullish_lit(ctx, &expr) => generate_ident_expr("None"),
+        ValueType::Option(_) if matches!(infer_type(ctx, &expr), ValueType::Option(_)) => {
+            transpile_expr(ctx, expr)
+        }
+        ValueType::Option(inner) => {
+            let value = transpile_value(ctx, expr, inner);
+
+            parse_quote! { Some(#value) }
+        }
+        ValueType::Array(element_type) if expr.is_array() => {
+            transpile_array(ctx, expr.array().expect("Expr is Array."), element_type)
+        }
+        ValueType::Object(value_type) if expr.is_object() => {
+            transpile_object(ctx, expr.object().expect("Expr is Object."), value_type)
+        }
+        ValueType::Struct(name) if expr.is_object() => {
+            transpile_struct(ctx, expr.object().expect("Expr is Object."), name)
+        }
+        ValueType::Function(signature) => transpile_stored_function(ctx, expr, signature),
+        // Promises that are created without a value, like `Promise.reject(reason)`, get their type from the binding.
+        ValueType::Promise(Some(value_type))
+            if expr.as_call().is_some_and(|call| {
+                matches!(promise_call(ctx, call), Some(PromiseCall::Static("reject")))
+            }) =>
+        {
+            transpile_promise_call(ctx, expr.call().expect("Expr is Call."), Some(value_type))
+        }
+        // Promises are shared, so awaiting or passing one doesn't move it.
+        ValueType::Promise(_) if expr.is_ident() || expr.is_member() => {
+            generate_method_call(transpile_expr(ctx, expr), "clone", vec![])
+        }
+        _ => transpile_expr(ctx, expr),
+    }
+}
+
+/// Transpiles a value that is moved into an owned binding, like the arguments of async functions, which converts
+/// strings to `String`s and clones arrays.
+pub fn transpile_owned_value(ctx: &mut Context, expr: swc::Expr, value_type: &ValueType) -> Expr {
+    match value_type {
+        ValueType::Option(_) if is_nullish_lit(ctx, &expr) => generate_ident_expr("None"),
+        ValueType::Option(inner) if !matches!(infer_type(ctx, &expr), ValueType::Option(_)) => {
+            let value = transpile_owned_value(ctx, expr, inner);
+
+            parse_quote! { Some(#value) }
+        }
+        ValueType::String => transpile_owned_string(ctx, expr),
+        ValueType::Array(_)
+        | ValueType::Object(_)
+        | ValueType::Struct(_)
+        | ValueType::Option(_)
+            if expr.is_ident() || expr.is_member() =>
+        {
+            generate_method_call(transpile_expr(ctx, expr), "clone", vec![])
+        }
+        value_type => transpile_value(ctx, expr, value_type),
+    }
+}
+
+/// Generates `&expr`, leaving literals as they are since they are already references or `Copy`.
+fn generate_ref(expr: Expr) -> Expr {
+    match expr {
+        Expr::Lit(_) => expr,
+        expr => Expr::Reference(ExprReference {
+            attrs: vec![],
+            and_token: token::And(dummy_span()),
+            mutability: None,
+            expr: Box::new(expr),
+        }),
+    }
+}
+
+/// Generates a path expression of a single identifier, e.g. `value`.
+fn generate_ident_expr(ident: &str) -> Expr {
+    Expr::Path(ExprPath {
+        attrs: vec![],
+        qself: None,
+        path: Path::from(PathSegment {
+            ident: Ident::new(ident, dummy_span()),
+            arguments: PathArguments::None,
         }),
-        swc::Lit::Null(_) => todo!(),
-        swc::Lit::Num(num) => Expr::Lit(ExprLit {
+    })
+}
+
+/// Transpiles an expression used as a condition, converting non-boolean values with `Truthy`.
+pub fn transpile_condition(ctx: &mut Context, expr: swc::Expr) -> Expr {
+    if infer_type(ctx, &expr) == ValueType::Boolean {
+        return transpile_expr(ctx, expr);
+    }
+
+    // Only the truthiness of the resulting operand matters, so the operands can be converted separately.
+    match expr {
+        swc::Expr::Bin(bin)
+            if matches!(bin.op, swc::BinaryOp::LogicalAnd | swc::BinaryOp::LogicalOr) =>
+        {
+            return Expr::Binary(ExprBinary {
+                attrs: vec![],
+                left: Box::new(transpile_condition(ctx, *bin.left)),
+                op: transpile_bin_op(bin.op),
+                right: Box::new(transpile_condition(ctx, *bin.right)),
+            });
+        }
+        swc::Expr::Paren(paren) => {
+            return Expr::Paren(ExprParen {
+                attrs: vec![],
+                paren_token: token::Paren(dummy_span()),
+                expr: Box::new(transpile_condition(ctx, *paren.expr)),
+            });
+        }
+        _ => {}
+    }
+
+    // Methods can't be called on literals of ambiguous numeric types, but their truthiness is known anyway.
+    if let swc::Expr::Lit(lit) = &expr {
+        let truthy = match lit {
+            swc::Lit::Num(num) => num.value != 0.0 && !num.value.is_nan(),
+            swc::Lit::Str(str) => !str.value.is_empty(),
+            swc::Lit::Null(_) => false,
+            _ => true,
+        };
+
+        return Expr::Lit(ExprLit {
             attrs: vec![],
-            lit: Lit::Float(LitFloat::new(&num.value.to_string(), dummy_span())),
+            lit: Lit::Bool(LitBool::new(truthy, dummy_span())),
+        });
+    }
+
+    generate_truthy_call(transpile_expr(ctx, expr))
+}
+
+/// Generates `expr.truthy()`.
+fn generate_truthy_call(expr: Expr) -> Expr {
+    generate_method_call(expr, "truthy", vec![])
+}
+
+/// Generates `receiver.method(args)`.
+pub fn generate_method_call(receiver: Expr, method: &str, args: Vec<Expr>) -> Expr {
+    Expr::MethodCall(ExprMethodCall {
+        attrs: vec![],
+        receiver: Box::new(receiver),
+        dot_token: token::Dot(dummy_span()),
+        method: Ident::new(method, dummy_span()),
+        turbofish: None,
+        paren_token: token::Paren(dummy_span()),
+        args: Punctuated::from_iter(args),
+    })
+}
+
+/// Whether both operands of an equality operator are known to have the same primitive type, for which `==` and `===`
+/// are the same as Rust `==`.
+fn is_native_eq(ctx: &Context, left: &swc::Expr, right: &swc::Expr) -> bool {
+    let left = infer_type(ctx, left);
+
+    matches!(left, ValueType::Boolean | ValueType::String) && left == infer_type(ctx, right)
+}
+
+/// Transpiles an equality operator on operands of different or unknown types to `strict_eq(&a, &b)` or
+/// `loose_eq(&a, &b)`.
+fn transpile_eq_helper(ctx: &mut Context, bin: swc::BinExpr) -> Expr {
+    let helper = match bin.op {
+        swc::BinaryOp::EqEqEq | swc::BinaryOp::NotEqEq => "strict_eq",
+        swc::BinaryOp::EqEq | swc::BinaryOp::NotEq => "loose_eq",
+        _ => unreachable!("BinaryOp is not an equality operator."),
+    };
+    let reference = |expr| {
+        Expr::Reference(ExprReference {
+            attrs: vec![],
+            and_token: token::And(dummy_span()),
+            mutability: None,
+            expr: Box::new(expr),
+        })
+    };
+
+    let call = generate_path_call(
+        &[helper],
+        vec![
+            reference(transpile_expr(ctx, *bin.left)),
+            reference(transpile_expr(ctx, *bin.right)),
+        ],
+    );
+
+    match bin.op {
+        swc::BinaryOp::NotEq | swc::BinaryOp::NotEqEq => Expr::Unary(ExprUnary {
+            attrs: vec![],
+            op: UnOp::Not(token::Not(dummy_span())),
+            expr: Box::new(call),
         }),
-        swc::Lit::BigInt(_) => todo!(),
-        swc::Lit::Regex(_) => todo!(),
-        swc::Lit::JSXText(_) => todo!(),
+        _ => call,
+    }
+}
+
+pub fn transpile_bin_op(op: swc::BinaryOp) -> BinOp {
+    match op {
+        swc::BinaryOp::EqEq => BinOp::Eq(token::EqEq(dummy_span())),
+        swc::BinaryOp::NotEq => BinOp::Ne(token::Ne(dummy_span())),
+        swc::BinaryOp::EqEqEq => BinOp::Eq(token::EqEq(dummy_span())),
+        swc::BinaryOp::NotEqEq => BinOp::Ne(token::Ne(dummy_span())),
+        swc::BinaryOp::Lt => BinOp::Lt(token::Lt(dummy_span())),
+        swc::BinaryOp::LtEq => BinOp::Le(token::Le(dummy_span())),
+        swc::BinaryOp::Gt => BinOp::Gt(token::Gt(dummy_span())),
+        swc::BinaryOp::GtEq => BinOp::Ge(token::Ge(dummy_span())),
+        swc::BinaryOp::LShift => BinOp::Shl(token::Shl(dummy_span())),
+        swc::BinaryOp::RShift => BinOp::Shr(token::Shr(dummy_span())),
+        swc::BinaryOp::ZeroFillRShift => panic!("ZeroFillRShift can't be transpiled to BinOp."),
+        swc::BinaryOp::Add => BinOp::Add(token::Plus(dummy_span())),
+        swc::BinaryOp::Sub => BinOp::Sub(token::Minus(dummy_span())),
+        swc::BinaryOp::Mul => BinOp::Mul(token::Star(dummy_span())),
+        swc::BinaryOp::Div => BinOp::Div(token::Slash(dummy_span())),
+        swc::BinaryOp::Mod => BinOp::Rem(token::Percent(dummy_span())),
+        swc::BinaryOp::BitOr => BinOp::BitOr(token::Or(dummy_span())),
+        swc::BinaryOp::BitXor => BinOp::BitXor(token::Caret(dummy_span())),
+        swc::BinaryOp::BitAnd => BinOp::BitAnd(token::And(dummy_span())),
+        swc::BinaryOp::LogicalOr => BinOp::Or(token::OrOr(dummy_span())),
+        swc::BinaryOp::LogicalAnd => BinOp::And(token::AndAnd(dummy_span())),
+        swc::BinaryOp::In => panic!("In can't be transpiled to BinOp."),
+        swc::BinaryOp::InstanceOf => panic!("InstanceOf can't be transpiled to BinOp."),
+        swc::BinaryOp::Exp => panic!("Exp can't be transpiled to BinOp."),
+        swc::BinaryOp::NullishCoalescing => {
+            panic!("NullishCoalescing can't be transpiled to BinOp.")
+        }
+    }
+}
+
+pub fn transpile_assign(ctx: &mut Context, assign: swc::AssignExpr) -> Expr {
+    match &assign.left {
+        swc::AssignTarget::Simple(swc::SimpleAssignTarget::Ident(ident))
+            if ctx.is_shared(ident.id.sym.as_str()) =>
+        {
+            return transpile_shared_assign(ctx, assign);
+        }
+        // Properties of dynamic objects may not exist yet, so they are set by their key, e.g. `object.set("a", 1.0)`.
+        swc::AssignTarget::Simple(swc::SimpleAssignTarget::Member(member))
+            if assign.op == swc::AssignOp::Assign =>
+        {
+            if let ValueType::Object(value_type) = infer_type(ctx, &member.obj) {
+                let member = member.clone();
+                let object = transpile_expr(ctx, *member.obj);
+                let key = transpile_object_key(ctx, member.prop);
+                let value = transpile_element(ctx, *assign.right, &value_type);
+
+                return generate_method_call(object, "set", vec![key, value]);
+            }
+        }
+        _ => {}
+    }
+
+    let target = match &assign.left {
+        swc::AssignTarget::Simple(swc::SimpleAssignTarget::Ident(ident)) => {
+            Some(swc::Expr::Ident(ident.id.clone()))
+        }
+        _ => None,
+    };
+    // The target as an expression that reads it, for operators like `**=` that are rewritten to a plain assignment.
+    let place = match &assign.left {
+        swc::AssignTarget::Simple(swc::SimpleAssignTarget::Member(member)) => {
+            Some(swc::Expr::Member(member.clone()))
+        }
+        _ => target.clone(),
+    };
+    // Fields of structs own their values like bindings, e.g. `Some(1.0)` for optional properties.
+    let field_target = place.as_ref().filter(|place| {
+        place
+            .as_member()
+            .is_some_and(|member| matches!(infer_type(ctx, &member.obj), ValueType::Struct(_)))
+    });
+    let left_type = target
+        .as_ref()
+        .or(field_target)
+        .map(|target| infer_type(ctx, target))
+        .unwrap_or(ValueType::Unknown);
+    let is_field = field_target.is_some();
+
+    // TODO: mut option can be removed if every branch defines left
+    let mut left: Option<Expr> = None;
+
+    if assign.left.is_simple() {
+        let simple = assign.left.simple().expect("AssignTarget is Simple.");
+
+        if simple.is_ident() {
+            let ident = simple.ident().expect("SimpleAssignTarget is Ident.");
+
+            left = Some(Expr::Path(ExprPath {
+                attrs: vec![],
+                qself: None,
+                path: Path::from(PathSegment {
+                    ident: Ident::new(ident.id.sym.as_str(), dummy_span()),
+                    arguments: PathArguments::None,
+                }),
+            }));
+        } else if simple.is_member() {
+            left = Some(transpile_member_place(
+                ctx,
+                simple.member().expect("SimpleAssignTarget is Member."),
+            ));
+        } else if simple.is_super_prop() {
+            todo!("simple assign target super prop")
+        } else if simple.is_paren() {
+            todo!("simple assign target paren")
+        } else if simple.is_opt_chain() {
+            todo!("simple assign target opt chain")
+        } else if simple.is_ts_as() {
+            todo!("simple assign target ts as")
+        } else if simple.is_ts_satisfies() {
+            todo!("simple assign target ts satisfies")
+        } else if simple.is_ts_non_null() {
+            todo!("simple assign target ts non null")
+        } else if simple.is_ts_type_assertion() {
+            todo!("simple assign target ts type assertion")
+        } else if simple.is_ts_instantiation() {
+            todo!("simple assign target ts type instantiation")
+        } else if simple.is_invalid() {
+            todo!("simple assign target invalid")
+        } else {
+            unreachable!("Unknown SimpleAssignTarget.")
+        }
+    } else if assign.left.is_pat() {
+        let pat = assign.left.pat().expect("AssignTarget is Pat.");
+
+        if pat.is_array() {
+            todo!("pat assign target array")
+        } else if pat.is_object() {
+            todo!("pat assign target array")
+        } else if pat.is_invalid() {
+            todo!("pat assign target invalid")
+        } else {
+            unreachable!("Unknown AssignTargetPat.")
+        }
+    }
+
+    let left = left.expect("Left should be defined.");
+
+    let helper = match assign.op {
+        swc::AssignOp::BitAndAssign => Some(bitwise_helper(swc::BinaryOp::BitAnd)),
+        swc::AssignOp::BitOrAssign => Some(bitwise_helper(swc::BinaryOp::BitOr)),
+        swc::AssignOp::BitXorAssign => Some(bitwise_helper(swc::BinaryOp::BitXor)),
+        swc::AssignOp::LShiftAssign => Some(bitwise_helper(swc::BinaryOp::LShift)),
+        swc::AssignOp::RShiftAssign => Some(bitwise_helper(swc::BinaryOp::RShift)),
+        swc::AssignOp::ZeroFillRShiftAssign => Some(bitwise_helper(swc::BinaryOp::ZeroFillRShift)),
+        _ => None,
+    };
+
+    if let Some(helper) = helper {
+        // `a |= b` becomes `a = number::bit_or(a, b)`, the target is a place expression so it can be repeated.
+        let right = transpile_number(ctx, *assign.right, NumberType::F64);
+        let value = generate_number_helper_call(
+            helper,
+            cast_number(left.clone(), NumberType::F64, left_type.number_type()),
+            right,
+        );
+
+        return Expr::Assign(ExprAssign {
+            attrs: vec![],
+            left: Box::new(left),
+            eq_token: token::Eq(dummy_span()),
+            right: Box::new(match left_type.number_type() {
+                Some(number_type) if number_type.is_integer() => {
+                    cast_number(value, number_type, Some(NumberType::F64))
+                }
+                _ => value,
+            }),
+        });
+    }
+
+    if matches!(
+        assign.op,
+        swc::AssignOp::AndAssign | swc::AssignOp::OrAssign | swc::AssignOp::NullishAssign
+    ) {
+        let owned_string = target
+            .as_ref()
+            .is_some_and(|target| is_owned_string(ctx, target));
+
+        return transpile_logical_assign(
+            ctx,
+            assign.op,
+            left,
+            &left_type,
+            owned_string,
+            *assign.right,
+        );
+    }
+
+    if assign.op == swc::AssignOp::ExpAssign {
+        // `a **= b` becomes `a = a ** b`, the target is a place expression so it can be repeated.
+        let target = place.expect("ExpAssign target is Ident or Member.");
+        let target_type = infer_type(ctx, &target).number_type();
+        let value_type = exp_number_type(ctx, &target, &assign.right);
+        let base = cast_number(left.clone(), value_type, target_type);
+        let value = generate_exp(ctx, base, value_type, *assign.right);
+
+        return Expr::Assign(ExprAssign {
+            attrs: vec![],
+            left: Box::new(left),
+            eq_token: token::Eq(dummy_span()),
+            right: Box::new(match target_type {
+                Some(number_type) => cast_number(value, number_type, Some(value_type)),
+                None => value,
+            }),
+        });
+    }
+
+    if assign.op == swc::AssignOp::AddAssign && left_type == ValueType::String {
+        // Targets of `+=` are `String` bindings, see `transpile_var_declarator`.
+        let right = match *assign.right {
+            right @ swc::Expr::Lit(swc::Lit::Str(_)) => transpile_expr(ctx, right),
+            right => generate_ref(transpile_string(ctx, right)),
+        };
+
+        return generate_method_call(left, "push_str", vec![right]);
+    }
+
+    let right = Box::new(match target {
+        Some(target) if is_owned_string(ctx, &target) => transpile_owned_string(ctx, *assign.right),
+        _ if is_field && assign.op == swc::AssignOp::Assign => {
+            transpile_owned_value(ctx, *assign.right, &left_type)
+        }
+        _ => transpile_value(ctx, *assign.right, &left_type),
+    });
+
+    match assign.op {
+        swc::AssignOp::Assign => Expr::Assign(ExprAssign {
+            attrs: vec![],
+            left: Box::new(left),
+            eq_token: token::Eq(dummy_span()),
+            right,
+        }),
+        _ => Expr::Binary(ExprBinary {
+            attrs: vec![],
+            left: Box::new(left),
+            op: transpile_assign_op(assign.op),
+            right,
+        }),
+    }
+}
+
+/// Transpiles an assignment to a `Shared` binding, where `a = b` becomes `a.set(b)`.
+fn transpile_shared_assign(ctx: &mut Context, assign: swc::AssignExpr) -> Expr {
+    let ident = match &assign.left {
+        swc::AssignTarget::Simple(swc::SimpleAssignTarget::Ident(ident)) => ident.id.clone(),
+        _ => unreachable!("Shared assign target is Ident."),
+    };
+
+    if assign.op != swc::AssignOp::Assign {
+        return generate_shared_update(ctx, ident, |ctx| transpile_assign(ctx, assign));
+    }
+
+    let target = swc::Expr::Ident(ident.clone());
+    let value = if is_owned_string(ctx, &target) {
+        transpile_owned_string(ctx, *assign.right)
+    } else {
+        let value_type = infer_type(ctx, &target);
+
+        transpile_value(ctx, *assign.right, &value_type)
+    };
+
+    generate_method_call(transpile_ident(ident), "set", vec![value])
+}
+
+/// Transpiles an update of a `Shared` binding on a local copy of its value, which is stored again afterwards, e.g.
+/// `count += 1` becomes `count.set({ let mut count = count.get(); count += 1.0; count })`.
+fn generate_shared_update<F: FnOnce(&mut Context) -> Expr>(
+    ctx: &mut Context,
+    ident: swc::Ident,
+    update: F,
+) -> Expr {
+    let name = ident.sym.to_string();
+    let cell = transpile_ident(ident);
+    let update = ctx.with_unshared(&name, update);
+    let local = Ident::new(&name, dummy_span());
+
+    generate_method_call(
+        cell.clone(),
+        "set",
+        vec![parse_quote!({
+            let mut #local = #cell.get();
+            #update;
+            #local
+        })],
+    )
+}
+
+/// Reads the value of a `Shared` binding, e.g. `count.get()`.
+fn transpile_shared_read(ident: swc::Ident) -> Expr {
+    generate_method_call(transpile_ident(ident), "get", vec![])
+}
+
+/// Transpiles `&&=`, `||=` and `??=` to an `if` that only evaluates the right-hand side if it is assigned.
+///
+/// Targets that aren't plain places, like indexes, are only evaluated once by borrowing them first, e.g.
+/// `{ let target = &mut a[i]; if !target.truthy() { *target = b; } }`.
+fn transpile_logical_assign(
+    ctx: &mut Context,
+    op: swc::AssignOp,
+    left: Expr,
+    left_type: &ValueType,
+    owned_string: bool,
+    right: swc::Expr,
+) -> Expr {
+    let left_type = match (op, left_type) {
+        (swc::AssignOp::NullishAssign, ValueType::Option(_)) => left_type.clone(),
+        // Unknown values that can be nullish are most likely optional.
+        (swc::AssignOp::NullishAssign, ValueType::Unknown) => {
+            ValueType::Option(Box::new(ValueType::Unknown))
+        }
+        // Other values are never nullish, so nothing is assigned.
+        (swc::AssignOp::NullishAssign, _) => {
+            return Expr::Tuple(ExprTuple {
+                attrs: vec![],
+                paren_token: token::Paren(dummy_span()),
+                elems: Punctuated::new(),
+            })
+        }
+        _ => left_type.clone(),
+    };
+    let right = if owned_string {
+        transpile_owned_string(ctx, right)
+    } else {
+        transpile_value(ctx, right, &left_type)
+    };
+
+    let (binding, target, place) = if is_place(&left) {
+        (None, left.clone(), left)
+    } else {
+        let target = generate_ident_expr("target");
+
+        (
+            Some(parse_quote! { let target = &mut #left; }),
+            target.clone(),
+            Expr::Unary(ExprUnary {
+                attrs: vec![],
+                op: UnOp::Deref(token::Star(dummy_span())),
+                expr: Box::new(target),
+            }),
+        )
+    };
+
+    let truthy = |target| match left_type {
+        ValueType::Boolean => target,
+        _ => generate_truthy_call(target),
+    };
+    let cond = match op {
+        swc::AssignOp::AndAssign => truthy(target),
+        swc::AssignOp::OrAssign => Expr::Unary(ExprUnary {
+            attrs: vec![],
+            op: UnOp::Not(token::Not(dummy_span())),
+            expr: Box::new(truthy(target)),
+        }),
+        swc::AssignOp::NullishAssign => generate_method_call(target, "is_none", vec![]),
+        _ => unreachable!("AssignOp is not a logical assignment."),
+    };
+
+    let if_expr = Expr::If(ExprIf {
+        attrs: vec![],
+        if_token: token::If(dummy_span()),
+        cond: Box::new(cond),
+        then_branch: Block {
+            brace_token: token::Brace(dummy_span()),
+            stmts: vec![Stmt::Expr(
+                Expr::Assign(ExprAssign {
+                    attrs: vec![],
+                    left: Box::new(place),
+                    eq_token: token::Eq(dummy_span()),
+                    right: Box::new(right),
+                }),
+                Some(token::Semi(dummy_span())),
+            )],
+        },
+        else_branch: None,
+    });
+
+    match binding {
+        Some(binding) => Expr::Block(ExprBlock {
+            attrs: vec![],
+            label: None,
+            block: Block {
+                brace_token: token::Brace(dummy_span()),
+                stmts: vec![binding, Stmt::Expr(if_expr, None)],
+            },
+        }),
+        None => if_expr,
+    }
+}
+
+/// Whether an expression is a place that can be evaluated repeatedly without side effects, like `a` or `a.b.c`.
+fn is_place(expr: &Expr) -> bool {
+    match expr {
+        Expr::Path(_) => true,
+        Expr::Field(field) => is_place(&field.base),
+        _ => false,
+    }
+}
+
+/// Transpiles a property read, which is a field access for named properties and an index for computed ones.
+///
+/// `.length` becomes `len()` on arrays and counts UTF-16 code units on strings.
+pub fn transpile_member(ctx: &mut Context, member: swc::MemberExpr) -> Expr {
+    let obj_type = infer_type(ctx, &member.obj);
+
+    if let ValueType::Struct(_) = obj_type {
+        return transpile_field(ctx, member);
+    }
+
+    // Properties of dynamic objects are read by their key, e.g. `object["a"]`.
+    if let ValueType::Object(_) = obj_type {
+        let key = transpile_object_key(ctx, member.prop);
+
+        return Expr::Index(ExprIndex {
+            attrs: vec![],
+            expr: Box::new(transpile_expr(ctx, *member.obj)),
+            bracket_token: token::Bracket(dummy_span()),
+            index: Box::new(key),
+        });
+    }
+
+    match member_key(&member.prop) {
+        Some(MemberKey::Name(name)) if name == "length" => {
+            let owned = is_owned_string(ctx, &member.obj);
+            let obj = transpile_expr(ctx, *member.obj);
+
+            if obj_type == ValueType::String {
+                generate_path_call(
+                    &["string", "length"],
+                    vec![if owned { generate_ref(obj) } else { obj }],
+                )
+            } else {
+                generate_method_call(obj, "len", vec![])
+            }
+        }
+        Some(MemberKey::Name(name)) => Expr::Field(ExprField {
+            attrs: vec![],
+            base: Box::new(transpile_expr(ctx, *member.obj)),
+            dot_token: token::Dot(dummy_span()),
+            member: Member::Named(Ident::new(&name, dummy_span())),
+        }),
+        Some(MemberKey::Index(index)) => Expr::Index(ExprIndex {
+            attrs: vec![],
+            expr: Box::new(transpile_expr(ctx, *member.obj)),
+            bracket_token: token::Bracket(dummy_span()),
+            index: Box::new(transpile_number_lit(index as f64, NumberType::Usize)),
+        }),
+        None => match member.prop {
+            swc::MemberProp::Computed(computed) => Expr::Index(ExprIndex {
+                attrs: vec![],
+                expr: Box::new(transpile_expr(ctx, *member.obj)),
+                bracket_token: token::Bracket(dummy_span()),
+                index: Box::new(transpile_number(ctx, *computed.expr, NumberType::Usize)),
+            }),
+            swc::MemberProp::PrivateName(_) => todo!("member private name"),
+            swc::MemberProp::Ident(_) => unreachable!("Ident props have a MemberKey."),
+        },
+    }
+}
+
+/// Statically known key of a member expression.
+enum MemberKey {
+    /// A named property, from `a.b` or `a["b"]`.
+    Name(String),
+    /// An array index, from `a[0]`.
+    Index(u32),
+}
+
+/// The key of `a.b`, `a["b"]` and `a[0]`, if it is known without evaluating an expression.
+fn member_key(prop: &swc::MemberProp) -> Option<MemberKey> {
+    match prop {
+        swc::MemberProp::Ident(ident) => Some(MemberKey::Name(ident.sym.to_string())),
+        swc::MemberProp::Computed(computed) => match &*computed.expr {
+            swc::Expr::Lit(swc::Lit::Str(str)) if is_field_name(str.value.as_str()) => {
+                Some(MemberKey::Name(str.value.to_string()))
+            }
+            expr => integer_lit(expr)
+                .and_then(|index| u32::try_from(index).ok())
+                .map(MemberKey::Index),
+        },
+        swc::MemberProp::PrivateName(_) => None,
+    }
+}
+
+/// Transpiles a property of a struct to its field, e.g. `row._2` for `row[2]`.
+fn transpile_field(ctx: &mut Context, member: swc::MemberExpr) -> Expr {
+    let key = member_prop_key(&member.prop).unwrap_or_else(|| todo!("struct computed property"));
+    let base = match *member.obj {
+        swc::Expr::Member(obj) => transpile_member_place(ctx, obj),
+        obj => transpile_expr(ctx, obj),
+    };
+
+    Expr::Field(ExprField {
+        attrs: vec![],
+        base: Box::new(base),
+        dot_token: token::Dot(dummy_span()),
+        member: Member::Named(generate_field_ident(&key)),
+    })
+}
+
+/// Transpiles a member expression used as a place, like an assignment target, to a field access or an index.
+pub fn transpile_member_place(ctx: &mut Context, member: swc::MemberExpr) -> Expr {
+    if let ValueType::Struct(_) = infer_type(ctx, &member.obj) {
+        return transpile_field(ctx, member);
+    }
+
+    let is_object = matches!(infer_type(ctx, &member.obj), ValueType::Object(_));
+    let base = match *member.obj {
+        swc::Expr::Member(obj) => transpile_member_place(ctx, obj),
+        obj => transpile_expr(ctx, obj),
+    };
+
+    if is_object {
+        return Expr::Index(ExprIndex {
+            attrs: vec![],
+            expr: Box::new(base),
+            bracket_token: token::Bracket(dummy_span()),
+            index: Box::new(transpile_object_key(ctx, member.prop)),
+        });
+    }
+
+    match (member_key(&member.prop), member.prop) {
+        (Some(MemberKey::Name(name)), _) => Expr::Field(ExprField {
+            attrs: vec![],
+            base: Box::new(base),
+            dot_token: token::Dot(dummy_span()),
+            member: Member::Named(Ident::new(&name, dummy_span())),
+        }),
+        (Some(MemberKey::Index(index)), _) => Expr::Index(ExprIndex {
+            attrs: vec![],
+            expr: Box::new(base),
+            bracket_token: token::Bracket(dummy_span()),
+            index: Box::new(transpile_number_lit(index as f64, NumberType::Usize)),
+        }),
+        (None, swc::MemberProp::Computed(computed)) => Expr::Index(ExprIndex {
+            attrs: vec![],
+            expr: Box::new(base),
+            bracket_token: token::Bracket(dummy_span()),
+            index: Box::new(transpile_number(ctx, *computed.expr, NumberType::Usize)),
+        }),
+        (None, swc::MemberProp::PrivateName(_)) => todo!("member place private name"),
+        (None, swc::MemberProp::Ident(_)) => unreachable!("Ident props have a MemberKey."),
+    }
+}
+
+/// Transpiles the key of a property of a dynamic object, e.g. `"a"` for `object.a`.
+fn transpile_object_key(ctx: &mut Context, prop: swc::MemberProp) -> Expr {
+    match (member_key(&prop), prop) {
+        (Some(MemberKey::Name(name)), _) => Expr::Lit(ExprLit {
+            attrs: vec![],
+            lit: Lit::Str(LitStr::new(&name, dummy_span())),
+        }),
+        (Some(MemberKey::Index(index)), _) => Expr::Lit(ExprLit {
+            attrs: vec![],
+            lit: Lit::Str(LitStr::new(&index.to_string(), dummy_span())),
+        }),
+        (None, swc::MemberProp::Computed(computed)) => transpile_property_key(ctx, *computed.expr),
+        (None, _) => todo!("member private name"),
+    }
+}
+
+pub fn transpile_assign_op(op: swc::AssignOp) -> BinOp {
+    match op {
+        swc::AssignOp::Assign => panic!("Assign can't be transpiled to BinOp."),
+        swc::AssignOp::AddAssign => BinOp::AddAssign(token::PlusEq(dummy_span())),
+        swc::AssignOp::SubAssign => BinOp::SubAssign(token::MinusEq(dummy_span())),
+        swc::AssignOp::MulAssign => BinOp::MulAssign(token::StarEq(dummy_span())),
+        swc::AssignOp::DivAssign => BinOp::DivAssign(token::SlashEq(dummy_span())),
+        swc::AssignOp::ModAssign => BinOp::RemAssign(token::PercentEq(dummy_span())),
+        swc::AssignOp::LShiftAssign => BinOp::ShlAssign(token::ShlEq(dummy_span())),
+        swc::AssignOp::RShiftAssign => BinOp::ShrAssign(token::ShrEq(dummy_span())),
+        swc::AssignOp::ZeroFillRShiftAssign => {
+            panic!("ZeroFillRShiftAssign can't be transpiled to BinOp.")
+        }
+        swc::AssignOp::BitOrAssign => BinOp::BitOrAssign(token::OrEq(dummy_span())),
+        swc::AssignOp::BitXorAssign => BinOp::BitXorAssign(token::CaretEq(dummy_span())),
+        swc::AssignOp::BitAndAssign => BinOp::BitAndAssign(token::AndEq(dummy_span())),
+        swc::AssignOp::ExpAssign => panic!("ExpAssign can't be transpiled to BinOp."),
+        swc::AssignOp::AndAssign | swc::AssignOp::OrAssign | swc::AssignOp::NullishAssign => {
+            panic!("Logical assignments can't be transpiled to BinOp.")
+        }
+    }
+}
+
+pub fn transpile_call(ctx: &mut Context, call: swc::CallExpr) -> Expr {
+    if call.callee.is_super_() {
+        todo!("call super")
+    } else if call.callee.is_import() {
+        todo!("call import")
+    } else if call.callee.is_expr() {
+        if let Some(method) = math_min_max(ctx, &call) {
+            return transpile_math_min_max(ctx, method, call.args);
+        }
+
+        if let Some(function) = timer_function(ctx, &call) {
+            return transpile_timer_call(ctx, function, call.args);
+        }
+
+        if let Some(name) = call
+            .callee
+            .as_expr()
+            .and_then(|callee| callee.as_ident())
+            .filter(|ident| ctx.overloads(ident.sym.as_str()).is_some())
+            .map(|ident| ident.sym.to_string())
+        {
+            let overload = call_overload(ctx, &call)
+                .cloned()
+                .unwrap_or_else(|| panic!("No overload of `{}` accepts the arguments.", name));
+
+            return transpile_overload_call(ctx, &name, overload, call.args);
+        }
+
+        if promise_call(ctx, &call).is_some() {
+            return transpile_promise_call(ctx, call, None);
+        }
+
+        if let Some((method, element_type)) = array_method(ctx, &call) {
+            let method = method.to_string();
+
+            return transpile_array_method(ctx, call, &method, element_type);
+        }
+
+        // Async functions start running when they are called, even if their result isn't awaited.
+        if let Some(signature) = async_fn_signature(ctx, &call) {
+            let call = transpile_async_call(ctx, call, &signature);
+
+            return generate_path_call(&["Promise", "from_future"], vec![call]);
+        }
+
+        let signature = call_signature(ctx, &call);
+        let expr = call.callee.expr().expect("Callee is Expr.");
+        let args = match signature {
+            Some(signature) => transpile_fn_args(ctx, &signature, call.args),
+            None => call
+                .args
+                .into_iter()
+                .map(|arg| transpile_unknown_arg(ctx, arg))
+                .collect(),
+        };
+
+        // Functions stored in arrays are called like any other value, e.g. `callbacks[0](x)`.
+        if expr.is_member() && !matches!(infer_type(ctx, &expr), ValueType::Function(_)) {
+            let member = expr.member().expect("Expr is Member.");
+
+            let method = match member_key(&member.prop) {
+                Some(MemberKey::Name(name)) => name,
+                _ => todo!("call computed member"),
+            };
+
+            let receiver = transpile_expr(ctx, *member.obj);
+
+            generate_method_call(receiver, &method, args)
+        } else {
+            Expr::Call(ExprCall {
+                attrs: vec![],
+                func: Box::new(transpile_expr(ctx, *expr)),
+                paren_token: token::Paren(dummy_span()),
+                args: Punctuated::from_iter(args),
+            })
+        }
+    } else {
+        unreachable!("Unknown Callee.")
+    }
+}
+
+/// Transpiles a call to an overloaded function, which calls the function of the overload or passes the arguments as
+/// its variant of the argument enum, depending on the `OverloadStrategy`.
+fn transpile_overload_call(
+    ctx: &mut Context,
+    name: &str,
+    overload: Overload,
+    args: Vec<swc::ExprOrSpread>,
+) -> Expr {
+    let args = transpile_fn_args(ctx, &overload.signature, args);
+
+    match ctx.overload_strategy() {
+        OverloadStrategy::Split => generate_path_call(&[&overload.name], args),
+        OverloadStrategy::ArgumentEnum => {
+            let enum_name = overload_enum_name(name);
+            let variant = if args.is_empty() {
+                generate_path_expr(&[&enum_name, &overload.name])
+            } else {
+                generate_path_call(&[&enum_name, &overload.name], args)
+            };
+
+            generate_path_call(&[name], vec![variant])
+        }
+    }
+}
+
+/// Transpiles an argument of a function whose signature isn't known.
+fn transpile_unknown_arg(ctx: &mut Context, arg: swc::ExprOrSpread) -> Expr {
+    if arg.spread.is_some() {
+        todo!("spread into unknown function")
+    }
+
+    match *arg.expr {
+        // `String` bindings are borrowed, so they can still be used after the call.
+        swc::Expr::Ident(ident) if ctx.is_owned_string(ident.sym.as_str()) => {
+            let ident = transpile_expr(ctx, swc::Expr::Ident(ident));
+
+            generate_ref(ident)
+        }
+        expr => transpile_expr(ctx, expr),
+    }
+}
+
+/// Transpiles the arguments of a call to a declared function, converting them to its parameter types.
+///
+/// Spread arrays of statically known length are expanded into the parameters, and the arguments for a rest
+/// parameter are passed as one slice.
+fn transpile_fn_args(
+    ctx: &mut Context,
+    signature: &FnSignature,
+    args: Vec<swc::ExprOrSpread>,
+) -> Vec<Expr> {
+    let mut args = expand_spread_args(ctx, args, signature.params.len()).into_iter();
+
+    let mut transpiled: Vec<Expr> = signature
+        .params
+        .iter()
+        .zip(args.by_ref())
+        .map(|(param_type, arg)| {
+            if arg.spread.is_some() {
+                todo!("spread of unknown length into parameters")
+            }
+
+            if signature.takes_owned_args() {
+                transpile_owned_value(ctx, *arg.expr, param_type)
+            } else {
+                transpile_arg(ctx, *arg.expr, param_type)
+            }
+        })
+        .collect();
+
+    // Omitted arguments of optional parameters are `undefined`.
+    transpiled.extend(
+        signature.params[transpiled.len()..]
+            .iter()
+            .map(|_| generate_ident_expr("None")),
+    );
+
+    if let Some(rest_type) = &signature.rest {
+        let rest: Vec<swc::ExprOrSpread> = args.collect();
+
+        transpiled.push(match &rest[..] {
+            [swc::ExprOrSpread {
+                spread: Some(_),
+                expr,
+            }] if signature.takes_owned_args() => transpile_owned_value(
+                ctx,
+                *expr.clone(),
+                &ValueType::Array(Box::new(rest_type.clone())),
+            ),
+            // Arrays that are spread into a rest parameter are passed as they are.
+            [swc::ExprOrSpread {
+                spread: Some(_),
+                expr,
+            }] => generate_ref(transpile_expr(ctx, *expr.clone())),
+            _ if signature.takes_owned_args() => transpile_elements(ctx, rest, rest_type, true),
+            _ => generate_ref(transpile_elements(ctx, rest, rest_type, false)),
+        });
+    }
+
+    transpiled
+}
+
+/// Expands spread arguments for the first `count` parameters into their elements, if their length is known.
+fn expand_spread_args(
+    ctx: &Context,
+    args: Vec<swc::ExprOrSpread>,
+    count: usize,
+) -> Vec<swc::ExprOrSpread> {
+    let mut expanded = vec![];
+
+    for arg in args {
+        let elements = match (&arg.spread, &*arg.expr) {
+            (Some(_), _) if expanded.len() >= count => None,
+            (Some(_), swc::Expr::Array(array))
+                if array.elems.iter().all(|element| {
+                    element
+                        .as_ref()
+                        .is_some_and(|element| element.spread.is_none())
+                }) =>
+            {
+                Some(array.elems.iter().flatten().cloned().collect())
+            }
+            (Some(_), swc::Expr::Ident(ident)) => {
+                ctx.array_length(ident.sym.as_str()).map(|length| {
+                    (0..length)
+                        .map(|index| swc::ExprOrSpread {
+                            spread: None,
+                            expr: Box::new(generate_swc_index(ident.clone(), index)),
+                        })
+                        .collect()
+                })
+            }
+            _ => None,
+        };
+
+        match elements {
+            Some(elements) => expanded.extend::<Vec<swc::ExprOrSpread>>(elements),
+            None => expanded.push(arg),
+        }
+    }
+
+    expanded
+}
+
+/// Generates `array[index]` as TypeScript, for elements of spread arrays.
+fn generate_swc_index(array: swc::Ident, index: usize) -> swc::Expr {
+    swc::Expr::Member(swc::MemberExpr {
+        span: DUMMY_SP,
+        obj: Box::new(swc::Expr::Ident(array)),
+        prop: swc::MemberProp::Computed(swc::ComputedPropName {
+            span: DUMMY_SP,
+            expr: Box::new(swc::Expr::Lit(swc::Lit::Num(swc::Number {
+                span: DUMMY_SP,
+                value: index as f64,
+                raw: None,
+            }))),
+        }),
+    })
+}
+
+/// Transpiles an argument for a parameter of `param_type`, borrowing strings and arrays.
+fn transpile_arg(ctx: &mut Context, expr: swc::Expr, param_type: &ValueType) -> Expr {
+    match param_type {
+        ValueType::Option(_) if is_nullish_lit(ctx, &expr) => generate_ident_expr("None"),
+        ValueType::Option(_) if matches!(infer_type(ctx, &expr), ValueType::Option(_)) => {
+            transpile_expr(ctx, expr)
+        }
+        ValueType::Option(inner) => {
+            let value = transpile_arg(ctx, expr, inner);
+
+            parse_quote! { Some(#value) }
+        }
+        ValueType::String if is_owned_string(ctx, &expr) => generate_ref(transpile_expr(ctx, expr)),
+        ValueType::String => transpile_expr(ctx, expr),
+        ValueType::Array(_) | ValueType::Object(_) | ValueType::Struct(_) => {
+            generate_ref(transpile_value(ctx, expr, param_type))
+        }
+        ValueType::Function(signature) => transpile_callback_arg(ctx, expr, signature),
+        param_type => transpile_value(ctx, expr, param_type),
+    }
+}
+
+/// Transpiles an array literal to `vec![...]`, see `transpile_elements`.
+pub fn transpile_array(ctx: &mut Context, array: swc::ArrayLit, element_type: &ValueType) -> Expr {
+    let elements = array
+        .elems
+        .into_iter()
+        .map(|element| element.unwrap_or_else(|| todo!("array hole")))
+        .collect();
+
+    transpile_elements(ctx, elements, element_type, true)
+}
+
+/// Transpiles an object literal to a dynamic object, e.g. `Object::from([("a", 1.0), ("b", 2.0)])`.
+pub fn transpile_object(ctx: &mut Context, object: swc::ObjectLit, value_type: &ValueType) -> Expr {
+    if object.props.is_empty() {
+        return parse_quote! { Object::new() };
+    }
+
+    // `Object` has one value type, so other objects need an interface to be transpiled to a struct.
+    if *value_type == ValueType::Unknown {
+        let value_types: Vec<_> = object
+            .props
+            .iter()
+            .filter_map(|prop| match prop.as_prop().map(|prop| &**prop) {
+                Some(swc::Prop::KeyValue(key_value)) => Some(infer_type(ctx, &key_value.value)),
+                Some(swc::Prop::Shorthand(ident)) => {
+                    Some(infer_type(ctx, &swc::Expr::Ident(ident.clone())))
+                }
+                _ => None,
+            })
+            .collect();
+
+        if value_types.windows(2).any(|pair| pair[0] != pair[1]) {
+            panic!(
+                "Object literal has properties of different types {:?}, declare an interface for it.",
+                value_types
+            );
+        }
+    }
+
+    let properties: Vec<Expr> = object
+        .props
+        .into_iter()
+        .map(|prop| {
+            let (key, value) = match prop {
+                swc::PropOrSpread::Prop(prop) => match *prop {
+                    swc::Prop::KeyValue(key_value) => (key_value.key, *key_value.value),
+                    swc::Prop::Shorthand(ident) => {
+                        (swc::PropName::Ident(ident.clone()), swc::Expr::Ident(ident))
+                    }
+                    _ => todo!("object prop"),
+                },
+                swc::PropOrSpread::Spread(_) => todo!("object spread"),
+            };
+            let key = match key {
+                swc::PropName::Ident(ident) => ident.sym.to_string(),
+                swc::PropName::Str(str) => str.value.to_string(),
+                swc::PropName::Num(num) => num.value.to_js_string(),
+                _ => todo!("object computed key"),
+            };
+            let key = LitStr::new(&key, dummy_span());
+            let value = transpile_element(ctx, value, value_type);
+
+            parse_quote! { (#key, #value) }
+        })
+        .collect();
+
+    parse_quote! { Object::from([#(#properties),*]) }
+}
+
+/// Transpiles an object literal of an interface type to its struct, e.g. `Point { x: 1.0, y: None }`, where the
+/// optional properties it leaves out are `None`.
+fn transpile_struct(ctx: &mut Context, object: swc::ObjectLit, name: &str) -> Expr {
+    let mut values: Vec<(String, swc::Expr)> = vec![];
+
+    for prop in object.props {
+        let (key, value) = match prop {
+            swc::PropOrSpread::Prop(prop) => match *prop {
+                swc::Prop::KeyValue(key_value) => (key_value.key, *key_value.value),
+                swc::Prop::Shorthand(ident) => {
+                    (swc::PropName::Ident(ident.clone()), swc::Expr::Ident(ident))
+                }
+                _ => todo!("struct prop"),
+            },
+            swc::PropOrSpread::Spread(_) => todo!("struct spread"),
+        };
+        let key = match key {
+            swc::PropName::Ident(ident) => ident.sym.to_string(),
+            swc::PropName::Str(str) => str.value.to_string(),
+            swc::PropName::Num(num) if num.value.fract() == 0.0 && num.value >= 0.0 => {
+                (num.value as u64).to_string()
+            }
+            _ => todo!("struct computed key"),
+        };
+
+        values.push((key, value));
+    }
+
+    let fields = ctx
+        .struct_fields(name)
+        .unwrap_or_else(|| panic!("Interface `{name}` is declared."))
+        .to_vec();
+    let fields: Vec<FieldValue> = fields
+        .into_iter()
+        .map(|(key, value_type)| {
+            let value = match values.iter().position(|(property, _)| *property == key) {
+                Some(index) => transpile_owned_value(ctx, values.remove(index).1, &value_type),
+                None if matches!(value_type, ValueType::Option(_)) => generate_ident_expr("None"),
+                None => panic!("Object literal of `{name}` has the property `{key}`."),
+            };
+            let key = generate_field_ident(&key);
+
+            parse_quote! { #key: #value }
+        })
+        .collect();
+
+    if let Some((key, _)) = values.first() {
+        panic!("Interface `{name}` has no property `{key}`.")
+    }
+
+    let ident = Ident::new(name, dummy_span());
+
+    parse_quote! { #ident { #(#fields),* } }
+}
+
+/// Transpiles the elements of an array, which are a `vec![...]`, `empty_array()` or an array `[...]`, or concatenated
+/// from slices if other arrays are spread into it, e.g. `[&[a][..], &b[..]].concat()`.
+fn transpile_elements(
+    ctx: &mut Context,
+    elements: Vec<swc::ExprOrSpread>,
+    element_type: &ValueType,
+    vec: bool,
+) -> Expr {
+    if elements.iter().all(|element| element.spread.is_none()) {
+        let elements: Vec<Expr> = elements
+            .into_iter()
+            .map(|element| transpile_element(ctx, *element.expr, element_type))
+            .collect();
+
+        return if vec && elements.is_empty() {
+            parse_quote! { empty_array() }
+        } else if vec {
+            parse_quote! { vec![#(#elements),*] }
+        } else {
+            parse_quote! { [#(#elements),*] }
+        };
+    }
+
+    let mut slices = vec![];
+    let mut values = vec![];
+
+    for element in elements {
+        if element.spread.is_some() {
+            if !values.is_empty() {
+                slices.push(parse_quote! { &[#(#values),*][..] });
+                values.clear();
+            }
+
+            let array = transpile_expr(ctx, *element.expr);
+            slices.push(parse_quote! { &#array[..] });
+        } else {
+            values.push(transpile_element(ctx, *element.expr, element_type));
+        }
+    }
+
+    if !values.is_empty() {
+        slices.push(parse_quote! { &[#(#values),*][..] });
+    }
+
+    let slices: Vec<Expr> = slices;
+
+    generate_method_call(parse_quote! { [#(#slices),*] }, "concat", vec![])
+}
+
+/// Transpiles an element of an array, which owns its strings.
+fn transpile_element(ctx: &mut Context, expr: swc::Expr, element_type: &ValueType) -> Expr {
+    match element_type {
+        ValueType::String => transpile_owned_string(ctx, expr),
+        ValueType::Unknown => transpile_expr(ctx, expr),
+        element_type => transpile_value(ctx, expr, element_type),
+    }
+}
+
+/// Whether a call is `Math.min` or `Math.max`, which take any number of arguments.
+fn math_min_max(ctx: &Context, call: &swc::CallExpr) -> Option<&'static str> {
+    if !is_math_call(ctx, call) {
+        return None;
+    }
+
+    let member = call.callee.as_expr()?.as_member()?;
+
+    match member_key(&member.prop) {
+        Some(MemberKey::Name(name)) if name == "min" => Some("min"),
+        Some(MemberKey::Name(name)) if name == "max" => Some("max"),
+        _ => None,
+    }
+}
+
+/// Transpiles `Math.min` and `Math.max`, which compare their arguments pairwise with `number::min` and `number::max`.
+///
+/// Spread arrays are folded, e.g. `Math.max(...xs)` becomes `xs.iter().copied().fold(f64::NEG_INFINITY, number::max)`.
+fn transpile_math_min_max(ctx: &mut Context, method: &str, args: Vec<swc::ExprOrSpread>) -> Expr {
+    let helper = generate_path_expr(&["number", method]);
+
+    if args.iter().all(|arg| arg.spread.is_none()) {
+        let mut args = args
+            .into_iter()
+            .map(|arg| transpile_number(ctx, *arg.expr, NumberType::F64));
+
+        return match args.next() {
+            Some(first) => args.fold(first, |result, arg| {
+                generate_path_call(&["number", method], vec![result, arg])
+            }),
+            None => transpile_number_lit(min_max_identity(method), NumberType::F64),
+        };
+    }
+
+    let number = ValueType::Number(NumberType::F64);
+    let values = match &args[..] {
+        [swc::ExprOrSpread {
+            spread: Some(_),
+            expr,
+        }] => {
+            let array = transpile_expr(ctx, *expr.clone());
+
+            generate_method_call(
+                generate_method_call(array, "iter", vec![]),
+                "copied",
+                vec![],
+            )
+        }
+        _ => generate_method_call(
+            transpile_elements(ctx, args, &number, false),
+            "into_iter",
+            vec![],
+        ),
+    };
+
+    generate_method_call(
+        values,
+        "fold",
+        vec![
+            transpile_number_lit(min_max_identity(method), NumberType::F64),
+            helper,
+        ],
+    )
+}
+
+/// The result of `Math.min()` and `Math.max()` without arguments.
+fn min_max_identity(method: &str) -> f64 {
+    match method {
+        "min" => f64::INFINITY,
+        _ => f64::NEG_INFINITY,
+    }
+}
+
+pub fn transpile_ident(ident: swc::Ident) -> Expr {
+    Expr::Path(ExprPath {
+        attrs: vec![],
+        qself: None,
+        path: Path::from(PathSegment {
+            ident: Ident::new(ident.sym.as_str(), dummy_span()),
+            arguments: PathArguments::None,
+        }),
+    })
+}
+
+pub fn transpile_lit(lit: swc::Lit) -> Expr {
+    match lit {
+        swc::Lit::Str(str) => Expr::Lit(ExprLit {
+            attrs: vec![],
+            lit: Lit::Str(LitStr::new(str.value.as_str(), dummy_span())),
+        }),
+        swc::Lit::Bool(bool) => Expr::Lit(ExprLit {
+            attrs: vec![],
+            lit: Lit::Bool(LitBool::new(bool.value, dummy_span())),
+        }),
+        swc::Lit::Null(_) => generate_ident_expr("null"),
+        swc::Lit::Num(num) => transpile_number_lit(num.value, NumberType::F64),
+        swc::Lit::BigInt(_) => todo!(),
+        swc::Lit::Regex(_) => todo!(),
+        swc::Lit::JSXText(_) => todo!(),
+    }
+}
+
+/// Transpiles an expression to a number of the given type, converting it if it has another number type.
+pub fn transpile_number(ctx: &mut Context, expr: swc::Expr, number_type: NumberType) -> Expr {
+    if integer_lit(&expr).is_some() {
+        // The float value keeps the sign of `-0`.
+        let value = number_lit(&expr).expect("Integer literal is a number literal.");
+
+        return transpile_number_lit(value, number_type);
+    }
+
+    let expr_type = infer_type(ctx, &expr).number_type();
+
+    match expr {
+        // Converting the operands instead of the result keeps e.g. `i32` sums or `usize` differences from
+        // overflowing before they are widened.
+        swc::Expr::Bin(bin)
+            if number_type.is_integer()
+                && expr_type.is_some_and(|expr_type| {
+                    expr_type.is_integer() && expr_type != number_type
+                })
+                && matches!(
+                    bin.op,
+                    swc::BinaryOp::Add
+                        | swc::BinaryOp::Sub
+                        | swc::BinaryOp::Mul
+                        | swc::BinaryOp::Mod
+                ) =>
+        {
+            Expr::Binary(ExprBinary {
+                attrs: vec![],
+                left: Box::new(transpile_number(ctx, *bin.left, number_type)),
+                op: transpile_bin_op(bin.op),
+                right: Box::new(transpile_number(ctx, *bin.right, number_type)),
+            })
+        }
+        swc::Expr::Cond(cond) if expr_type != Some(number_type) => {
+            transpile_number_cond(ctx, cond, number_type)
+        }
+        expr => cast_number(transpile_expr(ctx, expr), number_type, expr_type),
+    }
+}
+
+pub fn transpile_number_lit(value: f64, number_type: NumberType) -> Expr {
+    if value.is_infinite() {
+        return Expr::Path(ExprPath {
+            attrs: vec![],
+            qself: None,
+            path: Path {
+                leading_colon: None,
+                segments: Punctuated::from_iter(vec![
+                    PathSegment {
+                        ident: Ident::new("f64", dummy_span()),
+                        arguments: PathArguments::None,
+                    },
+                    PathSegment {
+                        ident: Ident::new(
+                            if value > 0.0 {
+                                "INFINITY"
+                            } else {
+                                "NEG_INFINITY"
+                            },
+                            dummy_span(),
+                        ),
+                        arguments: PathArguments::None,
+                    },
+                ]),
+            },
+        });
+    }
+
+    let lit = if number_type.is_integer() {
+        Lit::Int(LitInt::new(&(value as i64).abs().to_string(), dummy_span()))
+    } else {
+        let mut value = value.abs().to_string();
+        if !value.contains('.') {
+            value.push_str(".0");
+        }
+
+        Lit::Float(LitFloat::new(&value, dummy_span()))
+    };
+
+    let expr = Expr::Lit(ExprLit { attrs: vec![], lit });
+
+    // Integers have no `-0`, while `-0.0` keeps it for floats.
+    if value.is_sign_negative() && (value != 0.0 || !number_type.is_integer()) {
+        Expr::Unary(ExprUnary {
+            attrs: vec![],
+            op: UnOp::Neg(token::Minus(dummy_span())),
+            expr: Box::new(expr),
+        })
+    } else {
+        expr
+    }
+}
+
+/// Casts a number expression of type `from` to type `to`, where unknown types are assumed to be `f64`.
+pub fn cast_number(expr: Expr, to: NumberType, from: Option<NumberType>) -> Expr {
+    if from.unwrap_or(NumberType::F64) == to {
+        return expr;
+    }
+
+    Expr::Cast(ExprCast {
+        attrs: vec![],
+        expr: Box::new(match expr {
+            expr @ (Expr::Path(_)
+            | Expr::Lit(_)
+            | Expr::Paren(_)
+            | Expr::Call(_)
+            | Expr::MethodCall(_)
+            | Expr::Field(_)
+            | Expr::Index(_)) => expr,
+            expr => Expr::Paren(ExprParen {
+                attrs: vec![],
+                paren_token: token::Paren(dummy_span()),
+                expr: Box::new(expr),
+            }),
+        }),
+        as_token: token::As(dummy_span()),
+        ty: Box::new(Type::Path(TypePath {
+            qself: None,
+            path: Path::from(PathSegment {
+                ident: Ident::new(to.name(), dummy_span()),
+                arguments: PathArguments::None,
+            }),
+        })),
+    })
+}
+
+/// Transpiles `base ** exponent`, which is right-associative in the swc AST already.
+///
+/// Integer literal exponents use `pow` for integer bases and `powi` for floats, other exponents go through
+/// `number::pow`, which handles the cases where `powf` differs from TypeScript.
+pub fn transpile_exp(ctx: &mut Context, base: swc::Expr, exponent: swc::Expr) -> Expr {
+    let number_type = exp_number_type(ctx, &base, &exponent);
+    let base = transpile_number(ctx, base, number_type);
+
+    generate_exp(ctx, base, number_type, exponent)
+}
+
+/// Generates `base ** exponent` for a base that is already converted to the number type of the power, see
+/// `exp_number_type`.
+fn generate_exp(
+    ctx: &mut Context,
+    base: Expr,
+    number_type: NumberType,
+    exponent: swc::Expr,
+) -> Expr {
+    match integer_lit(&exponent) {
+        Some(value) if number_type.is_integer() => generate_path_call(
+            &[number_type.name(), "pow"],
+            vec![base, transpile_number_lit(value as f64, NumberType::I32)],
+        ),
+        Some(value) if i32::try_from(value).is_ok() => generate_path_call(
+            &["f64", "powi"],
+            vec![base, transpile_number_lit(value as f64, NumberType::I32)],
+        ),
+        _ => {
+            let exponent = transpile_number(ctx, exponent, NumberType::F64);
+
+            generate_number_helper_call("pow", base, exponent)
+        }
+    }
+}
+
+/// Name of the `ts_std::number` function implementing a bitwise or shift operator.
+fn bitwise_helper(op: swc::BinaryOp) -> &'static str {
+    match op {
+        swc::BinaryOp::BitAnd => "bit_and",
+        swc::BinaryOp::BitOr => "bit_or",
+        swc::BinaryOp::BitXor => "bit_xor",
+        swc::BinaryOp::LShift => "shl",
+        swc::BinaryOp::RShift => "shr",
+        swc::BinaryOp::ZeroFillRShift => "ushr",
+        _ => unreachable!("BinaryOp is not bitwise."),
+    }
+}
+
+fn transpile_number_helper(
+    ctx: &mut Context,
+    helper: &str,
+    left: swc::Expr,
+    right: swc::Expr,
+) -> Expr {
+    let left = transpile_number(ctx, left, NumberType::F64);
+    let right = transpile_number(ctx, right, NumberType::F64);
+
+    generate_number_helper_call(helper, left, right)
+}
+
+/// Generates a call to a function of `ts_std::number`, e.g. `number::bit_or(a, b)`.
+pub fn generate_number_helper_call(helper: &str, left: Expr, right: Expr) -> Expr {
+    generate_path_call(&["number", helper], vec![left, right])
+}
+
+/// Generates a path expression, e.g. `number::max`.
+pub fn generate_path_expr(segments: &[&str]) -> Expr {
+    Expr::Path(ExprPath {
+        attrs: vec![],
+        qself: None,
+        path: Path {
+            leading_colon: None,
+            segments: Punctuated::from_iter(segments.iter().map(|segment| PathSegment {
+                ident: Ident::new(segment, dummy_span()),
+                arguments: PathArguments::None,
+            })),
+        },
+    })
+}
+
+/// Generates a call to a function by its path, e.g. `f64::powi(a, 2)`.
+///
+/// Parentheses around arguments are dropped, since they are already delimited by the call.
+pub fn generate_path_call(segments: &[&str], args: Vec<Expr>) -> Expr {
+    Expr::Call(ExprCall {
+        attrs: vec![],
+        func: Box::new(generate_path_expr(segments)),
+        paren_token: token::Paren(dummy_span()),
+        args: Punctuated::from_iter(args.into_iter().map(|arg| match arg {
+            Expr::Paren(paren) => *paren.expr,
+            arg => arg,
+        })),
+    })
+}
+
+/// Transpiles an arrow function or `function` expression to a Rust closure with typed parameters.
+///
+/// Parameters without annotations have the types of `expected`, e.g. the parameter types of the function the closure is
+/// passed to. Closures that are stored as `Rc<dyn Fn>` move their captures, since they may outlive the bindings.
+pub fn transpile_closure(
+    ctx: &mut Context,
+    expr: swc::Expr,
+    expected: Option<&FnSignature>,
+    move_captures: bool,
+) -> Expr {
+    let param_types = expected
+        .map(|expected| expected.params.clone())
+        .unwrap_or_default();
+    let signature =
+        function_expr_signature(ctx, &expr, &param_types).expect("Expr is a function expression.");
+    let has_return_annotation =
+        function_expr_parts(&expr).is_some_and(|(_, return_type, _)| return_type.is_some());
+    let return_type = match expected {
+        Some(expected) if !has_return_annotation => expected.return_type.clone(),
+        _ => signature.return_type.clone(),
+    };
+
+    let (pats, body) = transpile_function_expr(ctx, expr, &signature.params, return_type, false);
+    let inputs = pats
+        .into_iter()
+        .zip(&signature.params)
+        .map(|(pat, param_type)| match param_type {
+            ValueType::Unknown => pat,
+            param_type => Pat::Type(PatType {
+                attrs: vec![],
+                pat: Box::new(pat),
+                colon_token: token::Colon(dummy_span()),
+                ty: Box::new(param_type_of(param_type)),
+            }),
+        })
+        .collect();
+
+    generate_closure(inputs, body, move_captures)
+}
+
+/// Rust type of a closure parameter, like `param_type`, except that callbacks are borrowed as `&dyn Fn`.
+fn param_type_of(param_type: &ValueType) -> Type {
+    match param_type {
+        ValueType::Function(signature) => {
+            let bound = generate_fn_bound(signature, "Fn");
+
+            parse_quote!(&dyn #bound)
+        }
+        param_type => types::param_type(param_type),
+    }
+}
+
+fn generate_closure(inputs: Vec<Pat>, body: Expr, move_captures: bool) -> Expr {
+    Expr::Closure(ExprClosure {
+        attrs: vec![],
+        lifetimes: None,
+        constness: None,
+        movability: None,
+        asyncness: None,
+        capture: move_captures.then(|| token::Move(dummy_span())),
+        or1_token: token::Or(dummy_span()),
+        inputs: Punctuated::from_iter(inputs),
+        or2_token: token::Or(dummy_span()),
+        output: ReturnType::Default,
+        body: Box::new(body),
+    })
+}
+
+/// Transpiles the parameters and body of a function expression, where the parameters have `param_types` and returned
+/// values are converted to `return_type`.
+///
+/// Owned parameters are `String`s and `Rc<dyn Fn>`s instead of borrows and closures, like the values that promises
+/// pass to their callbacks.
+fn transpile_function_expr(
+    ctx: &mut Context,
+    expr: swc::Expr,
+    param_types: &[ValueType],
+    return_type: Option<ValueType>,
+    owned_params: bool,
+) -> (Vec<Pat>, Expr) {
+    let (params, body) = match expr {
+        swc::Expr::Paren(paren) => {
+            return transpile_function_expr(
+                ctx,
+                *paren.expr,
+                param_types,
+                return_type,
+                owned_params,
+            )
+        }
+        swc::Expr::Arrow(arrow) => {
+            if arrow.is_async {
+                todo!("async arrow")
+            } else if arrow.is_generator {
+                todo!("generator arrow")
+            }
+
+            (arrow.params, *arrow.body)
+        }
+        swc::Expr::Fn(fn_expr) => {
+            let function = *fn_expr.function;
+
+            if function.is_async {
+                todo!("async fn expr")
+            } else if function.is_generator {
+                todo!("generator fn expr")
+            }
+
+            (
+                function.params.into_iter().map(|param| param.pat).collect(),
+                swc::BlockStmtOrExpr::BlockStmt(function.body.expect("Function has a body.")),
+            )
+        }
+        _ => unreachable!("Expr is a function expression."),
+    };
+
+    // Parameters are scoped to the closure, so only assignments in its body make them mutable.
+    let modifications = find_modifications(&body);
+
+    ctx.with_function(return_type.clone(), |ctx| {
+        let pats = params
+            .into_iter()
+            .enumerate()
+            .map(|(index, pat)| {
+                let ident = match pat {
+                    swc::Pat::Ident(ident) => ident.id.sym.to_string(),
+                    _ => todo!("closure param pattern"),
+                };
+                let value_type = param_types
+                    .get(index)
+                    .cloned()
+                    .unwrap_or(ValueType::Unknown);
+
+                ctx.declare_closure(
+                    &ident,
+                    !owned_params && matches!(value_type, ValueType::Function(_)),
+                );
+                ctx.declare_string(&ident, owned_params && value_type == ValueType::String);
+                ctx.declare_array_length(&ident, None);
+                ctx.declare(&ident, value_type);
+
+                Pat::Ident(PatIdent {
+                    attrs: vec![],
+                    by_ref: None,
+                    mutability: modifications
+                        .is_reassigned(&ident)
+                        .then(|| token::Mut(dummy_span())),
+                    ident: Ident::new(&ident, dummy_span()),
+                    subpat: None,
+                })
+            })
+            .collect();
+
+        let body = match body {
+            swc::BlockStmtOrExpr::BlockStmt(block) => {
+                declare_functions(ctx, &block.stmts);
+
+                Expr::Block(ExprBlock {
+                    attrs: vec![],
+                    label: None,
+                    block: Block {
+                        brace_token: token::Brace(dummy_span()),
+                        stmts: transpile_stmts(ctx, block.stmts),
+                    },
+                })
+            }
+            // Closures that don't return a value only run the expression.
+            swc::BlockStmtOrExpr::Expr(expr) if return_type.is_none() => Expr::Block(ExprBlock {
+                attrs: vec![],
+                label: None,
+                block: Block {
+                    brace_token: token::Brace(dummy_span()),
+                    stmts: transpile_expr_stmt(ctx, *expr),
+                },
+            }),
+            swc::BlockStmtOrExpr::Expr(expr) => {
+                transpile_return_value(ctx, *expr, return_type.as_ref())
+            }
+        };
+
+        (pats, body)
+    })
+}
+
+/// Transpiles a returned value, converting it to the return type of the function.
+///
+/// Values returned as `boolean` are converted with `Truthy`, since callbacks like the one of `Array.filter` may
+/// return any value.
+pub fn transpile_return_value(
+    ctx: &mut Context,
+    expr: swc::Expr,
+    return_type: Option<&ValueType>,
+) -> Expr {
+    match return_type {
+        Some(ValueType::String) => transpile_owned_string(ctx, expr),
+        Some(ValueType::Boolean) => transpile_condition(ctx, expr),
+        Some(return_type) => transpile_value(ctx, expr, return_type),
+        None => transpile_expr(ctx, expr),
+    }
+}
+
+/// Transpiles a function stored as an `Rc<dyn Fn>`, e.g. in an array or a reassigned binding.
+fn transpile_stored_function(ctx: &mut Context, expr: swc::Expr, signature: &FnSignature) -> Expr {
+    let bound = generate_fn_bound(signature, "Fn");
+    let function = match expr {
+        swc::Expr::Paren(paren) => return transpile_stored_function(ctx, *paren.expr, signature),
+        expr @ (swc::Expr::Arrow(_) | swc::Expr::Fn(_)) => {
+            transpile_closure(ctx, expr, Some(signature), true)
+        }
+        swc::Expr::Ident(ident)
+            if ctx.is_closure(ident.sym.as_str()) || ctx.function(ident.sym.as_str()).is_some() =>
+        {
+            transpile_ident(ident)
+        }
+        // Stored functions are shared, like functions in TypeScript.
+        expr @ (swc::Expr::Ident(_) | swc::Expr::Member(_)) => {
+            let expr = transpile_expr(ctx, expr);
+
+            return generate_method_call(expr, "clone", vec![]);
+        }
+        expr => return transpile_expr(ctx, expr),
+    };
+
+    parse_quote! { Rc::new(#function) as Rc<dyn #bound> }
+}
+
+/// Transpiles a function passed for an `impl Fn` parameter, borrowing closures and stored functions.
+fn transpile_callback_arg(ctx: &mut Context, expr: swc::Expr, signature: &FnSignature) -> Expr {
+    match expr {
+        swc::Expr::Paren(paren) => transpile_callback_arg(ctx, *paren.expr, signature),
+        expr @ (swc::Expr::Arrow(_) | swc::Expr::Fn(_)) => {
+            transpile_closure(ctx, expr, Some(signature), false)
+        }
+        swc::Expr::Ident(ident) if ctx.function(ident.sym.as_str()).is_some() => {
+            transpile_ident(ident)
+        }
+        swc::Expr::Ident(ident) if ctx.is_closure(ident.sym.as_str()) => {
+            let mutable = ctx.is_mutating_closure(ident.sym.as_str());
+
+            Expr::Reference(ExprReference {
+                attrs: vec![],
+                and_token: token::And(dummy_span()),
+                mutability: mutable.then(|| token::Mut(dummy_span())),
+                expr: Box::new(transpile_ident(ident)),
+            })
+        }
+        expr => {
+            let expr = transpile_expr(ctx, expr);
+
+            parse_quote! { &*#expr }
+        }
+    }
+}
+
+/// Transpiles array methods that take a callback to iterator adapters, e.g. `xs.map((x) => x * 2)` becomes
+/// `xs.iter().copied().map(|x| x * 2.0).collect::<Vec<_>>()`.
+///
+/// Callbacks that take the index get the elements with `enumerate`, so the index is a `usize`.
+fn transpile_array_method(
+    ctx: &mut Context,
+    call: swc::CallExpr,
+    method: &str,
+    element_type: ValueType,
+) -> Expr {
+    let return_type = infer_type(ctx, &swc::Expr::Call(call.clone()));
+    let member = call
+        .callee
+        .expr()
+        .expect("Callee is Expr.")
+        .member()
+        .expect("Expr is Member.");
+    let elements = generate_element_iter(transpile_expr(ctx, *member.obj), &element_type);
+    let mut args = call.args.into_iter().map(|arg| {
+        if arg.spread.is_some() {
+            todo!("spread into array method")
+        }
+
+        *arg.expr
+    });
+    let callback = args.next().expect("Array method has a callback.");
+    let index_type = ValueType::Number(NumberType::Usize);
+
+    if method == "reduce" {
+        let initial = args.next();
+        let (pats, body) = transpile_callback(
+            ctx,
+            callback,
+            &[return_type.clone(), element_type, index_type],
+            Some(return_type.clone()),
+        );
+        let mut pats = pats.into_iter();
+        let accumulator = pats.next().unwrap_or_else(|| parse_quote!(_));
+        let (elements, element) = generate_enumerated(elements, pats.collect());
+        let callback = generate_closure(vec![accumulator, element], body, false);
+
+        return match initial {
+            Some(initial) => {
+                let initial = transpile_return_value(ctx, initial, Some(&return_type));
+
+                parse_quote! { #elements.fold(#initial, #callback) }
+            }
+            None => parse_quote! {
+                #elements.reduce(#callback).expect("Reduce of empty array with no initial value")
+            },
+        };
+    }
+
+    let callback_return_type = match method {
+        "map" => match &return_type {
+            ValueType::Array(mapped_type) => Some((**mapped_type).clone()),
+            _ => None,
+        },
+        "forEach" => None,
+        _ => Some(ValueType::Boolean),
+    };
+    let (pats, body) = transpile_callback(
+        ctx,
+        callback,
+        &[element_type.clone(), index_type],
+        callback_return_type,
+    );
+    let is_enumerated = pats.len() > 1;
+    let (elements, element) = generate_enumerated(elements, pats);
+
+    match method {
+        "map" => {
+            let callback = generate_closure(vec![element], body, false);
+
+            parse_quote! { #elements.map(#callback).collect::<Vec<_>>() }
+        }
+        "forEach" => {
+            let callback = generate_closure(vec![element], body, false);
+
+            parse_quote! { #elements.for_each(#callback) }
+        }
+        "some" | "every" => {
+            let callback = generate_closure(vec![element], body, false);
+            let adapter = Ident::new(if method == "some" { "any" } else { "all" }, dummy_span());
+
+            parse_quote! { #elements.#adapter(#callback) }
+        }
+        "filter" | "find" => {
+            // The adapters pass the elements by reference.
+            let callback = generate_closure(
+                vec![Pat::Reference(PatReference {
+                    attrs: vec![],
+                    and_token: token::And(dummy_span()),
+                    mutability: None,
+                    pat: Box::new(element),
+                })],
+                body,
+                false,
+            );
+            let adapter = Ident::new(method, dummy_span());
+            let mut found: Expr = parse_quote! { #elements.#adapter(#callback) };
+
+            if is_enumerated {
+                found = parse_quote! { #found.map(|(_, element)| element) };
+            }
+
+            if let Some(to_owned) = generate_element_to_owned(&element_type) {
+                found = parse_quote! { #found.map(#to_owned) };
+            }
+
+            match method {
+                "filter" => parse_quote! { #found.collect::<Vec<_>>() },
+                _ => found,
+            }
+        }
+        _ => unreachable!("Unknown array method."),
+    }
+}
+
+/// Adds `enumerate` to an iterator if the callback takes the index after the element, with the closure pattern for
+/// the items, e.g. `(index, element)`.
+fn generate_enumerated(elements: Expr, pats: Vec<Pat>) -> (Expr, Pat) {
+    let mut pats = pats.into_iter();
+
+    match (pats.next(), pats.next()) {
+        (Some(element), Some(index)) => (
+            parse_quote! { #elements.enumerate() },
+            parse_quote! { (#index, #element) },
+        ),
+        (Some(element), None) => (elements, element),
+        (None, _) => (elements, parse_quote!(_)),
+    }
+}
+
+/// Transpiles the callback of an array method to the parameters and body of a closure, where the parameters have
+/// `param_types`.
+///
+/// Function expressions are inlined, and other functions are called with as many arguments as they take.
+fn transpile_callback(
+    ctx: &mut Context,
+    callback: swc::Expr,
+    param_types: &[ValueType],
+    return_type: Option<ValueType>,
+) -> (Vec<Pat>, Expr) {
+    if function_expr_parts(&callback).is_some() {
+        let param_count = function_expr_parts(&callback)
+            .map(|(params, _, _)| params.len())
+            .unwrap_or_default();
+
+        if param_count > param_types.len() {
+            todo!("array method callback with the array parameter")
+        }
+
+        return transpile_function_expr(ctx, callback, param_types, return_type, false);
+    }
+
+    let signature = match infer_type(ctx, &callback) {
+        ValueType::Function(signature) => *signature,
+        _ => todo!("array method callback of unknown type"),
+    };
+    let names = ["accumulator", "element", "index"];
+    let names = match param_types.len() {
+        3 => &names[..],
+        _ => &names[1..],
+    };
+    let arity = signature.params.len().min(param_types.len());
+    let pats = names[..arity]
+        .iter()
+        .map(|name| {
+            Pat::Ident(PatIdent {
+                attrs: vec![],
+                by_ref: None,
+                mutability: None,
+                ident: Ident::new(name, dummy_span()),
+                subpat: None,
+            })
+        })
+        .collect();
+    let args = names[..arity]
+        .iter()
+        .zip(param_types)
+        .zip(&signature.params)
+        .map(|((name, arg_type), param_type)| {
+            let arg = generate_ident_expr(name);
+
+            match (arg_type, param_type) {
+                (ValueType::Number(from), ValueType::Number(to)) => {
+                    cast_number(arg, *to, Some(*from))
+                }
+                (ValueType::String, _) if param_types.len() == 3 && *name == "accumulator" => {
+                    generate_ref(arg)
+                }
+                _ => arg,
+            }
+        })
+        .collect();
+    let callback = transpile_expr(ctx, callback);
+    let call = Expr::Call(ExprCall {
+        attrs: vec![],
+        func: Box::new(callback),
+        paren_token: token::Paren(dummy_span()),
+        args: Punctuated::from_iter::<Vec<Expr>>(args),
+    });
+    let body = match (&return_type, &signature.return_type) {
+        (Some(ValueType::Boolean), Some(ValueType::Boolean)) => call,
+        (Some(ValueType::Boolean), _) => generate_method_call(call, "truthy", vec![]),
+        _ => call,
+    };
+
+    (pats, body)
+}
+
+/// Generates an iterator over the elements of an array as they are passed to functions, e.g. `xs.iter().copied()`
+/// for numbers and `xs.iter().map(String::as_str)` for strings.
+pub fn generate_element_iter(array: Expr, element_type: &ValueType) -> Expr {
+    let iter = generate_method_call(array, "iter", vec![]);
+
+    match element_type {
+        ValueType::Boolean | ValueType::Number(_) => generate_method_call(iter, "copied", vec![]),
+        ValueType::String => generate_method_call(iter, "map", vec![parse_quote!(String::as_str)]),
+        ValueType::Array(_) => generate_method_call(iter, "map", vec![parse_quote!(Vec::as_slice)]),
+        ValueType::Object(_) | ValueType::Struct(_) | ValueType::Function(_) => iter,
+        ValueType::Option(_) | ValueType::Promise(_) | ValueType::Unknown => {
+            generate_method_call(iter, "cloned", vec![])
+        }
+        ValueType::Generator(..) => todo!("array of generators"),
+        ValueType::AsyncGenerator(..) => generate_method_call(iter, "cloned", vec![]),
+    }
+}
+
+/// The function converting the items of `generate_element_iter` back to owned elements, if they are borrowed.
+fn generate_element_to_owned(element_type: &ValueType) -> Option<Expr> {
+    match element_type {
+        ValueType::String => Some(parse_quote!(str::to_string)),
+        ValueType::Array(_) => Some(parse_quote!(<[_]>::to_vec)),
+        ValueType::Object(_) => Some(parse_quote!(Object::clone)),
+        ValueType::Struct(name) => {
+            let ident = Ident::new(name, dummy_span());
+
+            Some(parse_quote!(#ident::clone))
+        }
+        ValueType::Function(_) => Some(parse_quote!(Rc::clone)),
+        _ => None,
+    }
+}
+
+/// The signature of the callee of a call to an async function declaration.
+fn async_fn_signature(ctx: &Context, call: &swc::CallExpr) -> Option<FnSignature> {
+    let callee = call.callee.as_expr()?.as_ident()?;
+
+    ctx.function(callee.sym.as_str())
+        .filter(|signature| signature.returns_promise())
+        .cloned()
+}
+
+/// Transpiles a call to an async function to the future it returns, e.g. `fetch_user(id)`.
+fn transpile_async_call(ctx: &mut Context, call: swc::CallExpr, signature: &FnSignature) -> Expr {
+    let callee = call
+        .callee
+        .expr()
+        .expect("Callee is Expr.")
+        .ident()
+        .expect("Expr is Ident.");
+    let args = transpile_fn_args(ctx, signature, call.args);
+
+    Expr::Call(ExprCall {
+        attrs: vec![],
+        func: Box::new(transpile_ident(callee)),
+        paren_token: token::Paren(dummy_span()),
+        args: Punctuated::from_iter(args),
+    })
+}
+
+/// Transpiles `await` to `.await` on a `Promise`, which resumes in a microtask like in JavaScript.
+///
+/// Values that aren't promises are awaited as a resolved promise, so they take a microtask as well.
+fn transpile_await(ctx: &mut Context, await_expr: swc::AwaitExpr) -> Expr {
+    let arg = *await_expr.arg;
+    let promise = match infer_type(ctx, &arg) {
+        promise_type @ ValueType::Promise(_) => transpile_value(ctx, arg, &promise_type),
+        ValueType::Unknown => {
+            let value = transpile_expr(ctx, arg);
+
+            generate_path_call(&["Promise", "resolve"], vec![value])
+        }
+        value_type => {
+            let value = transpile_owned_value(ctx, arg, &value_type);
+
+            generate_path_call(&["Promise", "resolve"], vec![value])
+        }
+    };
+
+    Expr::Await(ExprAwait {
+        attrs: vec![],
+        base: Box::new(promise),
+        dot_token: token::Dot(dummy_span()),
+        await_token: token::Await(dummy_span()),
+    })
+}
+
+/// Transpiles `yield` in the body of an async generator, which hands the value to the `Yielder` and waits for the
+/// next value to be requested, e.g. `yielder.yield_value(x).await`.
+///
+/// `yield*` delegates to another async generator, and evaluates to the value it returned.
+fn transpile_yield(ctx: &mut Context, yield_expr: swc::YieldExpr) -> Expr {
+    let (yielder, yield_type) = ctx
+        .yielder()
+        .map(|(yielder, yield_type)| (Ident::new(yielder, dummy_span()), yield_type.clone()))
+        .unwrap_or_else(|| todo!("yield outside of a generator statement"));
+    let arg = *yield_expr
+        .arg
+        .unwrap_or_else(|| todo!("yield without a value"));
+
+    if yield_expr.delegate {
+        let generator = match infer_type(ctx, &arg) {
+            ValueType::AsyncGenerator(..) => transpile_expr(ctx, arg),
+            _ => todo!("yield* of something other than an async generator"),
+        };
+
+        parse_quote!(#yielder.yield_all(#generator).await)
+    } else {
+        let value = transpile_owned_value(ctx, arg, &yield_type);
+
+        parse_quote!(#yielder.yield_value(#value).await)
+    }
+}
+
+/// Transpiles `new Promise(executor)`, where the executor gets `resolve` and `reject` as `Rc<dyn Fn>`s.
+///
+/// Promises of `void` are created with `Promise::new_void`, since their `resolve` takes no value.
+fn transpile_promise_constructor(ctx: &mut Context, new: swc::NewExpr) -> Expr {
+    let value_type = match infer_type(ctx, &swc::Expr::New(new.clone())) {
+        ValueType::Promise(value_type) => value_type.map(|value_type| *value_type),
+        _ => unreachable!("Promises have a promise type."),
+    };
+    let executor = match new.args.as_deref() {
+        Some([swc::ExprOrSpread { spread: None, expr }]) if function_expr_parts(expr).is_some() => {
+            *expr.clone()
+        }
+        _ => todo!("promise executor that isn't a function expression"),
+    };
+    let resolve = FnSignature {
+        params: value_type.iter().cloned().collect(),
+        rest: None,
+        return_type: None,
+        is_async: false,
+        is_generator: false,
+    };
+    let reject = FnSignature {
+        params: vec![ValueType::String],
+        rest: None,
+        return_type: None,
+        is_async: false,
+        is_generator: false,
+    };
+    let executor = transpile_runtime_callback(
+        ctx,
+        executor,
+        &[
+            ValueType::Function(Box::new(resolve)),
+            ValueType::Function(Box::new(reject)),
+        ],
+        None,
+        false,
+    );
+    let constructor = match value_type {
+        Some(_) => "new",
+        None => "new_void",
+    };
+
+    generate_path_call(&["Promise", constructor], vec![executor])
+}
+
+/// Transpiles a promise method like `then`, or a static `Promise` function like `Promise.all`.
+///
+/// `then` becomes `and_then` if its callback returns a promise, which the result is flattened into. Static functions
+/// are called on `Promise::<T>` if the `value_type` of the promise is known from elsewhere.
+fn transpile_promise_call(
+    ctx: &mut Context,
+    call: swc::CallExpr,
+    value_type: Option<&ValueType>,
+) -> Expr {
+    let expected_type = value_type;
+    let (name, value_type, is_static) = match promise_call(ctx, &call) {
+        Some(PromiseCall::Method(name, value_type)) => (name.to_string(), value_type, false),
+        Some(PromiseCall::Static(name)) => (name.to_string(), None, true),
+        None => unreachable!("Call is a promise call."),
+    };
+    let member = call
+        .callee
+        .expr()
+        .expect("Callee is Expr.")
+        .member()
+        .expect("Expr is Member.");
+    let mut args = call.args.into_iter().map(|arg| {
+        if arg.spread.is_some() {
+            todo!("spread into promise call")
+        }
+
+        *arg.expr
+    });
+
+    if is_static {
+        let arg = match (name.as_str(), args.next()) {
+            ("resolve", None) => parse_quote!(()),
+            ("reject", Some(reason)) => transpile_expr(ctx, reason),
+            (_, Some(arg)) => {
+                let arg_type = infer_type(ctx, &arg);
+
+                transpile_owned_value(ctx, arg, &arg_type)
+            }
+            (_, None) => todo!("promise function without arguments"),
+        };
+        let function = Ident::new(
+            match name.as_str() {
+                "allSettled" => "all_settled",
+                name => name,
+            },
+            dummy_span(),
+        );
+
+        return match expected_type {
+            Some(value_type) => {
+                let value_type = types::owned_type(value_type);
+
+                parse_quote! { Promise::<#value_type>::#function(#arg) }
+            }
+            None => parse_quote! { Promise::#function(#arg) },
+        };
+    }
+
+    let receiver = transpile_expr(ctx, *member.obj);
+    let callback = args.next().expect("Promise method has a callback.");
+
+    if args.next().is_some() {
+        todo!("promise method with a rejection handler")
+    }
+
+    let (method, param_types, return_type) = match name.as_str() {
+        "then" => {
+            let return_type = promise_callback_type(ctx, &callback, &value_type);
+            let method = match return_type {
+                Some(ValueType::Promise(_)) => "and_then",
+                _ => "then",
+            };
+
+            (
+                method,
+                vec![value_type.unwrap_or(ValueType::Unknown)],
+                return_type,
+            )
+        }
+        "catch" => ("catch", vec![ValueType::String], value_type),
+        _ => ("finally", vec![], None),
+    };
+    let callback = transpile_runtime_callback(ctx, callback, &param_types, return_type, true);
+
+    generate_method_call(receiver, method, vec![callback])
+}
+
+/// Transpiles a callback that ts-std calls, like the callbacks of promises and timers, to a closure that takes
+/// exactly one parameter per type in `param_types`, since ts-std calls it with all of them.
+///
+/// The parameters are owned and their types are left to inference, and callbacks that run later move their
+/// captures.
+fn transpile_runtime_callback(
+    ctx: &mut Context,
+    callback: swc::Expr,
+    param_types: &[ValueType],
+    return_type: Option<ValueType>,
+    move_captures: bool,
+) -> Expr {
+    let param_count = match function_expr_parts(&callback) {
+        Some((params, _, _)) => params.len(),
+        None => return transpile_expr(ctx, callback),
+    };
+
+    if param_count > param_types.len() {
+        todo!("runtime callback with more parameters than it is passed")
+    } else if move_captures
+        && captured_assignments(&callback)
+            .iter()
+            .any(|ident| !ctx.is_shared(ident))
+    {
+        // The callback would change its own copy of the binding.
+        todo!("runtime callback that assigns to captured bindings that aren't shared")
+    }
+
+    // Callbacks that run later move clones of the `Shared` cells they capture, so the code around them keeps its own.
+    let mut shared: Vec<String> = find_idents(&callback)
+        .into_iter()
+        .filter(|ident| move_captures && ctx.is_shared(ident))
+        .collect();
+
+    shared.sort();
+
+    let (mut pats, body) = transpile_function_expr(ctx, callback, param_types, return_type, true);
+
+    pats.resize(param_types.len(), parse_quote!(_));
+
+    let closure = generate_closure(pats, body, move_captures);
+
+    if shared.is_empty() {
+        return closure;
+    }
+
+    let clones: Vec<Stmt> = shared
+        .iter()
+        .map(|ident| {
+            let ident = Ident::new(ident, dummy_span());
+
+            parse_quote! { let #ident = #ident.clone(); }
+        })
+        .collect();
+
+    parse_quote! {
+        {
+            #(#clones)*
+            #closure
+        }
+    }
+}
+
+/// Whether a call is of a global timer function, e.g. `setTimeout`, with the name of its ts-std function.
+fn timer_function(ctx: &Context, call: &swc::CallExpr) -> Option<&'static str> {
+    let name = call.callee.as_expr()?.as_ident()?.sym.as_str();
+
+    if ctx.lookup(name).is_some() || ctx.function(name).is_some() {
+        return None;
+    }
+
+    match name {
+        "setTimeout" => Some("set_timeout"),
+        "setInterval" => Some("set_interval"),
+        "clearTimeout" => Some("clear_timeout"),
+        "clearInterval" => Some("clear_interval"),
+        "queueMicrotask" => Some("queue_microtask"),
+        _ => None,
+    }
+}
+
+/// Transpiles a call of a timer function to its ts-std function on the event loop, e.g. `set_timeout(callback, 10.0)`.
+fn transpile_timer_call(ctx: &mut Context, function: &str, args: Vec<swc::ExprOrSpread>) -> Expr {
+    if args.iter().any(|arg| arg.spread.is_some()) {
+        todo!("spread into timer function")
+    }
+
+    let mut args = args.into_iter().map(|arg| *arg.expr);
+
+    let args = match function {
+        "clear_timeout" | "clear_interval" => {
+            let timer = args.next().expect("Timer is cleared.");
+
+            vec![transpile_expr(ctx, timer)]
+        }
+        _ => {
+            let callback = args.next().expect("Timer function has a callback.");
+            let callback = transpile_timer_callback(ctx, callback);
+
+            match function {
+                "queue_microtask" => vec![callback],
+                _ => {
+                    let delay = match args.next() {
+                        Some(delay) => transpile_number(ctx, delay, NumberType::F64),
+                        None => transpile_number_lit(0.0, NumberType::F64),
+                    };
+
+                    if args.next().is_some() {
+                        todo!("timer arguments")
+                    }
+
+                    vec![callback, delay]
+                }
+            }
+        }
+    };
+
+    generate_path_call(&[function], args)
+}
+
+/// Transpiles the callback of a timer, which is moved to the event loop.
+///
+/// Shared `Rc<dyn Fn>`s, like the `resolve` of a promise, are called from a closure with their own clone.
+fn transpile_timer_callback(ctx: &mut Context, callback: swc::Expr) -> Expr {
+    match callback {
+        swc::Expr::Ident(ident)
+            if matches!(
+                infer_type(ctx, &swc::Expr::Ident(ident.clone())),
+                ValueType::Function(_)
+            ) && !ctx.is_closure(ident.sym.as_str())
+                && ctx.function(ident.sym.as_str()).is_none() =>
+        {
+            let ident = transpile_ident(ident);
+
+            parse_quote! {
+                {
+                    let #ident = #ident.clone();
+                    move || #ident()
+                }
+            }
+        }
+        callback => transpile_runtime_callback(ctx, callback, &[], None, true),
     }
 }
@@ -0,0 +1,979 @@
+use std::collections::{HashMap, HashSet};
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use swc_ecma_ast as swc;
+use syn::{
+    punctuated::Punctuated,
+    visit_mut::{self, VisitMut},
+    *,
+};
+
+use crate::{
+    analysis::{contains_yield, find_idents},
+    context::Context,
+    decl::{declare_functions, pascal_case, transpile_var_declarator},
+    expr::{generate_method_call, static_condition, transpile_condition, transpile_owned_value},
+    stmt::{for_of_binding, transpile_expr_stmt, transpile_stmt_to_stmts, transpile_stmts},
+    types::{infer_type, owned_type, FnSignature, ValueType},
+    util::dummy_span,
+};
+
+/// The state of a generator that is done, while it starts in state 1.
+const DONE: usize = 0;
+
+/// Transpiles the body of a generator function to a struct implementing `Iterator` as a state machine, and returns
+/// the statements of the function, which declare the struct and return it as a `Box<dyn Generator>`.
+///
+/// The body is split into states at each `yield`, and `next` runs the states until the next `yield`. The parameters
+/// and the bindings that are declared between `yield`s are fields of the struct, so they live across them, and the
+/// branches and loops containing `yield`s jump between states.
+pub fn transpile_generator(
+    ctx: &mut Context,
+    name: &str,
+    params: &[String],
+    body: swc::BlockStmt,
+    signature: &FnSignature,
+) -> Vec<Stmt> {
+    let (yield_type, return_type) = match &signature.return_type {
+        Some(ValueType::Generator(yield_type, return_type)) => {
+            ((**yield_type).clone(), return_type.as_deref().cloned())
+        }
+        _ => todo!("generator without a Generator return type"),
+    };
+    let mut machine = StateMachine::new(&find_idents(&body), yield_type, return_type);
+
+    for param in params {
+        let value_type = ctx.lookup(param).cloned().expect("Parameter is declared.");
+        let ident = Ident::new(param, dummy_span());
+
+        machine.declare_field(param, value_type, parse_quote!(#ident));
+    }
+
+    declare_functions(ctx, &body.stmts);
+    machine.compile_stmts(ctx, body.stmts);
+    machine.finish();
+
+    machine.generate(name)
+}
+
+/// Transpiles the body of an async generator function to the `AsyncGenerator` it returns, whose body is an async
+/// block that yields through a `Yielder`, e.g. `AsyncGenerator::new(move |yielder| async move { .. })`.
+///
+/// Unlike generators, the body doesn't need to be split into states, since the async block is suspended at each
+/// `yield` like at an `await`.
+pub fn transpile_async_generator(
+    ctx: &mut Context,
+    body: swc::BlockStmt,
+    signature: &FnSignature,
+) -> Stmt {
+    let yield_type = match &signature.return_type {
+        Some(ValueType::AsyncGenerator(yield_type, _)) => (**yield_type).clone(),
+        _ => todo!("async generator without an AsyncGenerator return type"),
+    };
+    let yielder = unique_name(&find_idents(&body), "yielder");
+    let stmts = ctx.with_yielder(&yielder, yield_type, |ctx| {
+        declare_functions(ctx, &body.stmts);
+        transpile_stmts(ctx, body.stmts)
+    });
+    let yielder = Ident::new(&yielder, dummy_span());
+
+    Stmt::Expr(
+        parse_quote! {
+            AsyncGenerator::new(move |#yielder| async move { #(#stmts)* })
+        },
+        None,
+    )
+}
+
+/// A name starting with `name` that doesn't clash with `idents`, or other names starting with it.
+pub fn unique_name(idents: &HashSet<String>, name: &str) -> String {
+    let mut name = name.to_string();
+
+    while idents.iter().any(|ident| ident.starts_with(&name)) {
+        name.push('_');
+    }
+
+    name
+}
+
+/// Where `break` and `continue` jump to in a loop containing `yield`s.
+#[derive(Clone, Copy)]
+struct LoopStates {
+    break_state: usize,
+    continue_state: usize,
+}
+
+struct StateMachine {
+    /// The statements of each state, which end by jumping to another state or returning from `next`.
+    states: Vec<Vec<Stmt>>,
+    current: usize,
+    /// Fields for the bindings and iterators that live across `yield`s, with their types and initial values.
+    fields: Vec<(Ident, Type, Expr)>,
+    /// The bindings that are fields, which the transpiled code accesses through `self`.
+    bindings: HashMap<String, ValueType>,
+    /// The loops containing `yield`s around the current state, innermost last.
+    loops: Vec<LoopStates>,
+    state_field: Ident,
+    returned_field: Ident,
+    iterator_prefix: String,
+    yield_type: ValueType,
+    return_type: Option<ValueType>,
+}
+
+impl StateMachine {
+    /// Creates the state machine of a generator, whose own fields are named so they don't clash with `idents`.
+    fn new(
+        idents: &HashSet<String>,
+        yield_type: ValueType,
+        return_type: Option<ValueType>,
+    ) -> Self {
+        let unique_name = |name: &str| unique_name(idents, name);
+
+        StateMachine {
+            states: vec![vec![], vec![]],
+            current: 1,
+            fields: vec![],
+            bindings: HashMap::new(),
+            loops: vec![],
+            state_field: Ident::new(&unique_name("state"), dummy_span()),
+            returned_field: Ident::new(&unique_name("returned"), dummy_span()),
+            iterator_prefix: unique_name("iterator"),
+            yield_type,
+            return_type,
+        }
+    }
+
+    fn new_state(&mut self) -> usize {
+        self.states.push(vec![]);
+        self.states.len() - 1
+    }
+
+    fn push(&mut self, stmt: Stmt) {
+        self.states[self.current].push(stmt);
+    }
+
+    /// Pushes transpiled statements to the current state, see `StateRewriter`.
+    fn emit(&mut self, stmts: Vec<Stmt>) {
+        for stmt in self.rewrite_stmts(stmts) {
+            self.push(stmt);
+        }
+    }
+
+    fn rewrite_stmts(&self, stmts: Vec<Stmt>) -> Vec<Stmt> {
+        let mut block = Block {
+            brace_token: token::Brace(dummy_span()),
+            stmts,
+        };
+
+        self.rewriter().visit_block_mut(&mut block);
+
+        block.stmts
+    }
+
+    fn rewrite_expr(&self, mut expr: Expr) -> Expr {
+        self.rewriter().visit_expr_mut(&mut expr);
+
+        expr
+    }
+
+    fn rewriter(&self) -> StateRewriter<'_> {
+        StateRewriter {
+            bindings: &self.bindings,
+            state_field: &self.state_field,
+            returned_field: self.return_type.is_some().then_some(&self.returned_field),
+            loop_states: self.loops.last().copied(),
+            shadowed: vec![],
+            loop_depth: 0,
+            closure_depth: 0,
+        }
+    }
+
+    /// Ends the current state by continuing with `state`.
+    fn jump(&mut self, state: usize) {
+        for stmt in generate_jump(&self.state_field, state) {
+            self.push(stmt);
+        }
+    }
+
+    /// Ends the current state by continuing with `then` if `cond` holds, and with `otherwise` if it doesn't.
+    fn branch(&mut self, cond: Expr, then: usize, otherwise: usize) {
+        let state_field = &self.state_field;
+        let then = generate_state_lit(then);
+        let otherwise = generate_state_lit(otherwise);
+
+        self.push(parse_quote! { self.#state_field = if #cond { #then } else { #otherwise }; });
+        self.push(parse_quote! { continue; });
+    }
+
+    /// Declares a field, which bindings of the same name and type share, e.g. the counters of two loops.
+    fn declare_field(&mut self, name: &str, value_type: ValueType, init: Expr) {
+        match self.bindings.get(name) {
+            Some(field_type) if *field_type == value_type => {}
+            Some(_) => todo!("generator bindings with the same name and different types"),
+            None => {
+                self.fields.push((
+                    Ident::new(name, dummy_span()),
+                    owned_type(&value_type),
+                    init,
+                ));
+                self.bindings.insert(name.to_string(), value_type);
+            }
+        }
+    }
+
+    /// Declares the field of a binding, which holds its default value until the binding is declared.
+    fn declare_binding(&mut self, name: &str, value_type: ValueType) {
+        if matches!(
+            value_type,
+            ValueType::Function(_)
+                | ValueType::Promise(_)
+                | ValueType::Generator(..)
+                | ValueType::Unknown
+        ) {
+            todo!("generator binding without a default value")
+        }
+
+        self.declare_field(name, value_type, parse_quote!(Default::default()));
+    }
+
+    /// Declares a field for an iterator that is delegated to or looped over, e.g. `iterator0`.
+    fn declare_iterator(&mut self, yield_type: ValueType, return_type: Option<ValueType>) -> Ident {
+        let name = format!(
+            "{}{}",
+            self.iterator_prefix,
+            self.fields
+                .iter()
+                .filter(|(ident, ..)| ident.to_string().starts_with(&self.iterator_prefix))
+                .count()
+        );
+        let iterator_type = owned_type(&ValueType::Generator(
+            Box::new(yield_type),
+            return_type.map(Box::new),
+        ));
+
+        self.fields.push((
+            Ident::new(&name, dummy_span()),
+            parse_quote!(Option<#iterator_type>),
+            parse_quote!(None),
+        ));
+
+        Ident::new(&name, dummy_span())
+    }
+
+    fn compile_stmts(&mut self, ctx: &mut Context, stmts: Vec<swc::Stmt>) {
+        for stmt in stmts {
+            self.compile_stmt(ctx, stmt);
+        }
+    }
+
+    fn compile_stmt(&mut self, ctx: &mut Context, stmt: swc::Stmt) {
+        match stmt {
+            swc::Stmt::Decl(swc::Decl::Var(var)) => self.compile_var(ctx, *var),
+            stmt if !contains_yield(&stmt) => {
+                // Statements after leaving the state are unreachable, so they start a new state.
+                let diverges = matches!(
+                    stmt,
+                    swc::Stmt::Return(_) | swc::Stmt::Break(_) | swc::Stmt::Continue(_)
+                );
+                let stmts = transpile_stmt_to_stmts(ctx, stmt);
+
+                self.emit(stmts);
+
+                if diverges {
+                    self.current = self.new_state();
+                }
+            }
+            swc::Stmt::Expr(expr_stmt) => self.compile_yield(ctx, *expr_stmt.expr),
+            swc::Stmt::Block(block) => ctx.with_scope(|ctx| {
+                declare_functions(ctx, &block.stmts);
+                self.compile_stmts(ctx, block.stmts);
+            }),
+            swc::Stmt::If(if_stmt) => self.compile_if(ctx, if_stmt),
+            swc::Stmt::While(while_stmt) => self.compile_while(ctx, while_stmt),
+            swc::Stmt::DoWhile(do_while) => self.compile_do_while(ctx, do_while),
+            swc::Stmt::For(for_stmt) => ctx.with_scope(|ctx| self.compile_for(ctx, for_stmt)),
+            swc::Stmt::ForOf(for_of) => ctx.with_scope(|ctx| self.compile_for_of(ctx, for_of)),
+            // These aren't transpiled outside of generators either, see `transpile_stmt`.
+            swc::Stmt::Try(_) => todo!("yield in try"),
+            swc::Stmt::Switch(_) => todo!("yield in switch"),
+            swc::Stmt::Labeled(_) => todo!("yield in labeled statement"),
+            _ => todo!("yield in statement"),
+        }
+    }
+
+    /// Compiles a variable declaration, whose bindings are fields.
+    fn compile_var(&mut self, ctx: &mut Context, var: swc::VarDecl) {
+        for declarator in var.decls.clone() {
+            let name = match &declarator.name {
+                swc::Pat::Ident(ident) => ident.id.sym.to_string(),
+                _ => todo!("generator var declarator non-ident"),
+            };
+
+            match declarator.init.map(|init| *init) {
+                Some(swc::Expr::Yield(swc::YieldExpr {
+                    delegate: true,
+                    arg: Some(arg),
+                    ..
+                })) => self.compile_delegate(ctx, *arg, Some(&name)),
+                Some(init) if contains_yield(&init) => todo!("yield inside an expression"),
+                init => {
+                    let declarator = swc::VarDeclarator {
+                        init: init.map(Box::new),
+                        ..declarator
+                    };
+                    let init = match transpile_var_declarator(ctx, &var, declarator) {
+                        Stmt::Local(local) => *local.init.expect("Local has an init.").expr,
+                        _ => unreachable!("Var declarator is transpiled to a Local."),
+                    };
+                    let value_type = ctx.lookup(&name).cloned().expect("Binding is declared.");
+
+                    if ctx.is_closure(&name) {
+                        todo!("closure in generator")
+                    }
+
+                    // Fields own their strings.
+                    let init = if value_type == ValueType::String && !ctx.is_owned_string(&name) {
+                        ctx.declare_string(&name, true);
+
+                        generate_method_call(init, "to_string", vec![])
+                    } else {
+                        init
+                    };
+                    let ident = Ident::new(&name, dummy_span());
+
+                    self.declare_binding(&name, value_type);
+                    self.emit(vec![parse_quote! { #ident = #init; }]);
+                }
+            }
+        }
+    }
+
+    /// Compiles an expression statement containing `yield`, which is `yield value`, `yield* iterable` or an
+    /// assignment of the return value of `yield*`.
+    fn compile_yield(&mut self, ctx: &mut Context, expr: swc::Expr) {
+        match expr {
+            swc::Expr::Paren(paren) => self.compile_yield(ctx, *paren.expr),
+            swc::Expr::Yield(swc::YieldExpr {
+                delegate: true,
+                arg: Some(arg),
+                ..
+            }) => self.compile_delegate(ctx, *arg, None),
+            swc::Expr::Yield(swc::YieldExpr { arg: Some(arg), .. }) => {
+                let value = transpile_owned_value(ctx, *arg, &self.yield_type.clone());
+                let value = self.rewrite_expr(value);
+                let state_field = self.state_field.clone();
+                let next = self.new_state();
+                let next_lit = generate_state_lit(next);
+
+                self.push(parse_quote! { self.#state_field = #next_lit; });
+                self.push(parse_quote! { return Some(#value); });
+                self.current = next;
+            }
+            swc::Expr::Yield(_) => todo!("yield without a value"),
+            swc::Expr::Assign(swc::AssignExpr {
+                op: swc::AssignOp::Assign,
+                left,
+                right,
+                ..
+            }) => match (left.as_ident(), *right) {
+                (
+                    Some(ident),
+                    swc::Expr::Yield(swc::YieldExpr {
+                        delegate: true,
+                        arg: Some(arg),
+                        ..
+                    }),
+                ) => {
+                    let name = ident.sym.to_string();
+
+                    self.compile_delegate(ctx, *arg, Some(&name));
+                }
+                _ => todo!("yield inside an expression"),
+            },
+            _ => todo!("yield inside an expression"),
+        }
+    }
+
+    /// Compiles `yield* iterable`, which yields the values of the iterable, and assigns the value that it returns to
+    /// `target`, declaring the binding if it isn't a field yet.
+    fn compile_delegate(&mut self, ctx: &mut Context, iterable: swc::Expr, target: Option<&str>) {
+        let (iterator, yield_type, return_type) = self.transpile_iterator(ctx, iterable);
+        let iterator_field = self.declare_iterator(yield_type, return_type.clone());
+
+        self.push(parse_quote! { self.#iterator_field = Some(#iterator); });
+
+        let delegating = self.new_state();
+
+        self.jump(delegating);
+        self.current = delegating;
+        self.push(parse_quote! {
+            if let Some(value) = self.#iterator_field.as_mut().expect("Generator is delegating.").next() {
+                return Some(value);
+            }
+        });
+
+        match target {
+            Some(name) => {
+                let value_type =
+                    return_type.unwrap_or_else(|| todo!("value of yield* without a return value"));
+
+                if !self.bindings.contains_key(name) {
+                    ctx.declare_string(name, value_type == ValueType::String);
+                    ctx.declare_closure(name, false);
+                    ctx.declare_array_length(name, None);
+                    ctx.declare(name, value_type.clone());
+                    self.declare_binding(name, value_type);
+                }
+
+                let ident = Ident::new(name, dummy_span());
+
+                self.push(parse_quote! {
+                    self.#ident = self.#iterator_field.take().expect("Generator is delegating.").take_return();
+                });
+            }
+            None => self.push(parse_quote! { self.#iterator_field = None; }),
+        }
+    }
+
+    /// Transpiles an iterable that a generator iterates over to a `Box<dyn Generator>`, with the types it yields and
+    /// returns.
+    fn transpile_iterator(
+        &self,
+        ctx: &mut Context,
+        iterable: swc::Expr,
+    ) -> (Expr, ValueType, Option<ValueType>) {
+        match infer_type(ctx, &iterable) {
+            value_type @ ValueType::Generator(..) => {
+                let iterator = transpile_owned_value(ctx, iterable, &value_type);
+                let ValueType::Generator(yield_type, return_type) = value_type else {
+                    unreachable!("Iterable is a Generator.")
+                };
+
+                (
+                    self.rewrite_expr(iterator),
+                    *yield_type,
+                    return_type.map(|return_type| *return_type),
+                )
+            }
+            value_type @ ValueType::Array(_) => {
+                let array = transpile_owned_value(ctx, iterable, &value_type);
+                let ValueType::Array(element_type) = value_type else {
+                    unreachable!("Iterable is an Array.")
+                };
+                let array = self.rewrite_expr(array);
+
+                (
+                    parse_quote!(Box::new(#array.into_iter())),
+                    *element_type,
+                    None,
+                )
+            }
+            ValueType::String => todo!("generator iterating a string"),
+            _ => todo!("generator iterating a value of unknown type"),
+        }
+    }
+
+    fn compile_if(&mut self, ctx: &mut Context, if_stmt: swc::IfStmt) {
+        match static_condition(ctx, &if_stmt.test) {
+            Some(true) => return self.compile_stmt(ctx, *if_stmt.cons),
+            Some(false) => {
+                if let Some(alt) = if_stmt.alt {
+                    self.compile_stmt(ctx, *alt);
+                }
+
+                return;
+            }
+            None => {}
+        }
+
+        let test = transpile_condition(ctx, *if_stmt.test);
+        let test = self.rewrite_expr(test);
+        let then = self.new_state();
+        let otherwise = if_stmt.alt.as_ref().map(|_| self.new_state());
+        let end = self.new_state();
+
+        self.branch(test, then, otherwise.unwrap_or(end));
+        self.current = then;
+        ctx.with_scope(|ctx| self.compile_stmt(ctx, *if_stmt.cons));
+        self.jump(end);
+
+        if let (Some(otherwise), Some(alt)) = (otherwise, if_stmt.alt) {
+            self.current = otherwise;
+            ctx.with_scope(|ctx| self.compile_stmt(ctx, *alt));
+            self.jump(end);
+        }
+
+        self.current = end;
+    }
+
+    fn compile_while(&mut self, ctx: &mut Context, while_stmt: swc::WhileStmt) {
+        let test = self.new_state();
+        let body = self.new_state();
+        let end = self.new_state();
+
+        self.jump(test);
+        self.current = test;
+        self.compile_test(ctx, Some(*while_stmt.test), body, end);
+        self.compile_loop_body(ctx, *while_stmt.body, body, end, test);
+        self.current = end;
+    }
+
+    fn compile_do_while(&mut self, ctx: &mut Context, do_while: swc::DoWhileStmt) {
+        let body = self.new_state();
+        let test = self.new_state();
+        let end = self.new_state();
+
+        self.jump(body);
+        self.compile_loop_body(ctx, *do_while.body, body, end, test);
+        self.current = test;
+        self.compile_test(ctx, Some(*do_while.test), body, end);
+        self.current = end;
+    }
+
+    fn compile_for(&mut self, ctx: &mut Context, for_stmt: swc::ForStmt) {
+        match for_stmt.init {
+            Some(swc::VarDeclOrExpr::VarDecl(var)) => self.compile_var(ctx, *var),
+            Some(swc::VarDeclOrExpr::Expr(expr)) => {
+                let stmts = transpile_expr_stmt(ctx, *expr);
+
+                self.emit(stmts);
+            }
+            None => {}
+        }
+
+        let test = self.new_state();
+        let body = self.new_state();
+        let update = self.new_state();
+        let end = self.new_state();
+
+        self.jump(test);
+        self.current = test;
+        self.compile_test(ctx, for_stmt.test.map(|test| *test), body, end);
+        self.compile_loop_body(ctx, *for_stmt.body, body, end, update);
+        self.current = update;
+
+        if let Some(update) = for_stmt.update {
+            let stmts = transpile_expr_stmt(ctx, *update);
+
+            self.emit(stmts);
+        }
+
+        self.jump(test);
+        self.current = end;
+    }
+
+    /// Compiles `for...of`, which keeps the iterator in a field and assigns each value to the field of its binding.
+    fn compile_for_of(&mut self, ctx: &mut Context, for_of: swc::ForOfStmt) {
+        if for_of.is_await {
+            todo!("for await of")
+        }
+
+        let (name, _) = for_of_binding(&for_of.left);
+        let (iterator, element_type, return_type) = self.transpile_iterator(ctx, *for_of.right);
+        let iterator_field = self.declare_iterator(element_type.clone(), return_type);
+
+        self.push(parse_quote! { self.#iterator_field = Some(#iterator); });
+
+        let next = self.new_state();
+        let body = self.new_state();
+        let end = self.new_state();
+
+        ctx.declare_string(&name, element_type == ValueType::String);
+        ctx.declare_closure(&name, false);
+        ctx.declare_array_length(&name, None);
+        ctx.declare(&name, element_type.clone());
+        self.declare_binding(&name, element_type);
+
+        let ident = Ident::new(&name, dummy_span());
+        let state_field = self.state_field.clone();
+        let body_lit = generate_state_lit(body);
+        let end_lit = generate_state_lit(end);
+
+        self.jump(next);
+        self.current = next;
+        self.push(parse_quote! {
+            match self.#iterator_field.as_mut().expect("Loop is iterating.").next() {
+                Some(value) => {
+                    self.#ident = value;
+                    self.#state_field = #body_lit;
+                }
+                None => self.#state_field = #end_lit,
+            }
+        });
+        self.push(parse_quote! { continue; });
+        self.compile_loop_body(ctx, *for_of.body, body, end, next);
+        self.current = end;
+        self.push(parse_quote! { self.#iterator_field = None; });
+    }
+
+    /// Compiles the test of a loop in the current state, which continues with `body` or `end`.
+    fn compile_test(
+        &mut self,
+        ctx: &mut Context,
+        test: Option<swc::Expr>,
+        body: usize,
+        end: usize,
+    ) {
+        match test {
+            Some(swc::Expr::Lit(swc::Lit::Bool(test))) if test.value => self.jump(body),
+            Some(test) => match static_condition(ctx, &test) {
+                Some(true) => self.jump(body),
+                Some(false) => self.jump(end),
+                None => {
+                    let test = transpile_condition(ctx, test);
+                    let test = self.rewrite_expr(test);
+
+                    self.branch(test, body, end);
+                }
+            },
+            None => self.jump(body),
+        }
+    }
+
+    /// Compiles the body of a loop starting in the state `body`, where `break` continues with `break_state` and
+    /// `continue` and the end of the body with `continue_state`.
+    fn compile_loop_body(
+        &mut self,
+        ctx: &mut Context,
+        stmt: swc::Stmt,
+        body: usize,
+        break_state: usize,
+        continue_state: usize,
+    ) {
+        self.current = body;
+        self.loops.push(LoopStates {
+            break_state,
+            continue_state,
+        });
+        ctx.with_scope(|ctx| self.compile_stmt(ctx, stmt));
+        self.loops.pop();
+        self.jump(continue_state);
+    }
+
+    /// Ends the last state, where the generator returns without a value.
+    fn finish(&mut self) {
+        let returned_field = &self.returned_field;
+
+        if let Some(ValueType::Option(_)) = self.return_type {
+            self.push(parse_quote! { self.#returned_field = Some(None); });
+        }
+
+        for stmt in generate_done(&self.state_field) {
+            self.push(stmt);
+        }
+    }
+
+    /// Generates the struct of the generator, its `Iterator` and `Generator` implementations, and the boxed struct
+    /// that the generator function returns.
+    fn generate(self, name: &str) -> Vec<Stmt> {
+        let struct_name = Ident::new(&format!("{}Generator", pascal_case(name)), dummy_span());
+        let state_field = &self.state_field;
+        let returned_field = &self.returned_field;
+        let yield_type = owned_type(&self.yield_type);
+        let return_type: Type = match &self.return_type {
+            Some(return_type) => owned_type(return_type),
+            None => parse_quote!(()),
+        };
+        let field_idents: Vec<&Ident> = self.fields.iter().map(|(ident, ..)| ident).collect();
+        let field_types = self.fields.iter().map(|(_, field_type, _)| field_type);
+        let field_values = self.fields.iter().map(|(ident, _, init)| match init {
+            Expr::Path(path) if path.path.is_ident(ident) => quote!(#ident),
+            init => quote!(#ident: #init),
+        });
+        let arms = self
+            .states
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(state, stmts)| {
+                let state = generate_state_lit(state);
+
+                quote! { #state => { #(#stmts)* } }
+            });
+        let (returned_decl, returned_value, take_return): (TokenStream, TokenStream, ImplItemFn) =
+            match self.return_type {
+                Some(_) => (
+                    quote! { #returned_field: Option<#return_type>, },
+                    quote! { #returned_field: None, },
+                    parse_quote! {
+                        fn take_return(&mut self) -> #return_type {
+                            self.#returned_field.take().expect("Generator returned a value.")
+                        }
+                    },
+                ),
+                None => (
+                    quote! {},
+                    quote! {},
+                    parse_quote! { fn take_return(&mut self) {} },
+                ),
+            };
+
+        vec![
+            parse_quote! {
+                struct #struct_name {
+                    #state_field: usize,
+                    #(#field_idents: #field_types,)*
+                    #returned_decl
+                }
+            },
+            parse_quote! {
+                impl Iterator for #struct_name {
+                    type Item = #yield_type;
+
+                    fn next(&mut self) -> Option<#yield_type> {
+                        loop {
+                            match self.#state_field {
+                                #(#arms)*
+                                _ => return None,
+                            }
+                        }
+                    }
+                }
+            },
+            parse_quote! {
+                impl Generator for #struct_name {
+                    type Return = #return_type;
+
+                    #take_return
+                }
+            },
+            Stmt::Expr(
+                parse_quote! {
+                    Box::new(#struct_name {
+                        #state_field: 1,
+                        #(#field_values,)*
+                        #returned_value
+                    })
+                },
+                None,
+            ),
+        ]
+    }
+}
+
+fn generate_state_lit(state: usize) -> LitInt {
+    LitInt::new(&state.to_string(), dummy_span())
+}
+
+/// Generates the statements ending `next` once the generator is done.
+fn generate_done(state_field: &Ident) -> Vec<Stmt> {
+    let done = generate_state_lit(DONE);
+
+    vec![
+        parse_quote! { self.#state_field = #done; },
+        parse_quote! { return None; },
+    ]
+}
+
+/// Rewrites transpiled statements to run in `next` of a generator, where bindings that are fields are accessed
+/// through `self`, and `return`, `break` and `continue` outside of closures and Rust loops jump to other states.
+struct StateRewriter<'a> {
+    bindings: &'a HashMap<String, ValueType>,
+    state_field: &'a Ident,
+    returned_field: Option<&'a Ident>,
+    loop_states: Option<LoopStates>,
+    /// Local bindings that shadow fields, e.g. closure parameters.
+    shadowed: Vec<String>,
+    loop_depth: usize,
+    closure_depth: usize,
+}
+
+impl StateRewriter<'_> {
+    fn is_field(&self, ident: &Ident) -> bool {
+        let name = ident.to_string();
+
+        self.bindings.contains_key(&name) && !self.shadowed.contains(&name)
+    }
+
+    /// The statements replacing `return`, `break` and `continue`, if they leave the state.
+    fn generate_exit(&mut self, expr: &mut Expr) -> Option<Vec<Stmt>> {
+        if self.closure_depth > 0 {
+            return None;
+        }
+
+        let state_field = self.state_field;
+
+        match expr {
+            Expr::Return(return_expr) => {
+                let mut stmts = vec![];
+
+                if let (Some(value), Some(returned_field)) =
+                    (&mut return_expr.expr, self.returned_field)
+                {
+                    self.visit_expr_mut(value);
+                    stmts.push(parse_quote! { self.#returned_field = Some(#value); });
+                }
+
+                stmts.extend(generate_done(state_field));
+
+                Some(stmts)
+            }
+            Expr::Break(ExprBreak { label: None, .. }) if self.loop_depth == 0 => {
+                let state = self.loop_states.expect("Break is in a loop.").break_state;
+
+                Some(generate_jump(state_field, state))
+            }
+            Expr::Continue(ExprContinue { label: None, .. }) if self.loop_depth == 0 => {
+                let state = self
+                    .loop_states
+                    .expect("Continue is in a loop.")
+                    .continue_state;
+
+                Some(generate_jump(state_field, state))
+            }
+            _ => None,
+        }
+    }
+
+    fn visit_loop_body_mut(&mut self, body: &mut Block) {
+        self.loop_depth += 1;
+        self.visit_block_mut(body);
+        self.loop_depth -= 1;
+    }
+}
+
+fn generate_jump(state_field: &Ident, state: usize) -> Vec<Stmt> {
+    let state = generate_state_lit(state);
+
+    vec![
+        parse_quote! { self.#state_field = #state; },
+        parse_quote! { continue; },
+    ]
+}
+
+impl VisitMut for StateRewriter<'_> {
+    fn visit_block_mut(&mut self, block: &mut Block) {
+        let shadowed = self.shadowed.len();
+        let mut stmts = vec![];
+
+        for mut stmt in block.stmts.drain(..) {
+            match &mut stmt {
+                Stmt::Local(local) => {
+                    if let Some(init) = &mut local.init {
+                        self.visit_local_init_mut(init);
+                    }
+
+                    self.shadowed.extend(pat_idents(&local.pat));
+                }
+                Stmt::Expr(expr, _) => {
+                    if let Some(exit) = self.generate_exit(expr) {
+                        stmts.extend(exit);
+                        continue;
+                    }
+
+                    self.visit_expr_mut(expr);
+                }
+                stmt => self.visit_stmt_mut(stmt),
+            }
+
+            stmts.push(stmt);
+        }
+
+        block.stmts = stmts;
+        self.shadowed.truncate(shadowed);
+    }
+
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        if let Some(exit) = self.generate_exit(expr) {
+            *expr = Expr::Block(ExprBlock {
+                attrs: vec![],
+                label: None,
+                block: Block {
+                    brace_token: token::Brace(dummy_span()),
+                    stmts: exit,
+                },
+            });
+            return;
+        }
+
+        match expr {
+            Expr::Path(path)
+                if path
+                    .path
+                    .get_ident()
+                    .is_some_and(|ident| self.is_field(ident)) =>
+            {
+                let ident = path.path.get_ident().expect("Path is Ident.").clone();
+
+                *expr = parse_quote!(self.#ident);
+            }
+            Expr::Call(call) => {
+                visit_mut::visit_expr_call_mut(self, call);
+
+                // Functions in fields are called as `(self.f)(x)`, since `self.f(x)` would call a method.
+                if let Expr::Field(field) = &*call.func {
+                    *call.func = parse_quote!((#field));
+                }
+            }
+            Expr::Closure(closure) => {
+                let shadowed = self.shadowed.len();
+
+                self.shadowed
+                    .extend(closure.inputs.iter().flat_map(pat_idents));
+                self.closure_depth += 1;
+                self.visit_expr_mut(&mut closure.body);
+                self.closure_depth -= 1;
+                self.shadowed.truncate(shadowed);
+            }
+            Expr::ForLoop(for_loop) => {
+                let shadowed = self.shadowed.len();
+
+                self.visit_expr_mut(&mut for_loop.expr);
+                self.shadowed.extend(pat_idents(&for_loop.pat));
+                self.visit_loop_body_mut(&mut for_loop.body);
+                self.shadowed.truncate(shadowed);
+            }
+            Expr::While(while_loop) => {
+                self.visit_expr_mut(&mut while_loop.cond);
+                self.visit_loop_body_mut(&mut while_loop.body);
+            }
+            Expr::Loop(loop_expr) => self.visit_loop_body_mut(&mut loop_expr.body),
+            expr => visit_mut::visit_expr_mut(self, expr),
+        }
+    }
+
+    // Macros like `format!` and `vec!` take expressions, which refer to fields like any other.
+    fn visit_macro_mut(&mut self, mac: &mut Macro) {
+        if let Ok(mut args) = mac.parse_body_with(Punctuated::<Expr, Token![,]>::parse_terminated) {
+            for arg in &mut args {
+                self.visit_expr_mut(arg);
+            }
+
+            mac.tokens = quote!(#args);
+        }
+    }
+
+    fn visit_arm_mut(&mut self, arm: &mut Arm) {
+        let shadowed = self.shadowed.len();
+
+        self.shadowed.extend(pat_idents(&arm.pat));
+        visit_mut::visit_arm_mut(self, arm);
+        self.shadowed.truncate(shadowed);
+    }
+
+    // Nested items can't refer to the fields.
+    fn visit_item_mut(&mut self, _: &mut Item) {}
+}
+
+/// The names of the bindings a pattern declares.
+fn pat_idents(pat: &Pat) -> Vec<String> {
+    let mut idents = PatIdents::default();
+
+    idents.visit_pat_mut(&mut pat.clone());
+
+    idents.names
+}
+
+#[derive(Default)]
+struct PatIdents {
+    names: Vec<String>,
+}
+
+impl VisitMut for PatIdents {
+    fn visit_pat_ident_mut(&mut self, pat_ident: &mut PatIdent) {
+        self.names.push(pat_ident.ident.to_string());
+
+        visit_mut::visit_pat_ident_mut(self, pat_ident);
+    }
+}
@@ -0,0 +1,603 @@
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    io,
+    path::{Path, PathBuf},
+};
+
+use swc_ecma_ast as swc;
+
+use crate::{
+    context::Context,
+    decl::fn_signature,
+    parse_typescript_file,
+    types::{annotation_type, infer_type, FnSignature, ValueType},
+};
+
+/// What a module exports under a name, with the type that importers need to transpile its uses.
+#[derive(Clone, Debug)]
+pub enum Export {
+    Function(FnSignature),
+    /// Functions with overload signatures, which are transpiled to a function per overload.
+    Overloaded,
+    /// Top-level constants, which are transpiled to `const` items.
+    Constant(ValueType),
+}
+
+/// A TypeScript module of a `ModuleGraph`, and the Rust module it is transpiled to.
+pub struct ModuleNode {
+    pub path: PathBuf,
+    /// Path of the Rust module from the crate root, e.g. `["shapes", "circle"]` for `shapes/circle.ts`, which is empty
+    /// for the entry module.
+    pub module_path: Vec<String>,
+    pub module: swc::Module,
+    /// The modules that the specifiers of imports and re-exports resolve to, by index.
+    dependencies: HashMap<String, usize>,
+}
+
+/// The modules reachable from an entry module through relative imports, which become the modules of a Rust crate
+/// whose root is the entry module.
+///
+/// Module paths follow the directories of the files relative to the root directory, where an `index.ts` is the
+/// module of its directory like in TypeScript.
+pub struct ModuleGraph {
+    root_dir: PathBuf,
+    /// The modules in the order they were found, starting with the crate root if there is a module for it.
+    pub modules: Vec<ModuleNode>,
+    pub crate_type: CrateType,
+}
+
+/// Whether the crate root of a `ModuleGraph` is a program, whose top-level code runs in `main`, or a library.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CrateType {
+    Binary,
+    Library,
+}
+
+impl ModuleGraph {
+    /// Parses the entry module and the modules it imports, following relative imports and re-exports. The entry
+    /// module is the crate root of a binary, and its directory is the root directory.
+    pub fn load(entry: &Path) -> Result<ModuleGraph, io::Error> {
+        let entry = entry.canonicalize()?;
+        let root_dir = entry
+            .parent()
+            .expect("Files are in a directory.")
+            .to_path_buf();
+        let mut graph = ModuleGraph {
+            root_dir,
+            modules: vec![],
+            crate_type: CrateType::Binary,
+        };
+
+        graph.add(entry, vec![])?;
+        graph.load_dependencies()?;
+
+        Ok(graph)
+    }
+
+    /// Parses the files of a project and the modules they import, with their module paths relative to the root
+    /// directory.
+    ///
+    /// The crate root is the `main.ts` or `index.ts` in the root directory, if there is one. It is a binary if it has
+    /// top-level code besides declarations, and otherwise the crate is a library.
+    pub fn load_project(root_dir: &Path, files: &[PathBuf]) -> Result<ModuleGraph, io::Error> {
+        let root_dir = root_dir.canonicalize()?;
+        let files = files
+            .iter()
+            .map(|file| file.canonicalize())
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut graph = ModuleGraph {
+            root_dir: root_dir.clone(),
+            modules: vec![],
+            crate_type: CrateType::Library,
+        };
+
+        if let Some(crate_root) = ["main.ts", "index.ts"]
+            .iter()
+            .map(|name| root_dir.join(name))
+            .find(|path| files.contains(path))
+        {
+            graph.add(crate_root, vec![])?;
+
+            if graph.modules[0]
+                .module
+                .body
+                .iter()
+                .any(|module_item| module_item.as_stmt().is_some_and(|stmt| !stmt.is_decl()))
+            {
+                graph.crate_type = CrateType::Binary;
+            }
+        }
+
+        for file in files {
+            if !graph.modules.iter().any(|node| node.path == file) {
+                let module_path = graph.module_path(&file)?;
+                graph.add(file, module_path)?;
+            }
+        }
+
+        graph.load_dependencies()?;
+
+        Ok(graph)
+    }
+
+    /// Adds the modules that the modules import or re-export from, until all of their dependencies are added.
+    fn load_dependencies(&mut self) -> Result<(), io::Error> {
+        let mut index = 0;
+
+        while index < self.modules.len() {
+            let dir = self.modules[index]
+                .path
+                .parent()
+                .expect("Files are in a directory.")
+                .to_path_buf();
+
+            for specifier in module_specifiers(&self.modules[index].module) {
+                let path = resolve_specifier(&dir, &specifier)?;
+                let dependency = match self.modules.iter().position(|node| node.path == path) {
+                    Some(dependency) => dependency,
+                    None => {
+                        let module_path = self.module_path(&path)?;
+                        self.add(path, module_path)?
+                    }
+                };
+
+                self.modules[index]
+                    .dependencies
+                    .insert(specifier, dependency);
+            }
+
+            index += 1;
+        }
+
+        Ok(())
+    }
+
+    fn add(&mut self, path: PathBuf, module_path: Vec<String>) -> Result<usize, io::Error> {
+        if let Some(node) = self
+            .modules
+            .iter()
+            .find(|node| node.module_path == module_path)
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "`{}` and `{}` are both transpiled to module `{}`.",
+                    node.path.display(),
+                    path.display(),
+                    module_path.join("::")
+                ),
+            ));
+        }
+
+        let module = parse_typescript_file(&path)?;
+
+        self.modules.push(ModuleNode {
+            path,
+            module_path,
+            module,
+            dependencies: HashMap::new(),
+        });
+
+        Ok(self.modules.len() - 1)
+    }
+
+    /// The path of the Rust module for a file, from its path relative to the root directory.
+    fn module_path(&self, path: &Path) -> Result<Vec<String>, io::Error> {
+        let relative = path.strip_prefix(&self.root_dir).map_err(|_| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "Module `{}` is outside of the directory of the entry module `{}`.",
+                    path.display(),
+                    self.root_dir.display()
+                ),
+            )
+        })?;
+        let mut module_path: Vec<String> = relative
+            .parent()
+            .into_iter()
+            .flat_map(Path::components)
+            .map(|component| module_name(&component.as_os_str().to_string_lossy()))
+            .collect();
+        let stem = relative
+            .file_stem()
+            .expect("Modules are files.")
+            .to_string_lossy();
+
+        if stem != "index" {
+            module_path.push(module_name(&stem));
+        }
+
+        if module_path.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "`{}` can't be imported, since it would be the crate root.",
+                    path.display()
+                ),
+            ));
+        }
+
+        Ok(module_path)
+    }
+
+    /// The module that a specifier of an import or re-export in a module resolves to.
+    pub fn dependency(&self, index: usize, specifier: &str) -> usize {
+        self.modules[index].dependencies[specifier]
+    }
+
+    /// The modules that the crate root imports, directly or through other modules, in the order their top-level code
+    /// runs. Like in JavaScript, the modules a module imports run before it in the order of its imports, and each
+    /// module runs once.
+    pub fn evaluation_order(&self) -> Vec<usize> {
+        let mut order = vec![];
+
+        self.visit_dependencies(0, &mut HashSet::new(), &mut order);
+        order.retain(|index| *index != 0);
+
+        order
+    }
+
+    fn visit_dependencies(
+        &self,
+        index: usize,
+        visited: &mut HashSet<usize>,
+        order: &mut Vec<usize>,
+    ) {
+        if !visited.insert(index) {
+            return;
+        }
+
+        for specifier in module_specifiers(&self.modules[index].module) {
+            self.visit_dependencies(self.dependency(index, &specifier), visited, order);
+        }
+
+        order.push(index);
+    }
+
+    /// The names of the modules directly in a module, which include directories without a module of their own.
+    pub fn child_modules(&self, module_path: &[String]) -> Vec<String> {
+        self.modules
+            .iter()
+            .filter(|node| {
+                node.module_path.len() > module_path.len()
+                    && node.module_path.starts_with(module_path)
+            })
+            .map(|node| node.module_path[module_path.len()].clone())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+
+    /// The paths of directories that contain modules but have no module of their own, e.g. `shapes` for
+    /// `shapes/circle.ts` without a `shapes.ts` or `shapes/index.ts`, which includes the crate root of a project
+    /// without a root module.
+    pub fn directory_modules(&self) -> Vec<Vec<String>> {
+        self.modules
+            .iter()
+            .flat_map(|node| {
+                (0..node.module_path.len()).map(|len| node.module_path[..len].to_vec())
+            })
+            .filter(|module_path| {
+                !self
+                    .modules
+                    .iter()
+                    .any(|node| &node.module_path == module_path)
+            })
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+
+    /// The names a module exports, including the names it re-exports from other modules.
+    pub fn exports(&self, index: usize) -> HashMap<String, Export> {
+        self.collect_exports(index, &mut HashSet::new())
+    }
+
+    fn collect_exports(
+        &self,
+        index: usize,
+        visiting: &mut HashSet<usize>,
+    ) -> HashMap<String, Export> {
+        // Modules that re-export each other only export the names they declare themselves to each other.
+        if !visiting.insert(index) {
+            return HashMap::new();
+        }
+
+        let locals = self.collect_locals(index, visiting);
+        let mut exports = HashMap::new();
+
+        for module_item in &self.modules[index].module.body {
+            let swc::ModuleItem::ModuleDecl(module_decl) = module_item else {
+                continue;
+            };
+
+            match module_decl {
+                swc::ModuleDecl::ExportDecl(export_decl) => {
+                    extend_exports(&mut exports, decl_exports(&export_decl.decl))
+                }
+                swc::ModuleDecl::ExportDefaultDecl(swc::ExportDefaultDecl {
+                    decl: swc::DefaultDecl::Fn(fn_expr),
+                    ..
+                }) => {
+                    exports.insert(
+                        "default".to_string(),
+                        Export::Function(fn_signature(&fn_expr.function)),
+                    );
+                }
+                swc::ModuleDecl::ExportDefaultExpr(export_default) => {
+                    if let Some(export) = export_default
+                        .expr
+                        .as_ident()
+                        .and_then(|ident| locals.get(ident.sym.as_str()))
+                    {
+                        exports.insert("default".to_string(), export.clone());
+                    }
+                }
+                swc::ModuleDecl::ExportNamed(named) if !named.type_only => {
+                    let source = match &named.src {
+                        Some(src) => self
+                            .collect_exports(self.dependency(index, src.value.as_str()), visiting),
+                        None => locals.clone(),
+                    };
+
+                    for specifier in &named.specifiers {
+                        if let swc::ExportSpecifier::Named(specifier) = specifier {
+                            let orig = export_name(&specifier.orig);
+                            let exported = specifier
+                                .exported
+                                .as_ref()
+                                .map_or_else(|| orig.clone(), export_name);
+
+                            if let Some(export) =
+                                source.get(&orig).filter(|_| !specifier.is_type_only)
+                            {
+                                exports.insert(exported, export.clone());
+                            }
+                        }
+                    }
+                }
+                swc::ModuleDecl::ExportAll(export_all) if !export_all.type_only => {
+                    let dependency = self.dependency(index, export_all.src.value.as_str());
+
+                    // Like in TypeScript, `export *` doesn't re-export the default export.
+                    exports.extend(
+                        self.collect_exports(dependency, visiting)
+                            .into_iter()
+                            .filter(|(name, _)| name != "default"),
+                    );
+                }
+                _ => {}
+            }
+        }
+
+        visiting.remove(&index);
+
+        exports
+    }
+
+    /// The top-level functions and constants of a module and the bindings it imports, which `export { .. }` and
+    /// `export default` can export.
+    fn collect_locals(
+        &self,
+        index: usize,
+        visiting: &mut HashSet<usize>,
+    ) -> HashMap<String, Export> {
+        let mut locals = HashMap::new();
+
+        for module_item in &self.modules[index].module.body {
+            match module_item {
+                swc::ModuleItem::Stmt(swc::Stmt::Decl(decl))
+                | swc::ModuleItem::ModuleDecl(swc::ModuleDecl::ExportDecl(swc::ExportDecl {
+                    decl,
+                    ..
+                })) => {
+                    extend_exports(&mut locals, decl_exports(decl));
+                }
+                swc::ModuleItem::ModuleDecl(swc::ModuleDecl::Import(import))
+                    if !import.type_only =>
+                {
+                    let exports = self.collect_exports(
+                        self.dependency(index, import.src.value.as_str()),
+                        visiting,
+                    );
+
+                    for (local, imported) in import_bindings(import) {
+                        if let Some(export) = exports.get(&imported) {
+                            locals.insert(local, export.clone());
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        locals
+    }
+}
+
+/// The functions and constants that a top-level declaration declares.
+fn decl_exports(decl: &swc::Decl) -> Vec<(String, Export)> {
+    match decl {
+        // Overload signatures come before the implementation, which has a body.
+        swc::Decl::Fn(fn_decl) if fn_decl.function.body.is_none() => {
+            vec![(fn_decl.ident.sym.to_string(), Export::Overloaded)]
+        }
+        swc::Decl::Fn(fn_decl) => vec![(
+            fn_decl.ident.sym.to_string(),
+            Export::Function(fn_signature(&fn_decl.function)),
+        )],
+        swc::Decl::Var(var) if var.kind == swc::VarDeclKind::Const => var
+            .decls
+            .iter()
+            .filter_map(|declarator| {
+                let ident = declarator.name.as_ident()?;
+
+                Some((
+                    ident.id.sym.to_string(),
+                    Export::Constant(constant_type(declarator)),
+                ))
+            })
+            .collect(),
+        _ => vec![],
+    }
+}
+
+/// Adds the exports of a declaration, where the implementation of an overloaded function, which comes after its
+/// overload signatures, is still overloaded.
+fn extend_exports(exports: &mut HashMap<String, Export>, decl_exports: Vec<(String, Export)>) {
+    for (name, export) in decl_exports {
+        if !matches!(exports.get(&name), Some(Export::Overloaded)) {
+            exports.insert(name, export);
+        }
+    }
+}
+
+/// The type of a top-level constant of a module, from its annotation or its value, which importers rely on without
+/// analyzing the module.
+pub fn constant_type(declarator: &swc::VarDeclarator) -> ValueType {
+    match (
+        declarator
+            .name
+            .as_ident()
+            .and_then(|ident| ident.type_ann.as_ref()),
+        &declarator.init,
+    ) {
+        (Some(type_ann), _) => annotation_type(&type_ann.type_ann),
+        (None, Some(init)) => infer_type(&Context::new(), init),
+        (None, None) => ValueType::Unknown,
+    }
+}
+
+/// The local names of the value bindings an import declares, with the names they are imported by, where `default`
+/// is the default export.
+pub fn import_bindings(import: &swc::ImportDecl) -> Vec<(String, String)> {
+    import
+        .specifiers
+        .iter()
+        .filter_map(|specifier| match specifier {
+            swc::ImportSpecifier::Named(named) if named.is_type_only => None,
+            swc::ImportSpecifier::Named(named) => Some((
+                named.local.sym.to_string(),
+                named
+                    .imported
+                    .as_ref()
+                    .map_or_else(|| named.local.sym.to_string(), export_name),
+            )),
+            swc::ImportSpecifier::Default(default) => {
+                Some((default.local.sym.to_string(), "default".to_string()))
+            }
+            swc::ImportSpecifier::Namespace(_) => todo!("import namespace"),
+        })
+        .collect()
+}
+
+pub fn export_name(name: &swc::ModuleExportName) -> String {
+    match name {
+        swc::ModuleExportName::Ident(ident) => ident.sym.to_string(),
+        swc::ModuleExportName::Str(str) => str.value.to_string(),
+    }
+}
+
+/// Whether an import imports values, rather than only types, which TypeScript elides.
+pub fn is_value_import(import: &swc::ImportDecl) -> bool {
+    !import.type_only
+        && (import.specifiers.is_empty()
+            || import.specifiers.iter().any(|specifier| {
+                !matches!(specifier, swc::ImportSpecifier::Named(named) if named.is_type_only)
+            }))
+}
+
+/// Whether a re-export exports values, rather than only types.
+pub fn is_value_export(named: &swc::NamedExport) -> bool {
+    !named.type_only
+        && named.specifiers.iter().any(|specifier| {
+            !matches!(specifier, swc::ExportSpecifier::Named(named) if named.is_type_only)
+        })
+}
+
+/// The specifiers of the modules that a module imports or re-exports values from.
+fn module_specifiers(module: &swc::Module) -> Vec<String> {
+    module
+        .body
+        .iter()
+        .filter_map(|module_item| match module_item.as_module_decl()? {
+            swc::ModuleDecl::Import(import) if is_value_import(import) => {
+                Some(import.src.value.to_string())
+            }
+            swc::ModuleDecl::ExportNamed(named) if is_value_export(named) => {
+                named.src.as_ref().map(|src| src.value.to_string())
+            }
+            swc::ModuleDecl::ExportAll(export_all) if !export_all.type_only => {
+                Some(export_all.src.value.to_string())
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Resolves a relative specifier to a `.ts` file, or to the `index.ts` of a directory.
+///
+/// Specifiers ending in `.js` refer to the `.ts` file, since that is what TypeScript compiles to `.js`. Packages
+/// can't be imported, since there is no Rust crate to map them to.
+fn resolve_specifier(dir: &Path, specifier: &str) -> Result<PathBuf, io::Error> {
+    if !specifier.starts_with("./") && !specifier.starts_with("../") {
+        return Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            format!(
+                "Cannot import package `{}` from `{}`, only relative imports are supported.",
+                specifier,
+                dir.display()
+            ),
+        ));
+    }
+
+    let path = dir.join(specifier.strip_suffix(".js").unwrap_or(specifier));
+    let candidates = [
+        path.clone(),
+        PathBuf::from(format!("{}.ts", path.display())),
+        PathBuf::from(format!("{}.tsx", path.display())),
+        path.join("index.ts"),
+    ];
+
+    candidates
+        .into_iter()
+        .find(|candidate| {
+            candidate.is_file()
+                && candidate
+                    .extension()
+                    .is_some_and(|extension| extension == "ts" || extension == "tsx")
+        })
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!(
+                    "Cannot find module `{}` from `{}`.",
+                    specifier,
+                    dir.display()
+                ),
+            )
+        })?
+        .canonicalize()
+}
+
+/// The name of the Rust module for a file or directory name, in snake case, e.g. `math_utils` for `mathUtils`.
+fn module_name(name: &str) -> String {
+    let mut module_name = String::new();
+
+    for (index, char) in name.chars().enumerate() {
+        if char.is_ascii_uppercase() {
+            if index > 0 && !module_name.ends_with('_') {
+                module_name.push('_');
+            }
+
+            module_name.push(char.to_ascii_lowercase());
+        } else if char.is_ascii_alphanumeric() {
+            module_name.push(char);
+        } else {
+            module_name.push('_');
+        }
+    }
+
+    module_name
+}
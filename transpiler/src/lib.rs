@@ -1,10 +1,20 @@
+pub mod analysis;
+pub mod context;
 pub mod decl;
 pub mod expr;
+pub mod generator;
+pub mod graph;
 pub mod module;
+pub mod project;
 pub mod stmt;
+pub mod types;
 pub mod util;
 
-use std::{error::Error, fs, io, path::Path};
+use std::{
+    error::Error,
+    fs, io,
+    path::{Path, PathBuf},
+};
 
 use swc_common::{
     errors::{ColorConfig, Handler},
@@ -15,7 +25,11 @@ use swc_ecma_ast::Module;
 use swc_ecma_parser::{lexer::Lexer, Capturing, Parser, StringInput, Syntax, TsConfig};
 use syn::File;
 
-use crate::module::transpile_module;
+use crate::{
+    graph::ModuleGraph,
+    module::transpile_module_graph,
+    project::{module_file, write_crate},
+};
 
 pub fn parse_rust_file(input_file: &Path) -> Result<File, Box<dyn Error>> {
     let content = fs::read_to_string(input_file)?;
@@ -56,16 +70,102 @@ pub fn parse_typescript_file(input_file: &Path) -> Result<Module, io::Error> {
     Ok(module)
 }
 
-fn write_rust_file(file: File, output_file: &Path) -> Result<(), io::Error> {
-    fs::write(output_file, prettyplease::unparse(&file))
+/// Options that change how TypeScript is transpiled.
+#[derive(Clone, Debug)]
+pub struct TranspileOptions {
+    /// Represent `number` bindings that provably only hold integers as Rust integers instead of `f64`.
+    pub integer_narrowing: bool,
+    /// How functions with overload signatures are represented, since Rust has no overloading.
+    pub overloads: OverloadStrategy,
+    /// Run timers on a virtual clock that skips ahead to the next timer, so programs run instantly and
+    /// deterministically.
+    pub virtual_clock: bool,
+}
+
+impl Default for TranspileOptions {
+    fn default() -> Self {
+        TranspileOptions {
+            integer_narrowing: true,
+            overloads: OverloadStrategy::Split,
+            virtual_clock: false,
+        }
+    }
 }
 
-pub fn transpile_file(input_file: &Path, output_file: &Path) -> Result<(), io::Error> {
-    let module = parse_typescript_file(input_file)?;
+/// Representations of overloaded functions in Rust.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OverloadStrategy {
+    /// A function per overload named after its parameter types, e.g. `parse_string`, with each call resolved to one
+    /// of them by the types of its arguments.
+    Split,
+    /// One function taking a generated enum with a variant per overload, e.g. `parse(ParseArgs::String(x))`.
+    ArgumentEnum,
+}
 
-    let file = transpile_module(module);
+fn write_rust_file(file: File, output_file: &Path) -> Result<(), io::Error> {
+    fs::write(output_file, prettyplease::unparse(&file))
+}
 
-    write_rust_file(file, output_file)?;
+/// Transpiles a TypeScript file to a Rust file, along with the modules it imports, which are written to the files of
+/// their module paths next to it, see `transpile_module_graph`.
+pub fn transpile_file(
+    input_file: &Path,
+    output_file: &Path,
+    options: &TranspileOptions,
+) -> Result<(), io::Error> {
+    let graph = ModuleGraph::load(input_file)?;
+    let output_dir = output_file.parent().expect("Files are in a directory.");
+    let output_file_name = &output_file
+        .file_name()
+        .expect("Files have a name.")
+        .to_string_lossy();
+
+    for (module_path, file) in transpile_module_graph(&graph, options) {
+        let module_file = module_file(output_dir, &module_path, output_file_name);
+
+        if let Some(dir) = module_file.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        write_rust_file(file, &module_file)?;
+    }
 
     Ok(())
 }
+
+/// Transpiles the TypeScript project of a `tsconfig.json` to a Cargo crate in its `outDir`, or in `out_dir` if
+/// given, with a path dependency on the ts-std crate in `ts_std_dir`. Returns the directory of the crate.
+///
+/// The modules of the crate are the files of the project by their path relative to `rootDir`, where `main.ts` or
+/// `index.ts` is the crate root. The crate is a binary if the root has top-level statements, and a library otherwise.
+pub fn transpile_project(
+    tsconfig_file: &Path,
+    out_dir: Option<&Path>,
+    ts_std_dir: &Path,
+    options: &TranspileOptions,
+) -> Result<PathBuf, io::Error> {
+    let config = project::TsConfig::load(tsconfig_file)?;
+    let out_dir = match out_dir.map(Path::to_path_buf).or(config.out_dir.clone()) {
+        Some(out_dir) => out_dir,
+        None => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "`{}` has no `outDir` to write the crate to.",
+                    tsconfig_file.display()
+                ),
+            ))
+        }
+    };
+    let graph = ModuleGraph::load_project(&config.root_dir, &config.source_files()?)?;
+
+    write_crate(
+        &graph,
+        &out_dir,
+        &config.package_name(),
+        ts_std_dir,
+        options,
+    )?;
+
+    Ok(out_dir)
+}
@@ -1,43 +1,236 @@
+use std::collections::{HashMap, HashSet};
+
+use proc_macro2::TokenStream;
 use quote::quote;
 use swc_ecma_ast as swc;
 use syn::{punctuated::Punctuated, *};
 
 use crate::{
+    analysis::is_async_module,
+    context::Context,
+    decl::{declare_functions, transpile_fn_decl},
+    expr::transpile_value,
+    graph::{
+        constant_type, export_name, import_bindings, is_value_export, is_value_import, CrateType,
+        Export, ModuleGraph,
+    },
     stmt::transpile_stmt_to_stmts,
+    types::{param_type, ValueType},
     util::{dummy_span, ItemOrStmt},
+    TranspileOptions,
 };
 
-pub fn transpile_module(module: swc::Module) -> File {
-    let uses = vec![generate_std_use()];
+/// Transpiles the modules of a graph to the files of a Rust crate by their module paths, see `transpile_module`.
+/// Directories without a module of their own get a file that only declares the modules in them.
+pub fn transpile_module_graph(
+    graph: &ModuleGraph,
+    options: &TranspileOptions,
+) -> Vec<(Vec<String>, File)> {
+    (0..graph.modules.len())
+        .map(|index| {
+            (
+                graph.modules[index].module_path.clone(),
+                transpile_module(graph, index, options),
+            )
+        })
+        .chain(graph.directory_modules().into_iter().map(|module_path| {
+            let file = File {
+                shebang: None,
+                attrs: vec![],
+                items: generate_mod_decls(graph, &module_path),
+            };
 
-    let item_or_stmts = module.body.into_iter().flat_map(transpile_module_item);
-    let items = item_or_stmts.clone().filter_map(|ios| match ios {
-        ItemOrStmt::Item(item) => Some(item),
-        ItemOrStmt::Stmt(Stmt::Item(item)) => Some(item),
-        _ => None,
-    });
-    let stmts = item_or_stmts.filter_map(|ios| match ios {
-        ItemOrStmt::Stmt(Stmt::Item(_)) => None,
-        ItemOrStmt::Stmt(stmt) => Some(stmt),
-        _ => None,
-    });
+            (module_path, file)
+        }))
+        .collect()
+}
+
+/// The imports and exports of the module being transpiled.
+struct ModuleScope<'a> {
+    graph: &'a ModuleGraph,
+    index: usize,
+    /// Top-level functions and constants that are exported, which are `pub`.
+    exported: HashSet<String>,
+    /// Imported bindings by their local name, with the path of the module they are imported from and the name it
+    /// exports them by.
+    imported: HashMap<String, (Vec<String>, String)>,
+}
+
+impl ModuleScope<'_> {
+    /// Whether the module is the crate root of a binary, whose top-level code becomes `main`.
+    fn has_main(&self) -> bool {
+        self.graph.crate_type == CrateType::Binary
+            && self.graph.modules[self.index].module_path.is_empty()
+    }
+}
+
+/// Transpiles a module of a graph. The crate root of a binary has its top-level code in `main`, while the other
+/// modules have theirs in `init_module`, which `main` calls for the modules it imports before its own code. Their
+/// exports are `pub`.
+///
+/// Imports become `use` declarations of the modules they resolve to, e.g. `use crate::shapes::circle::area;`, and
+/// each module declares the modules in its directory with `mod`.
+pub fn transpile_module(graph: &ModuleGraph, index: usize, options: &TranspileOptions) -> File {
+    let node = &graph.modules[index];
+    let module = node.module.clone();
+    let mut ctx = Context::for_module(&module, options);
+    let is_async = is_async_module(&module);
+    let mut scope = ModuleScope {
+        graph,
+        index,
+        exported: HashSet::new(),
+        imported: HashMap::new(),
+    };
+    let has_code = scope.has_main() || has_top_level_code(&module);
+    let mut uses = vec![generate_std_use(has_code)];
+
+    // Imports are hoisted, so functions can call the functions they import before the import.
+    for module_item in &module.body {
+        if let Some(import) = module_item
+            .as_module_decl()
+            .and_then(swc::ModuleDecl::as_import)
+        {
+            uses.extend(transpile_import(&mut ctx, &mut scope, import));
+        }
+    }
+
+    let hoisted: Vec<swc::Stmt> = module.body.iter().filter_map(hoisted_stmt).collect();
+    declare_functions(&mut ctx, &hoisted);
+
+    let item_or_stmts: Vec<ItemOrStmt> = module
+        .body
+        .into_iter()
+        .flat_map(|module_item| transpile_module_item(&mut ctx, &mut scope, module_item))
+        .collect();
+    let items = item_or_stmts
+        .clone()
+        .into_iter()
+        .filter_map(|ios| match ios {
+            ItemOrStmt::Item(item) => Some(item),
+            ItemOrStmt::Stmt(Stmt::Item(item)) => Some(item),
+            _ => None,
+        })
+        .map(|item| generate_exported_item(item, &scope.exported));
+    let stmts: Vec<Stmt> = item_or_stmts
+        .into_iter()
+        .filter_map(|ios| match ios {
+            ItemOrStmt::Stmt(Stmt::Item(_)) => None,
+            ItemOrStmt::Stmt(stmt) => Some(stmt),
+            _ => None,
+        })
+        .collect();
+    let code_fn = if scope.has_main() {
+        let init_calls = graph
+            .evaluation_order()
+            .into_iter()
+            .filter(|dependency| has_top_level_code(&graph.modules[*dependency].module))
+            .map(|dependency| generate_init_call(&graph.modules[dependency].module_path));
+
+        Some(generate_main_fn(
+            init_calls.chain(stmts).collect(),
+            is_async,
+            options,
+        ))
+    } else if !has_code {
+        None
+    } else if graph.crate_type == CrateType::Library {
+        panic!(
+            "Module `{}` has top-level code, which only runs in the modules of a program with a `main.ts` or \
+             `index.ts` that has top-level code.",
+            node.path.display()
+        )
+    } else if is_async {
+        todo!("top-level await in imported modules")
+    } else {
+        Some(generate_init_fn(stmts))
+    };
 
     File {
         shebang: None,
         attrs: vec![],
-        items: uses
+        items: generate_mod_decls(graph, &node.module_path)
             .into_iter()
+            .chain(uses)
             .chain(items)
-            .chain(vec![generate_main_fn(stmts.collect())])
+            .chain(code_fn)
             .collect(),
     }
 }
 
-pub fn transpile_module_item(module_item: swc::ModuleItem) -> Vec<ItemOrStmt> {
+/// Whether a module other than the crate root has top-level code besides declarations, which runs in `init_module`.
+/// Variables other than constants are code, since they are bindings of `init_module`.
+fn has_top_level_code(module: &swc::Module) -> bool {
+    module.body.iter().any(|module_item| match module_item {
+        swc::ModuleItem::Stmt(swc::Stmt::Decl(swc::Decl::Var(var))) => {
+            var.kind != swc::VarDeclKind::Const
+        }
+        swc::ModuleItem::Stmt(stmt) => !stmt.is_decl(),
+        swc::ModuleItem::ModuleDecl(_) => false,
+    })
+}
+
+/// The declaration of a module item that is hoisted, including exported declarations.
+fn hoisted_stmt(module_item: &swc::ModuleItem) -> Option<swc::Stmt> {
+    match module_item {
+        swc::ModuleItem::Stmt(stmt) => Some(stmt.clone()),
+        swc::ModuleItem::ModuleDecl(swc::ModuleDecl::ExportDecl(export_decl)) => {
+            Some(swc::Stmt::Decl(export_decl.decl.clone()))
+        }
+        swc::ModuleItem::ModuleDecl(swc::ModuleDecl::ExportDefaultDecl(
+            swc::ExportDefaultDecl {
+                decl: swc::DefaultDecl::Fn(fn_expr),
+                ..
+            },
+        )) => Some(swc::Stmt::Decl(swc::Decl::Fn(default_fn_decl(fn_expr)))),
+        _ => None,
+    }
+}
+
+/// The declaration of a function that is exported as the default export, which is named `default` if it has no name.
+fn default_fn_decl(fn_expr: &swc::FnExpr) -> swc::FnDecl {
+    swc::FnDecl {
+        ident: fn_expr
+            .ident
+            .clone()
+            .unwrap_or_else(|| swc::Ident::new("default".into(), fn_expr.function.span)),
+        declare: false,
+        function: fn_expr.function.clone(),
+    }
+}
+
+fn transpile_module_item(
+    ctx: &mut Context,
+    scope: &mut ModuleScope,
+    module_item: swc::ModuleItem,
+) -> Vec<ItemOrStmt> {
     if module_item.is_module_decl() {
-        todo!("module item module decl")
+        transpile_module_decl(
+            ctx,
+            scope,
+            module_item
+                .module_decl()
+                .expect("ModuleItem is ModuleDecl."),
+        )
+    } else if module_item
+        .as_stmt()
+        .and_then(swc::Stmt::as_decl)
+        .and_then(swc::Decl::as_var)
+        .is_some_and(|var| var.kind == swc::VarDeclKind::Const && !scope.has_main())
+    {
+        let var = module_item
+            .stmt()
+            .expect("ModuleItem is Stmt.")
+            .decl()
+            .expect("Stmt is Decl.")
+            .var()
+            .expect("Decl is Var.");
+
+        transpile_constants(ctx, *var)
+            .into_iter()
+            .map(ItemOrStmt::Item)
+            .collect()
     } else if module_item.is_stmt() {
-        transpile_stmt_to_stmts(module_item.stmt().expect("ModuleItem is Stmt."))
+        transpile_stmt_to_stmts(ctx, module_item.stmt().expect("ModuleItem is Stmt."))
             .into_iter()
             .map(ItemOrStmt::Stmt)
             .collect()
@@ -46,9 +239,365 @@ pub fn transpile_module_item(module_item: swc::ModuleItem) -> Vec<ItemOrStmt> {
     }
 }
 
-fn generate_std_use() -> Item {
+fn transpile_module_decl(
+    ctx: &mut Context,
+    scope: &mut ModuleScope,
+    module_decl: swc::ModuleDecl,
+) -> Vec<ItemOrStmt> {
+    if module_decl.is_import() {
+        // Imports are transpiled before the other module items, see `transpile_module`.
+        vec![]
+    } else if module_decl.is_export_decl() {
+        let decl = module_decl
+            .export_decl()
+            .expect("ModuleDecl is ExportDecl.")
+            .decl;
+
+        if decl
+            .as_var()
+            .is_some_and(|var| var.kind != swc::VarDeclKind::Const)
+        {
+            todo!("exported variable");
+        }
+
+        scope.exported.extend(decl_names(&decl));
+        transpile_module_item(ctx, scope, swc::ModuleItem::Stmt(swc::Stmt::Decl(decl)))
+    } else if module_decl.is_export_named() {
+        transpile_named_export(
+            scope,
+            module_decl
+                .export_named()
+                .expect("ModuleDecl is ExportNamed."),
+        )
+    } else if module_decl.is_export_default_decl() {
+        match module_decl
+            .export_default_decl()
+            .expect("ModuleDecl is ExportDefaultDecl.")
+            .decl
+        {
+            swc::DefaultDecl::Fn(fn_expr) => {
+                let fn_decl = default_fn_decl(&fn_expr);
+                let name = fn_decl.ident.sym.to_string();
+                let mut items: Vec<ItemOrStmt> = transpile_fn_decl(ctx, fn_decl)
+                    .into_iter()
+                    .map(ItemOrStmt::Item)
+                    .collect();
+
+                items.extend(export_local(scope, &name, "default").map(ItemOrStmt::Item));
+                items
+            }
+            swc::DefaultDecl::Class(_) => todo!("export default class"),
+            swc::DefaultDecl::TsInterfaceDecl(_) => todo!("export default interface"),
+        }
+    } else if module_decl.is_export_default_expr() {
+        let expr = *module_decl
+            .export_default_expr()
+            .expect("ModuleDecl is ExportDefaultExpr.")
+            .expr;
+
+        match expr {
+            swc::Expr::Ident(ident) => export_local(scope, ident.sym.as_str(), "default")
+                .map(ItemOrStmt::Item)
+                .into_iter()
+                .collect(),
+            _ => todo!("export default expression"),
+        }
+    } else if module_decl.is_export_all() {
+        let export_all = module_decl.export_all().expect("ModuleDecl is ExportAll.");
+
+        if export_all.type_only {
+            return vec![];
+        }
+
+        let dependency = scope
+            .graph
+            .dependency(scope.index, export_all.src.value.as_str());
+        // The names are listed instead of a glob, since `export *` leaves out the default export and the names that
+        // are only local to the module.
+        let mut names: Vec<(String, String)> = scope
+            .graph
+            .exports(dependency)
+            .into_keys()
+            .filter(|name| name != "default")
+            .map(|name| (name.clone(), name))
+            .collect();
+
+        names.sort();
+
+        vec![ItemOrStmt::Item(generate_use(
+            generate_pub_visibility(),
+            &scope.graph.modules[dependency].module_path,
+            names,
+        ))]
+    } else if module_decl.is_ts_import_equals() {
+        todo!("module decl ts import equals")
+    } else if module_decl.is_ts_export_assignment() {
+        todo!("module decl ts export assignment")
+    } else if module_decl.is_ts_namespace_export() {
+        todo!("module decl ts namespace export")
+    } else {
+        unreachable!("Unknown ModuleDecl.")
+    }
+}
+
+/// Transpiles an import to a `use` declaration of the module it resolves to, and declares the functions and
+/// constants it imports with the types the module exports them with.
+///
+/// Imports of only types are elided like in TypeScript.
+fn transpile_import(
+    ctx: &mut Context,
+    scope: &mut ModuleScope,
+    import: &swc::ImportDecl,
+) -> Option<Item> {
+    if !is_value_import(import) {
+        return None;
+    }
+
+    // Imports for side effects only run the top-level code of the module, see `generate_init_call`.
+    if import.specifiers.is_empty() {
+        return None;
+    }
+
+    let dependency = scope
+        .graph
+        .dependency(scope.index, import.src.value.as_str());
+    let node = &scope.graph.modules[dependency];
+    let exports = scope.graph.exports(dependency);
+    let names = import_bindings(import)
+        .into_iter()
+        .map(|(local, imported)| {
+            match exports.get(&imported) {
+                Some(Export::Function(signature)) => {
+                    ctx.declare_function(&local, signature.clone())
+                }
+                Some(Export::Constant(value_type)) => {
+                    ctx.declare(&local, value_type.clone());
+                    ctx.declare_string(&local, false);
+                }
+                Some(Export::Overloaded) => todo!("import of overloaded function"),
+                None => panic!(
+                    "Module `{}` doesn't export `{}`.",
+                    node.path.display(),
+                    imported
+                ),
+            }
+
+            scope
+                .imported
+                .insert(local.clone(), (node.module_path.clone(), imported.clone()));
+
+            (imported, local)
+        })
+        .collect();
+
+    Some(generate_use(
+        Visibility::Inherited,
+        &node.module_path,
+        names,
+    ))
+}
+
+/// Transpiles `export { a, b as c }` to `pub` items and `pub use` declarations for renamed exports, and
+/// `export { a } from "./module"` to a `pub use` declaration of the module.
+fn transpile_named_export(scope: &mut ModuleScope, named: swc::NamedExport) -> Vec<ItemOrStmt> {
+    if !is_value_export(&named) {
+        return vec![];
+    }
+
+    let specifiers: Vec<(String, String)> = named
+        .specifiers
+        .iter()
+        .filter_map(|specifier| match specifier {
+            swc::ExportSpecifier::Named(named) if named.is_type_only => None,
+            swc::ExportSpecifier::Named(named) => {
+                let orig = export_name(&named.orig);
+                let exported = named
+                    .exported
+                    .as_ref()
+                    .map_or_else(|| orig.clone(), export_name);
+
+                Some((orig, exported))
+            }
+            swc::ExportSpecifier::Namespace(_) => todo!("export namespace"),
+            swc::ExportSpecifier::Default(_) => todo!("export default from"),
+        })
+        .collect();
+
+    match named.src {
+        Some(src) => {
+            let dependency = scope.graph.dependency(scope.index, src.value.as_str());
+            let exports = scope.graph.exports(dependency);
+            // Types that aren't marked as type-only are still elided.
+            let specifiers = specifiers
+                .into_iter()
+                .filter(|(orig, _)| exports.contains_key(orig))
+                .collect();
+            vec![ItemOrStmt::Item(generate_use(
+                generate_pub_visibility(),
+                &scope.graph.modules[dependency].module_path,
+                specifiers,
+            ))]
+        }
+        None => specifiers
+            .into_iter()
+            .filter_map(|(orig, exported)| export_local(scope, &orig, &exported))
+            .map(ItemOrStmt::Item)
+            .collect(),
+    }
+}
+
+/// Exports a top-level binding of the module by making it `pub`, with a `pub use` declaration if it is exported by
+/// another name. Imported bindings are private, so they are re-exported from the module they are imported from.
+fn export_local(scope: &mut ModuleScope, local: &str, exported: &str) -> Option<Item> {
+    if let Some((module_path, imported)) = scope.imported.get(local) {
+        return Some(generate_use(
+            generate_pub_visibility(),
+            module_path,
+            vec![(imported.clone(), exported.to_string())],
+        ));
+    }
+
+    scope.exported.insert(local.to_string());
+
+    (local != exported).then(|| {
+        let local = Ident::new(local, dummy_span());
+        let exported = Ident::new(exported, dummy_span());
+
+        parse_quote! { pub use self::#local as #exported; }
+    })
+}
+
+/// The names of the functions and constants that a declaration declares.
+fn decl_names(decl: &swc::Decl) -> Vec<String> {
+    match decl {
+        swc::Decl::Fn(fn_decl) => vec![fn_decl.ident.sym.to_string()],
+        swc::Decl::Var(var) => var
+            .decls
+            .iter()
+            .filter_map(|declarator| declarator.name.as_ident())
+            .map(|ident| ident.id.sym.to_string())
+            .collect(),
+        _ => vec![],
+    }
+}
+
+/// Transpiles the top-level constants of a module other than the crate root to `const` items, since there is no
+/// `main` for them to be bindings in.
+fn transpile_constants(ctx: &mut Context, var: swc::VarDecl) -> Vec<Item> {
+    var.decls
+        .into_iter()
+        .map(|declarator| {
+            let value_type = constant_type(&declarator);
+            let ident = match &declarator.name {
+                swc::Pat::Ident(ident) => ident.id.sym.to_string(),
+                _ => todo!("constant pattern"),
+            };
+            let ty = match value_type {
+                ValueType::Boolean | ValueType::Number(_) | ValueType::String => {
+                    param_type(&value_type)
+                }
+                _ => todo!("constant of a type other than boolean, number or string"),
+            };
+            let value = transpile_value(
+                ctx,
+                *declarator.init.expect("Constants are initialized."),
+                &value_type,
+            );
+
+            ctx.declare(&ident, value_type);
+            ctx.declare_string(&ident, false);
+
+            let ident = Ident::new(&ident, dummy_span());
+            let allow_clippy_all = generate_allow_clippy_all_attribute();
+
+            parse_quote! {
+                #allow_clippy_all
+                #[allow(non_upper_case_globals)]
+                const #ident: #ty = #value;
+            }
+        })
+        .collect()
+}
+
+/// Makes a function or constant `pub` if it is exported.
+fn generate_exported_item(mut item: Item, exported: &HashSet<String>) -> Item {
+    let (vis, ident) = match &mut item {
+        Item::Fn(item_fn) => (&mut item_fn.vis, &item_fn.sig.ident),
+        Item::Const(item_const) => (&mut item_const.vis, &item_const.ident),
+        _ => return item,
+    };
+
+    if exported.contains(&ident.to_string()) {
+        *vis = generate_pub_visibility();
+    }
+
+    item
+}
+
+/// Generates the `mod` declarations of the modules in a module, which are `pub` outside of the crate root of a binary
+/// so the other modules and the users of a library can use them.
+fn generate_mod_decls(graph: &ModuleGraph, module_path: &[String]) -> Vec<Item> {
+    let vis =
+        (!module_path.is_empty() || graph.crate_type == CrateType::Library).then(|| quote!(pub));
+
+    graph
+        .child_modules(module_path)
+        .into_iter()
+        .map(|name| {
+            let name = Ident::new(&name, dummy_span());
+
+            parse_quote! { #vis mod #name; }
+        })
+        .collect()
+}
+
+/// Generates the `use` declaration of names from a module, with the local names they are used by, e.g.
+/// `use crate::math::{add, sub as minus};`.
+fn generate_use(vis: Visibility, module_path: &[String], names: Vec<(String, String)>) -> Item {
+    let module_path = generate_module_path(module_path);
+    let trees: Vec<TokenStream> = names
+        .iter()
+        .map(|(name, local)| {
+            let is_renamed = name != local;
+            let name = Ident::new(name, dummy_span());
+            let local = Ident::new(local, dummy_span());
+
+            if is_renamed {
+                quote!(#name as #local)
+            } else {
+                quote!(#name)
+            }
+        })
+        .collect();
+
+    match &trees[..] {
+        [tree] => parse_quote! { #vis use #module_path::#tree; },
+        _ => parse_quote! { #vis use #module_path::{#(#trees),*}; },
+    }
+}
+
+fn generate_pub_visibility() -> Visibility {
+    Visibility::Public(token::Pub(dummy_span()))
+}
+
+/// The path of a module from the crate root, e.g. `crate::shapes::circle`.
+fn generate_module_path(module_path: &[String]) -> TokenStream {
+    let segments = module_path
+        .iter()
+        .map(|segment| Ident::new(segment, dummy_span()));
+
+    quote!(crate #(::#segments)*)
+}
+
+/// Generates `use ts_std::*;`, which modules without top-level code may not need, since they only contain
+/// declarations.
+fn generate_std_use(has_code: bool) -> Item {
     Item::Use(ItemUse {
-        attrs: vec![],
+        attrs: if has_code {
+            vec![]
+        } else {
+            vec![parse_quote!(#[allow(unused_imports)])]
+        },
         vis: Visibility::Inherited,
         use_token: token::Use(dummy_span()),
         leading_colon: None,
@@ -63,7 +612,23 @@ fn generate_std_use() -> Item {
     })
 }
 
-fn generate_main_fn(stmts: Vec<Stmt>) -> Item {
+/// Generates `main` with the top-level code, which runs as a future on the ts-std event loop if the module is async,
+/// so top-level `await` works and its promises and timers settle before the program exits.
+fn generate_main_fn(stmts: Vec<Stmt>, is_async: bool, options: &TranspileOptions) -> Item {
+    let stmts = match (is_async, options.virtual_clock) {
+        (true, false) => vec![parse_quote! {
+            run_main(async {
+                #(#stmts)*
+            });
+        }],
+        (true, true) => vec![parse_quote! {
+            run_main_with_clock(Clock::Virtual, async {
+                #(#stmts)*
+            });
+        }],
+        (false, _) => stmts,
+    };
+
     Item::Fn(ItemFn {
         attrs: vec![generate_allow_clippy_all_attribute()],
         vis: Visibility::Inherited,
@@ -92,7 +657,26 @@ fn generate_main_fn(stmts: Vec<Stmt>) -> Item {
     })
 }
 
-fn generate_allow_clippy_all_attribute() -> Attribute {
+/// Generates `init_module` with the top-level code of a module other than the crate root.
+fn generate_init_fn(stmts: Vec<Stmt>) -> Item {
+    let allow_clippy_all = generate_allow_clippy_all_attribute();
+
+    parse_quote! {
+        #allow_clippy_all
+        pub fn init_module() {
+            #(#stmts)*
+        }
+    }
+}
+
+/// Generates the call of `init_module` of a module, e.g. `crate::shapes::circle::init_module();`.
+fn generate_init_call(module_path: &[String]) -> Stmt {
+    let module_path = generate_module_path(module_path);
+
+    parse_quote! { #module_path::init_module(); }
+}
+
+pub fn generate_allow_clippy_all_attribute() -> Attribute {
     Attribute {
         pound_token: token::Pound(dummy_span()),
         style: AttrStyle::Outer,
@@ -0,0 +1,418 @@
+use std::{
+    collections::HashSet,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use swc_common::{sync::Lrc, FileName, SourceMap};
+use swc_ecma_ast as swc;
+use swc_ecma_parser::{lexer::Lexer, Parser, StringInput, Syntax};
+
+use crate::{
+    graph::{CrateType, ModuleGraph},
+    module::transpile_module_graph,
+    TranspileOptions,
+};
+
+/// The options of a `tsconfig.json` that decide which files are transpiled, and where the crate is written.
+#[derive(Clone, Debug)]
+pub struct TsConfig {
+    /// The directory of the `tsconfig.json`, which the other paths are relative to.
+    pub dir: PathBuf,
+    /// `compilerOptions.rootDir`, whose files are the modules of the crate by their path relative to it.
+    pub root_dir: PathBuf,
+    /// `compilerOptions.outDir`, where the crate is written.
+    pub out_dir: Option<PathBuf>,
+    pub files: Vec<String>,
+    pub include: Vec<String>,
+    pub exclude: Vec<String>,
+}
+
+impl TsConfig {
+    /// Reads a `tsconfig.json`, which may have comments and trailing commas like TypeScript allows.
+    pub fn load(path: &Path) -> Result<TsConfig, io::Error> {
+        let invalid = |message: &str| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("`{}` {}.", path.display(), message),
+            )
+        };
+        let dir = path
+            .canonicalize()?
+            .parent()
+            .expect("Files are in a directory.")
+            .to_path_buf();
+        let config = parse_json(path)?;
+        let config = config
+            .as_object()
+            .ok_or_else(|| invalid("isn't an object"))?;
+
+        if property(config, "extends").is_some() {
+            return Err(invalid(
+                "extends another configuration, which isn't supported",
+            ));
+        }
+
+        let compiler_options = property(config, "compilerOptions").and_then(swc::Expr::as_object);
+        let compiler_option = |key: &str| {
+            compiler_options
+                .and_then(|compiler_options| property(compiler_options, key))
+                .and_then(string_value)
+        };
+        let root_dir =
+            compiler_option("rootDir").map_or_else(|| dir.clone(), |root_dir| dir.join(root_dir));
+        let out_dir = compiler_option("outDir").map(|out_dir| dir.join(out_dir));
+        let files = property(config, "files")
+            .and_then(string_array)
+            .unwrap_or_default();
+        // Like in TypeScript, all files are included unless `files` lists them, and the output isn't.
+        let include = property(config, "include")
+            .and_then(string_array)
+            .unwrap_or_else(|| {
+                if files.is_empty() {
+                    vec!["**/*".to_string()]
+                } else {
+                    vec![]
+                }
+            });
+        let exclude = property(config, "exclude")
+            .and_then(string_array)
+            .unwrap_or_else(|| {
+                ["node_modules", "bower_components", "jspm_packages"]
+                    .into_iter()
+                    .map(str::to_string)
+                    .chain(compiler_option("outDir"))
+                    .collect()
+            });
+
+        Ok(TsConfig {
+            dir,
+            root_dir,
+            out_dir,
+            files,
+            include,
+            exclude,
+        })
+    }
+
+    /// The files of the project, which are its `files` and the TypeScript files matching `include` but not
+    /// `exclude`, sorted by path.
+    pub fn source_files(&self) -> Result<Vec<PathBuf>, io::Error> {
+        let mut source_files = vec![];
+
+        for file in &self.files {
+            let path = self.dir.join(file);
+
+            if !path.is_file() {
+                return Err(io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("File `{}` of the project doesn't exist.", path.display()),
+                ));
+            }
+
+            source_files.push(path.canonicalize()?);
+        }
+
+        self.find_source_files(&self.dir, &mut source_files)?;
+
+        source_files.sort();
+        source_files.dedup();
+
+        Ok(source_files)
+    }
+
+    fn find_source_files(
+        &self,
+        dir: &Path,
+        source_files: &mut Vec<PathBuf>,
+    ) -> Result<(), io::Error> {
+        let mut entries = fs::read_dir(dir)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        entries.sort();
+
+        for path in entries {
+            let relative = relative_pattern_path(&self.dir, &path);
+
+            if self
+                .exclude
+                .iter()
+                .any(|pattern| matches_pattern(pattern, &relative))
+            {
+                continue;
+            }
+
+            if path.is_dir() {
+                self.find_source_files(&path, source_files)?;
+            } else if is_source_file(&path)
+                && self
+                    .include
+                    .iter()
+                    .any(|pattern| matches_pattern(pattern, &relative))
+            {
+                source_files.push(path.canonicalize()?);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The name of the package, after the directory of the `tsconfig.json`, e.g. `my-app` for `my_app`.
+    pub fn package_name(&self) -> String {
+        self.dir
+            .file_name()
+            .map(|name| name.to_string_lossy())
+            .unwrap_or_default()
+            .chars()
+            .map(|char| {
+                if char.is_ascii_alphanumeric() {
+                    char.to_ascii_lowercase()
+                } else {
+                    '-'
+                }
+            })
+            .collect()
+    }
+}
+
+/// Parses a JSON file as a JavaScript expression, which also allows comments and trailing commas.
+fn parse_json(path: &Path) -> Result<swc::Expr, io::Error> {
+    let content = fs::read_to_string(path)?;
+    let cm: Lrc<SourceMap> = Default::default();
+    let fm = cm.new_source_file(FileName::Real(path.to_path_buf()), format!("({})", content));
+    let lexer = Lexer::new(
+        Syntax::Es(Default::default()),
+        Default::default(),
+        StringInput::from(&*fm),
+        None,
+    );
+    let expr = Parser::new_from(lexer).parse_expr().map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Failed to parse `{}`.", path.display()),
+        )
+    })?;
+
+    match *expr {
+        swc::Expr::Paren(paren) => Ok(*paren.expr),
+        expr => Ok(expr),
+    }
+}
+
+fn property<'a>(object: &'a swc::ObjectLit, key: &str) -> Option<&'a swc::Expr> {
+    object
+        .props
+        .iter()
+        .find_map(|prop| match prop.as_prop()?.as_key_value()? {
+            swc::KeyValueProp {
+                key: swc::PropName::Str(str),
+                value,
+            } if str.value.as_str() == key => Some(&**value),
+            swc::KeyValueProp {
+                key: swc::PropName::Ident(ident),
+                value,
+            } if ident.sym.as_str() == key => Some(&**value),
+            _ => None,
+        })
+}
+
+fn string_value(expr: &swc::Expr) -> Option<String> {
+    match expr {
+        swc::Expr::Lit(swc::Lit::Str(str)) => Some(str.value.to_string()),
+        _ => None,
+    }
+}
+
+fn string_array(expr: &swc::Expr) -> Option<Vec<String>> {
+    expr.as_array()?
+        .elems
+        .iter()
+        .map(|element| string_value(&element.as_ref()?.expr))
+        .collect()
+}
+
+/// Whether a file is a TypeScript module, rather than a declaration file.
+fn is_source_file(path: &Path) -> bool {
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy())
+        .unwrap_or_default();
+
+    (name.ends_with(".ts") || name.ends_with(".tsx")) && !name.ends_with(".d.ts")
+}
+
+/// A path relative to a directory with `/` separators, like the patterns of `include` and `exclude`.
+fn relative_pattern_path(dir: &Path, path: &Path) -> String {
+    path.strip_prefix(dir)
+        .unwrap_or(path)
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Whether a path matches a pattern of `include` or `exclude`, where `*` matches any characters in a path segment,
+/// `?` matches one and `**` matches any number of directories. Patterns without wildcards also match everything in
+/// the directory they name.
+fn matches_pattern(pattern: &str, path: &str) -> bool {
+    let pattern = pattern.trim_start_matches("./").trim_end_matches('/');
+
+    if !pattern.contains(['*', '?']) {
+        return path == pattern || path.starts_with(&format!("{}/", pattern));
+    }
+
+    let pattern: Vec<&str> = pattern.split('/').collect();
+    let path: Vec<&str> = path.split('/').collect();
+
+    matches_segments(&pattern, &path)
+}
+
+fn matches_segments(pattern: &[&str], path: &[&str]) -> bool {
+    match (pattern.first(), path.first()) {
+        (None, None) => true,
+        (Some(&"**"), _) => {
+            matches_segments(&pattern[1..], path)
+                || (!path.is_empty() && matches_segments(pattern, &path[1..]))
+        }
+        (Some(segment), Some(name)) => {
+            matches_segment(segment.as_bytes(), name.as_bytes())
+                && matches_segments(&pattern[1..], &path[1..])
+        }
+        _ => false,
+    }
+}
+
+fn matches_segment(pattern: &[u8], name: &[u8]) -> bool {
+    match (pattern.first(), name.first()) {
+        (None, None) => true,
+        (Some(b'*'), _) => {
+            matches_segment(&pattern[1..], name)
+                || (!name.is_empty() && matches_segment(pattern, &name[1..]))
+        }
+        (Some(b'?'), Some(_)) => matches_segment(&pattern[1..], &name[1..]),
+        (Some(expected), Some(char)) => {
+            expected == char && matches_segment(&pattern[1..], &name[1..])
+        }
+        _ => false,
+    }
+}
+
+/// Writes the transpiled modules of a graph as a Cargo crate, with a `Cargo.toml` that depends on ts-std by path and
+/// the modules in `src`, where the crate root is `main.rs` for a binary and `lib.rs` for a library.
+///
+/// Files are only written if their content changed, so transpiling a project again doesn't rebuild the crate. Other
+/// files in `src` are removed, like modules of deleted files or the crate root of the other crate type, in case the
+/// project changed from one to the other.
+pub fn write_crate(
+    graph: &ModuleGraph,
+    out_dir: &Path,
+    package_name: &str,
+    ts_std_dir: &Path,
+    options: &TranspileOptions,
+) -> Result<(), io::Error> {
+    let src_dir = out_dir.join("src");
+    let root_name = match graph.crate_type {
+        CrateType::Binary => "main.rs",
+        CrateType::Library => "lib.rs",
+    };
+
+    fs::create_dir_all(&src_dir)?;
+
+    let ts_std_dir = relative_path(&out_dir.canonicalize()?, &ts_std_dir.canonicalize()?);
+
+    write_if_changed(
+        &out_dir.join("Cargo.toml"),
+        &generate_cargo_toml(package_name, &ts_std_dir),
+    )?;
+
+    let mut written = HashSet::new();
+
+    for (module_path, file) in transpile_module_graph(graph, options) {
+        let path = module_file(&src_dir, &module_path, root_name);
+
+        write_if_changed(&path, &prettyplease::unparse(&file))?;
+        written.insert(path);
+    }
+
+    remove_other_files(&src_dir, &written)?;
+
+    Ok(())
+}
+
+/// Removes the files in a directory and its subdirectories that aren't in `keep`, and the directories that are empty
+/// afterwards.
+fn remove_other_files(dir: &Path, keep: &HashSet<PathBuf>) -> Result<(), io::Error> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+
+        if path.is_dir() {
+            remove_other_files(&path, keep)?;
+
+            if fs::read_dir(&path)?.next().is_none() {
+                fs::remove_dir(&path)?;
+            }
+        } else if !keep.contains(&path) {
+            fs::remove_file(&path)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// The file of a module in a directory, e.g. `shapes/circle.rs`, where the crate root is the file named `root_name`.
+pub fn module_file(dir: &Path, module_path: &[String], root_name: &str) -> PathBuf {
+    match module_path.split_last() {
+        Some((name, dirs)) => dirs
+            .iter()
+            .fold(dir.to_path_buf(), |dir, name| dir.join(name))
+            .join(format!("{}.rs", name)),
+        None => dir.join(root_name),
+    }
+}
+
+pub fn write_if_changed(path: &Path, content: &str) -> Result<(), io::Error> {
+    if fs::read_to_string(path).is_ok_and(|existing| existing == content) {
+        return Ok(());
+    }
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    fs::write(path, content)
+}
+
+fn generate_cargo_toml(package_name: &str, ts_std_dir: &str) -> String {
+    format!(
+        "[package]\n\
+         name = \"{}\"\n\
+         version = \"0.1.0\"\n\
+         edition = \"2021\"\n\
+         publish = false\n\
+         \n\
+         [dependencies]\n\
+         ts-std = {{ path = \"{}\" }}\n",
+        package_name, ts_std_dir
+    )
+}
+
+/// The path from one absolute directory to another with `/` separators, e.g. `../../ts-std`.
+fn relative_path(from: &Path, to: &Path) -> String {
+    let common = from
+        .components()
+        .zip(to.components())
+        .take_while(|(from, to)| from == to)
+        .count();
+
+    from.components()
+        .skip(common)
+        .map(|_| "..".to_string())
+        .chain(
+            to.components()
+                .skip(common)
+                .map(|component| component.as_os_str().to_string_lossy().to_string()),
+        )
+        .collect::<Vec<_>>()
+        .join("/")
+}
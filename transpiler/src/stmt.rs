@@ -1,20 +1,26 @@
-use std::mem;
-
 use swc_ecma_ast as swc;
 use syn::{punctuated::Punctuated, *};
 
 use crate::{
-    decl::{transpile_decl, transpile_var},
-    expr::transpile_expr,
+    analysis::{find_idents, find_modifications, integer_lit, root_ident},
+    context::Context,
+    decl::{declare_functions, transpile_decl, transpile_var},
+    expr::{
+        cast_number, generate_element_iter, generate_path_call, static_condition, transpile_bin_op,
+        transpile_condition, transpile_expr, transpile_member, transpile_number,
+        transpile_number_lit, transpile_return_value,
+    },
+    generator::unique_name,
+    types::{infer_type, integer_magnitude, NumberType, ValueType},
     util::{dummy_span, ExprOrStmt},
 };
 
-pub fn transpile_stmt(stmt: swc::Stmt) -> Vec<ExprOrStmt> {
+pub fn transpile_stmt(ctx: &mut Context, stmt: swc::Stmt) -> Vec<ExprOrStmt> {
     if stmt.is_block() {
         vec![ExprOrStmt::Expr(Expr::Block(ExprBlock {
             attrs: vec![],
             label: None,
-            block: transpile_block(stmt.block().expect("Stmt is Block.")),
+            block: transpile_block(ctx, stmt.block().expect("Stmt is Block.")),
         }))]
     } else if stmt.is_empty() {
         todo!("stmt empty")
@@ -24,6 +30,7 @@ pub fn transpile_stmt(stmt: swc::Stmt) -> Vec<ExprOrStmt> {
         todo!("stmt with")
     } else if stmt.is_return_stmt() {
         vec![ExprOrStmt::Stmt(transpile_return(
+            ctx,
             stmt.return_stmt().expect("Stmt is Return."),
         ))]
     } else if stmt.is_labeled() {
@@ -37,9 +44,10 @@ pub fn transpile_stmt(stmt: swc::Stmt) -> Vec<ExprOrStmt> {
             stmt.continue_stmt().expect("Stmt is Continue."),
         ))]
     } else if stmt.is_if_stmt() {
-        vec![ExprOrStmt::Expr(transpile_if(
-            stmt.if_stmt().expect("Stmt is If."),
-        ))]
+        transpile_if(ctx, stmt.if_stmt().expect("Stmt is If."))
+            .map(ExprOrStmt::Expr)
+            .into_iter()
+            .collect()
     } else if stmt.is_switch() {
         todo!("stmt switch")
     } else if stmt.is_throw() {
@@ -48,41 +56,134 @@ pub fn transpile_stmt(stmt: swc::Stmt) -> Vec<ExprOrStmt> {
         todo!("stmt try")
     } else if stmt.is_while_stmt() {
         vec![ExprOrStmt::Stmt(transpile_while(
+            ctx,
             stmt.while_stmt().expect("Stmt is While."),
         ))]
     } else if stmt.is_do_while() {
         vec![ExprOrStmt::Stmt(transpile_do_while(
+            ctx,
             stmt.do_while().expect("Stmt is DoWhile."),
         ))]
     } else if stmt.is_for_stmt() {
-        transpile_for(stmt.for_stmt().expect("Stmt is For."))
+        transpile_for(ctx, stmt.for_stmt().expect("Stmt is For."))
             .into_iter()
             .map(ExprOrStmt::Stmt)
             .collect()
     } else if stmt.is_for_in() {
-        todo!("stmt for in")
+        vec![ExprOrStmt::Stmt(transpile_for_in(
+            ctx,
+            stmt.for_in().expect("Stmt is ForIn."),
+        ))]
+    } else if stmt.as_for_of().is_some_and(|for_of| for_of.is_await) {
+        transpile_for_await(ctx, stmt.for_of().expect("Stmt is ForOf."))
+            .into_iter()
+            .map(ExprOrStmt::Stmt)
+            .collect()
     } else if stmt.is_for_of() {
-        todo!("stmt for of")
+        vec![ExprOrStmt::Stmt(transpile_for_of(
+            ctx,
+            stmt.for_of().expect("Stmt is ForOf."),
+        ))]
     } else if stmt.is_decl() {
-        transpile_decl(stmt.decl().expect("Stmt is Decl."))
+        transpile_decl(ctx, stmt.decl().expect("Stmt is Decl."))
     } else if stmt.is_expr() {
-        vec![ExprOrStmt::Stmt(transpile_expr_to_stmt(transpile_expr(
-            *stmt.expr().expect("Stmt is Expr.").expr,
-        )))]
+        transpile_expr_stmt(ctx, *stmt.expr().expect("Stmt is Expr.").expr)
+            .into_iter()
+            .map(ExprOrStmt::Stmt)
+            .collect()
     } else {
         unreachable!("Unknown Stmt.")
     }
 }
 
-pub fn transpile_block(block: swc::BlockStmt) -> Block {
-    Block {
-        brace_token: token::Brace(dummy_span()),
-        stmts: block
-            .stmts
-            .clone()
+pub fn transpile_block(ctx: &mut Context, block: swc::BlockStmt) -> Block {
+    ctx.with_scope(|ctx| {
+        declare_functions(ctx, &block.stmts);
+
+        Block {
+            brace_token: token::Brace(dummy_span()),
+            stmts: transpile_stmts(ctx, block.stmts),
+        }
+    })
+}
+
+/// Transpiles the statements of a block, leaving out the statements after one that always leaves the block, which
+/// would be unreachable code in Rust, e.g. after a branch that `static_condition` decided.
+///
+/// Function declarations are kept, since they are hoisted.
+pub fn transpile_stmts(ctx: &mut Context, stmts: Vec<swc::Stmt>) -> Vec<Stmt> {
+    let mut transpiled = vec![];
+    let mut diverged = false;
+
+    for stmt in stmts {
+        for stmt in transpile_stmt_to_stmts(ctx, stmt) {
+            if !diverged || matches!(stmt, Stmt::Item(_)) {
+                diverged |= is_diverging(&stmt);
+                transpiled.push(stmt);
+            }
+        }
+    }
+
+    transpiled
+}
+
+/// Whether a statement always returns from the function or leaves the loop it is in.
+fn is_diverging(stmt: &Stmt) -> bool {
+    match stmt {
+        Stmt::Expr(expr, _) => is_diverging_expr(expr),
+        _ => false,
+    }
+}
+
+fn is_diverging_expr(expr: &Expr) -> bool {
+    match expr {
+        Expr::Return(_) | Expr::Break(_) | Expr::Continue(_) => true,
+        Expr::Block(block) => block.block.stmts.iter().any(is_diverging),
+        Expr::If(if_expr) => {
+            if_expr.then_branch.stmts.iter().any(is_diverging)
+                && if_expr
+                    .else_branch
+                    .as_ref()
+                    .is_some_and(|(_, else_branch)| is_diverging_expr(else_branch))
+        }
+        _ => false,
+    }
+}
+
+/// Transpiles an expression statement, where `a && b()` and `a || b()` are used for control flow and become `if`s,
+/// and the expressions of `a, b` become separate statements.
+pub fn transpile_expr_stmt(ctx: &mut Context, expr: swc::Expr) -> Vec<Stmt> {
+    match expr {
+        swc::Expr::Seq(seq) => seq
+            .exprs
             .into_iter()
-            .flat_map(transpile_stmt_to_stmts)
+            .flat_map(|expr| transpile_expr_stmt(ctx, *expr))
             .collect(),
+        swc::Expr::Bin(bin)
+            if matches!(bin.op, swc::BinaryOp::LogicalAnd | swc::BinaryOp::LogicalOr) =>
+        {
+            let cond = transpile_condition(ctx, *bin.left);
+            let cond = match bin.op {
+                swc::BinaryOp::LogicalOr => Expr::Unary(ExprUnary {
+                    attrs: vec![],
+                    op: UnOp::Not(token::Not(dummy_span())),
+                    expr: Box::new(cond),
+                }),
+                _ => cond,
+            };
+
+            vec![transpile_expr_to_stmt(Expr::If(ExprIf {
+                attrs: vec![],
+                if_token: token::If(dummy_span()),
+                cond: Box::new(cond),
+                then_branch: Block {
+                    brace_token: token::Brace(dummy_span()),
+                    stmts: transpile_expr_stmt(ctx, *bin.right),
+                },
+                else_branch: None,
+            }))]
+        }
+        expr => vec![transpile_expr_to_stmt(transpile_expr(ctx, expr))],
     }
 }
 
@@ -90,8 +191,8 @@ pub fn transpile_expr_to_stmt(expr: Expr) -> Stmt {
     Stmt::Expr(expr, Some(token::Semi(dummy_span())))
 }
 
-pub fn transpile_stmt_to_stmts(stmt: swc::Stmt) -> Vec<Stmt> {
-    transpile_stmt(stmt)
+pub fn transpile_stmt_to_stmts(ctx: &mut Context, stmt: swc::Stmt) -> Vec<Stmt> {
+    transpile_stmt(ctx, stmt)
         .into_iter()
         .map(|expr_or_stmt| match expr_or_stmt {
             ExprOrStmt::Expr(expr) => transpile_expr_to_stmt(expr),
@@ -100,8 +201,8 @@ pub fn transpile_stmt_to_stmts(stmt: swc::Stmt) -> Vec<Stmt> {
         .collect()
 }
 
-pub fn transpile_stmt_to_expr(stmt: swc::Stmt) -> Expr {
-    let expr_or_stmts = transpile_stmt(stmt);
+pub fn transpile_stmt_to_expr(ctx: &mut Context, stmt: swc::Stmt) -> Expr {
+    let expr_or_stmts = transpile_stmt(ctx, stmt);
 
     match &expr_or_stmts[..] {
         [] => panic!("Expected expression, but got nothing."),
@@ -111,22 +212,26 @@ pub fn transpile_stmt_to_expr(stmt: swc::Stmt) -> Expr {
     }
 }
 
-pub fn transpile_stmt_to_block(stmt: swc::Stmt) -> Block {
+pub fn transpile_stmt_to_block(ctx: &mut Context, stmt: swc::Stmt) -> Block {
     if stmt.is_block() {
-        transpile_block(stmt.block().expect("Stmt is Block."))
+        transpile_block(ctx, stmt.block().expect("Stmt is Block."))
     } else {
-        Block {
+        ctx.with_scope(|ctx| Block {
             brace_token: token::Brace(dummy_span()),
-            stmts: transpile_stmt_to_stmts(stmt),
-        }
+            stmts: transpile_stmts(ctx, vec![stmt]),
+        })
     }
 }
 
-pub fn transpile_return(return_stmt: swc::ReturnStmt) -> Stmt {
+pub fn transpile_return(ctx: &mut Context, return_stmt: swc::ReturnStmt) -> Stmt {
     transpile_expr_to_stmt(Expr::Return(ExprReturn {
         attrs: vec![],
         return_token: token::Return(dummy_span()),
-        expr: return_stmt.arg.map(|expr| Box::new(transpile_expr(*expr))),
+        expr: return_stmt.arg.map(|expr| {
+            let return_type = ctx.return_type().cloned();
+
+            Box::new(transpile_return_value(ctx, *expr, return_type.as_ref()))
+        }),
     }))
 }
 
@@ -155,33 +260,56 @@ pub fn transpile_continue(continue_stmt: swc::ContinueStmt) -> Stmt {
     }))
 }
 
-pub fn transpile_if(if_stmt: swc::IfStmt) -> Expr {
-    Expr::If(ExprIf {
+/// Transpiles an `if` statement, leaving out the branches that `static_condition` rules out.
+///
+/// `None` if no branch is left.
+pub fn transpile_if(ctx: &mut Context, if_stmt: swc::IfStmt) -> Option<Expr> {
+    match static_condition(ctx, &if_stmt.test) {
+        Some(true) => return Some(transpile_branch(ctx, *if_stmt.cons)),
+        Some(false) => return if_stmt.alt.and_then(|alt| transpile_else_if(ctx, *alt)),
+        None => {}
+    }
+
+    Some(Expr::If(ExprIf {
         attrs: vec![],
         if_token: token::If(dummy_span()),
-        cond: Box::new(transpile_expr(*if_stmt.test)),
-        then_branch: transpile_stmt_to_block(*if_stmt.cons),
-        else_branch: if_stmt.alt.map(|alt| {
-            (
-                token::Else(dummy_span()),
-                Box::new(transpile_stmt_to_expr(*alt)),
-            )
-        }),
+        cond: Box::new(transpile_condition(ctx, *if_stmt.test)),
+        then_branch: transpile_stmt_to_block(ctx, *if_stmt.cons),
+        else_branch: if_stmt
+            .alt
+            .and_then(|alt| transpile_else_if(ctx, *alt))
+            .map(|alt| (token::Else(dummy_span()), Box::new(alt))),
+    }))
+}
+
+/// Transpiles the `else` branch of an `if` statement, which may be another `if`.
+fn transpile_else_if(ctx: &mut Context, alt: swc::Stmt) -> Option<Expr> {
+    match alt {
+        swc::Stmt::If(if_stmt) => transpile_if(ctx, if_stmt),
+        alt => Some(transpile_branch(ctx, alt)),
+    }
+}
+
+fn transpile_branch(ctx: &mut Context, stmt: swc::Stmt) -> Expr {
+    Expr::Block(ExprBlock {
+        attrs: vec![],
+        label: None,
+        block: transpile_stmt_to_block(ctx, stmt),
     })
 }
 
-pub fn transpile_while(when: swc::WhileStmt) -> Stmt {
+pub fn transpile_while(ctx: &mut Context, when: swc::WhileStmt) -> Stmt {
     transpile_expr_to_stmt(Expr::While(ExprWhile {
         attrs: vec![],
         label: None,
         while_token: token::While(dummy_span()),
-        cond: Box::new(transpile_expr(*when.test)),
-        body: transpile_stmt_to_block(*when.body),
+        cond: Box::new(transpile_condition(ctx, *when.test)),
+        body: transpile_stmt_to_block(ctx, *when.body),
     }))
 }
 
-pub fn transpile_do_while(when: swc::DoWhileStmt) -> Stmt {
-    let mut body = transpile_stmt_to_block(*when.body);
+pub fn transpile_do_while(ctx: &mut Context, when: swc::DoWhileStmt) -> Stmt {
+    let mut body = transpile_stmt_to_block(ctx, *when.body);
 
     body.stmts.push(Stmt::Expr(
         Expr::If(ExprIf {
@@ -193,7 +321,7 @@ pub fn transpile_do_while(when: swc::DoWhileStmt) -> Stmt {
                 expr: Box::new(Expr::Paren(ExprParen {
                     attrs: vec![],
                     paren_token: token::Paren(dummy_span()),
-                    expr: Box::new(transpile_expr(*when.test)),
+                    expr: Box::new(transpile_condition(ctx, *when.test)),
                 })),
             })),
             then_branch: Block {
@@ -221,26 +349,61 @@ pub fn transpile_do_while(when: swc::DoWhileStmt) -> Stmt {
     }))
 }
 
-pub fn transpile_for(for_stmt: swc::ForStmt) -> Vec<Stmt> {
-    if let Some(stmt) = transpile_for_range(for_stmt.clone()) {
+pub fn transpile_for(ctx: &mut Context, for_stmt: swc::ForStmt) -> Vec<Stmt> {
+    if let Some(stmt) = transpile_for_range(ctx, for_stmt.clone()) {
         return vec![stmt];
     }
 
-    let mut body = transpile_stmt_to_block(*for_stmt.body);
+    let block_scoped = for_stmt
+        .init
+        .as_ref()
+        .and_then(|init| init.as_var_decl())
+        .is_some_and(|var| var.kind != swc::VarDeclKind::Var);
+
+    if block_scoped {
+        // Keep `let` declarations scoped to the loop, like in TypeScript.
+        let stmts = ctx.with_scope(|ctx| transpile_for_loop(ctx, for_stmt));
+
+        vec![transpile_expr_to_stmt(Expr::Block(ExprBlock {
+            attrs: vec![],
+            label: None,
+            block: Block {
+                brace_token: token::Brace(dummy_span()),
+                stmts,
+            },
+        }))]
+    } else {
+        transpile_for_loop(ctx, for_stmt)
+    }
+}
+
+fn transpile_for_loop(ctx: &mut Context, for_stmt: swc::ForStmt) -> Vec<Stmt> {
+    let mut stmts = if let Some(init) = for_stmt.init {
+        if init.is_var_decl() {
+            transpile_var(ctx, *init.var_decl().expect("VarDeclOrExpr is VarDecl."))
+        } else if init.is_expr() {
+            transpile_expr_stmt(ctx, *init.expr().expect("VarDeclOrExpr is Expr."))
+        } else {
+            unreachable!("Unknown VarDeclOrExpr.")
+        }
+    } else {
+        vec![]
+    };
+
+    let cond = for_stmt.test.map(|test| transpile_condition(ctx, *test));
+
+    let mut body = transpile_stmt_to_block(ctx, *for_stmt.body);
 
     if let Some(update) = for_stmt.update {
-        body.stmts.push(Stmt::Expr(
-            transpile_expr(*update),
-            Some(token::Semi(dummy_span())),
-        ))
+        body.stmts.extend(transpile_expr_stmt(ctx, *update));
     }
 
-    let stmt = transpile_expr_to_stmt(if let Some(test) = for_stmt.test {
+    stmts.push(transpile_expr_to_stmt(if let Some(cond) = cond {
         Expr::While(ExprWhile {
             attrs: vec![],
             label: None,
             while_token: token::While(dummy_span()),
-            cond: Box::new(transpile_expr(*test)),
+            cond: Box::new(cond),
             body,
         })
     } else {
@@ -250,206 +413,251 @@ pub fn transpile_for(for_stmt: swc::ForStmt) -> Vec<Stmt> {
             loop_token: token::Loop(dummy_span()),
             body,
         })
-    });
+    }));
 
-    if let Some(init) = for_stmt.init {
-        if init.is_var_decl() {
-            transpile_var(*init.var_decl().expect("VarDeclOrExpr is VarDecl."))
-                .into_iter()
-                .map(transpile_expr_to_stmt)
-                .chain(vec![stmt])
-                .collect()
-        } else if init.is_expr() {
-            vec![
-                transpile_expr_to_stmt(transpile_expr(
-                    *init.expr().expect("VarDeclOrExpr is Expr."),
-                )),
-                stmt,
-            ]
-        } else {
-            unreachable!("Unknown VarDeclOrExpr.")
-        }
-    } else {
-        vec![stmt]
-    }
+    stmts
 }
 
-fn transpile_for_range(for_stmt: swc::ForStmt) -> Option<Stmt> {
-    let mut range_ident = "".into();
-    let mut range_start = 0;
-    let mut range_end = 0;
-    let mut range_step = 0;
-    let mut range_inclusive = false;
-
-    if let Some(init) = for_stmt.init {
-        if !init.is_var_decl() {
-            return None;
-        }
-
-        let var = init.var_decl().expect("VarDeclOrExpr is Expr.");
+pub fn transpile_for_in(ctx: &mut Context, for_in: swc::ForInStmt) -> Stmt {
+    let (key, pat) = if for_in.left.is_var_decl() {
+        let var = for_in.left.var_decl().expect("ForHead is VarDecl.");
         if var.decls.len() != 1 {
-            return None;
+            panic!("Expected a single declarator in for in.");
         }
 
         let decl = var.decls[0].clone();
         if !decl.name.is_ident() {
-            return None;
+            todo!("for in declarator non-ident")
         }
 
-        if let Some(init) = decl.init {
-            if !init.is_lit() {
-                return None;
-            }
+        let key = decl.name.ident().expect("Pat is Ident.").id.sym.to_string();
+        let pat = Pat::Ident(PatIdent {
+            attrs: vec![],
+            by_ref: None,
+            mutability: match var.kind {
+                swc::VarDeclKind::Var => Some(token::Mut(dummy_span())),
+                swc::VarDeclKind::Let => Some(token::Mut(dummy_span())),
+                swc::VarDeclKind::Const => None,
+            },
+            ident: Ident::new(&key, dummy_span()),
+            subpat: None,
+        });
 
-            let lit = init.lit().expect("Expr is Lit.");
-            match lit {
-                swc::Lit::Num(num) => {
-                    range_ident = decl
-                        .name
-                        .ident()
-                        .expect("VarDeclarator is Ident.")
-                        .id
-                        .sym
-                        .as_str()
-                        .to_string();
-
-                    if num.value.trunc() != num.value {
-                        return None;
-                    }
-
-                    range_start = num.value as i64;
-                }
-                _ => return None,
-            }
-        }
+        (key, pat)
+    } else if for_in.left.is_using_decl() {
+        todo!("for in using decl")
+    } else if for_in.left.is_pat() {
+        todo!("for in pat")
     } else {
-        return None;
-    }
-
-    if let Some(test) = for_stmt.test {
-        if !test.is_bin() {
-            return None;
-        }
+        unreachable!("Unknown ForHead.")
+    };
 
-        let bin = test.bin().expect("Expr is Bin.");
-        if !bin.left.is_ident() || !bin.right.is_lit() {
-            return None;
-        }
-
-        let ident = bin.left.ident().expect("Expr is Ident.");
-        if ident.sym.as_str() != range_ident {
-            return None;
-        }
+    let object = match transpile_expr(ctx, *for_in.right) {
+        expr @ (Expr::Path(_) | Expr::Field(_) | Expr::MethodCall(_) | Expr::Paren(_)) => expr,
+        expr => Expr::Paren(ExprParen {
+            attrs: vec![],
+            paren_token: token::Paren(dummy_span()),
+            expr: Box::new(expr),
+        }),
+    };
 
-        let lit = bin.right.lit().expect("Expr is Lit.");
-        match lit {
-            swc::Lit::Num(num) => {
-                if num.value.trunc() != num.value {
-                    return None;
-                }
+    transpile_expr_to_stmt(Expr::ForLoop(ExprForLoop {
+        attrs: vec![],
+        label: None,
+        for_token: token::For(dummy_span()),
+        pat: Box::new(pat),
+        in_token: token::In(dummy_span()),
+        expr: Box::new(Expr::MethodCall(ExprMethodCall {
+            attrs: vec![],
+            receiver: Box::new(object),
+            dot_token: token::Dot(dummy_span()),
+            method: Ident::new("own_keys", dummy_span()),
+            turbofish: None,
+            paren_token: token::Paren(dummy_span()),
+            args: Punctuated::new(),
+        })),
+        body: ctx.with_scope(|ctx| {
+            ctx.declare(&key, ValueType::String);
+            ctx.declare_string(&key, true);
+            transpile_stmt_to_block(ctx, *for_in.body)
+        }),
+    }))
+}
 
-                let value = num.value as i64;
-                match bin.op {
-                    swc::BinaryOp::Lt => {
-                        range_end = value;
-                        range_inclusive = false;
-                    }
-                    swc::BinaryOp::LtEq => {
-                        range_end = value;
-                        range_inclusive = true;
-                    }
-                    swc::BinaryOp::Gt => {
-                        range_end = value;
-                        range_inclusive = false;
-                    }
-                    swc::BinaryOp::GtEq => {
-                        range_end = value;
-                        range_inclusive = true;
-                    }
-                    _ => return None,
-                };
-            }
-            _ => return None,
+/// Transpiles `for...of` over an array or a generator to a `for` loop over its elements, see `transpile_for_await`
+/// for async generators.
+///
+/// Arrays are iterated by reference with their elements borrowed like in array methods, while generators are
+/// consumed by the loop.
+pub fn transpile_for_of(ctx: &mut Context, for_of: swc::ForOfStmt) -> Stmt {
+    let (ident, kind) = for_of_binding(&for_of.left);
+    // Elements of arrays are borrowed, while generators yield owned values.
+    let (iter, element_type, owned) = match infer_type(ctx, &for_of.right) {
+        ValueType::Array(element_type) => (
+            generate_element_iter(transpile_expr(ctx, *for_of.right), &element_type),
+            *element_type,
+            false,
+        ),
+        ValueType::Generator(yield_type, _) => {
+            (transpile_expr(ctx, *for_of.right), *yield_type, true)
         }
-    }
+        ValueType::String => todo!("for of string"),
+        _ => todo!("for of unknown iterable"),
+    };
+    let pat = Pat::Ident(PatIdent {
+        attrs: vec![],
+        by_ref: None,
+        mutability: (kind != swc::VarDeclKind::Const && ctx.is_reassigned(&ident))
+            .then(|| token::Mut(dummy_span())),
+        ident: Ident::new(&ident, dummy_span()),
+        subpat: None,
+    });
 
-    if let Some(update) = for_stmt.update {
-        if update.is_update() {
-            let update = update.update().expect("Expr is Update.");
+    transpile_expr_to_stmt(Expr::ForLoop(ExprForLoop {
+        attrs: vec![],
+        label: None,
+        for_token: token::For(dummy_span()),
+        pat: Box::new(pat),
+        in_token: token::In(dummy_span()),
+        expr: Box::new(iter),
+        body: ctx.with_scope(|ctx| {
+            ctx.declare_string(&ident, owned && element_type == ValueType::String);
+            ctx.declare_closure(&ident, false);
+            ctx.declare_array_length(&ident, None);
+            ctx.declare(&ident, element_type);
+            transpile_stmt_to_block(ctx, *for_of.body)
+        }),
+    }))
+}
 
-            if !update.arg.is_ident() {
-                return None;
-            }
+/// Transpiles `for await...of` over an async generator to a `while let` loop awaiting its `next` value, e.g.
+/// `while let Some(chunk) = stream.next().await { .. }`.
+///
+/// The generator is closed after the loop, so leaving it with `break` finishes the generator like in TypeScript,
+/// while closing a generator that is already done does nothing. Generators that aren't bindings are bound in a block
+/// around the loop first.
+pub fn transpile_for_await(ctx: &mut Context, for_of: swc::ForOfStmt) -> Vec<Stmt> {
+    let (ident, kind) = for_of_binding(&for_of.left);
+    let yield_type = match infer_type(ctx, &for_of.right) {
+        ValueType::AsyncGenerator(yield_type, _) => *yield_type,
+        _ => todo!("for await of something other than an async generator"),
+    };
+    let binding = for_of.right.as_ident().map(|ident| ident.sym.to_string());
+    let generator = Ident::new(
+        &binding
+            .clone()
+            .unwrap_or_else(|| unique_name(&find_idents(&for_of), "generator")),
+        dummy_span(),
+    );
+    let init = binding
+        .is_none()
+        .then(|| transpile_expr(ctx, *for_of.right));
+    let pat = Pat::Ident(PatIdent {
+        attrs: vec![],
+        by_ref: None,
+        mutability: (kind != swc::VarDeclKind::Const && ctx.is_reassigned(&ident))
+            .then(|| token::Mut(dummy_span())),
+        ident: Ident::new(&ident, dummy_span()),
+        subpat: None,
+    });
+    let body = ctx.with_scope(|ctx| {
+        ctx.declare_string(&ident, yield_type == ValueType::String);
+        ctx.declare_closure(&ident, false);
+        ctx.declare_array_length(&ident, None);
+        ctx.declare(&ident, yield_type);
+        transpile_stmt_to_block(ctx, *for_of.body)
+    });
+    let stmts: Vec<Stmt> = vec![
+        parse_quote! {
+            while let Some(#pat) = #generator.next().await #body
+        },
+        parse_quote! { #generator.close(); },
+    ];
 
-            let ident = update.arg.ident().expect("Expr is Ident.");
-            if ident.sym.as_str() != range_ident {
-                return None;
+    match init {
+        Some(init) => vec![parse_quote! {
+            {
+                let #generator = #init;
+                #(#stmts)*
             }
+        }],
+        None => stmts,
+    }
+}
 
-            range_step = match update.op {
-                swc::UpdateOp::PlusPlus => 1,
-                swc::UpdateOp::MinusMinus => -1,
-            }
-        } else if update.is_assign() {
-            let assign = update.assign().expect("Expr is Assign.");
+/// The binding that a `for...of` loop declares for each element, with its kind.
+pub fn for_of_binding(head: &swc::ForHead) -> (String, swc::VarDeclKind) {
+    match head {
+        swc::ForHead::VarDecl(var) => match &var.decls[..] {
+            [swc::VarDeclarator {
+                name: swc::Pat::Ident(ident),
+                ..
+            }] => (ident.id.sym.to_string(), var.kind),
+            [_] => todo!("for of declarator non-ident"),
+            _ => panic!("Expected a single declarator in for of."),
+        },
+        swc::ForHead::UsingDecl(_) => todo!("for of using decl"),
+        swc::ForHead::Pat(_) => todo!("for of pat"),
+    }
+}
 
-            if !assign.left.is_simple() || !assign.right.is_lit() {
-                return None;
-            }
+fn transpile_for_range(ctx: &mut Context, for_stmt: swc::ForStmt) -> Option<Stmt> {
+    let range = match_for_range(ctx, &for_stmt)?;
+    let shape = range_shape(&range)?;
 
-            let simple = assign.left.simple().expect("AssignTarget is Simple.");
-            if !simple.is_ident() {
-                return None;
-            }
+    let step = range.step.unsigned_abs();
 
-            let ident = simple.ident().expect("Expr is Ident.");
-            if ident.sym.as_str() != range_ident {
-                return None;
-            }
+    let (lower, upper, inclusive) = match shape {
+        RangeShape::Ascending { inclusive } => (
+            RangeBound::Floor(range.start.clone()),
+            if inclusive {
+                RangeBound::Floor(range.end.clone())
+            } else {
+                RangeBound::Ceil(range.end.clone())
+            },
+            inclusive,
+        ),
+        RangeShape::Descending { inclusive } => {
+            let lower = if inclusive {
+                RangeBound::Ceil(range.end.clone())
+            } else {
+                RangeBound::Above(range.end.clone())
+            };
 
-            let lit = assign.right.lit().expect("Expr is Lit.");
-            match lit {
-                swc::Lit::Num(num) => {
-                    if num.value.trunc() != num.value {
-                        return None;
-                    }
-
-                    let value = num.value as i64;
-                    range_step = match assign.op {
-                        swc::AssignOp::AddAssign => value,
-                        swc::AssignOp::SubAssign => -value,
-                        _ => return None,
-                    };
-                }
-                _ => return None,
+            match range_exclusive_upper(&range.start) {
+                // `for (let i = n - 1; i >= 0; i--)` iterates `(0..n).rev()`, which also avoids `n - 1` underflowing.
+                Some(upper) => (lower, RangeBound::Floor(upper), false),
+                None => (lower, RangeBound::Floor(range.start.clone()), true),
             }
-        } else {
-            return None;
         }
-    }
+    };
 
-    if range_step < 0 {
-        mem::swap(&mut range_start, &mut range_end);
-    }
+    let number_type = range_number_type(ctx, [lower.expr(), upper.expr()]);
+    // Comparisons with NaN are false, so the loop doesn't run, but NaN would be cast to a zero bound.
+    let nan_guard = (integer_lit(&range.end).is_none()
+        && infer_type(ctx, &range.end).number_type() == Some(NumberType::F64))
+    .then(|| transpile_range_number(ctx, range.end.clone(), NumberType::F64));
+    // The loop variable stays between the bounds.
+    let magnitude = integer_magnitude(ctx, lower.expr())
+        .max(integer_magnitude(ctx, upper.expr()))
+        .saturating_add(1);
+    let lower = transpile_range_bound(ctx, lower, number_type);
+    let upper = transpile_range_bound(ctx, upper, number_type);
 
     let mut expr = Expr::Range(ExprRange {
         attrs: vec![],
-        start: Some(Box::new(Expr::Lit(ExprLit {
-            attrs: vec![],
-            lit: Lit::Int(LitInt::new(&range_start.to_string(), dummy_span())),
-        }))),
-        limits: if range_inclusive {
+        start: Some(Box::new(lower)),
+        limits: if inclusive {
             RangeLimits::Closed(token::DotDotEq(dummy_span()))
         } else {
             RangeLimits::HalfOpen(token::DotDot(dummy_span()))
         },
-        end: Some(Box::new(Expr::Lit(ExprLit {
-            attrs: vec![],
-            lit: Lit::Int(LitInt::new(&range_end.to_string(), dummy_span())),
-        }))),
+        end: Some(Box::new(upper)),
     });
 
-    if range_step.abs() != 1 || range_step < 0 {
+    let descending = matches!(shape, RangeShape::Descending { .. });
+
+    if step != 1 || descending {
         expr = Expr::Paren(ExprParen {
             attrs: vec![],
             paren_token: token::Paren(dummy_span()),
@@ -457,7 +665,7 @@ fn transpile_for_range(for_stmt: swc::ForStmt) -> Option<Stmt> {
         });
     }
 
-    if range_step < 0 {
+    if descending {
         expr = Expr::MethodCall(ExprMethodCall {
             attrs: vec![],
             receiver: Box::new(expr),
@@ -469,7 +677,7 @@ fn transpile_for_range(for_stmt: swc::ForStmt) -> Option<Stmt> {
         })
     }
 
-    if range_step.abs() != 1 {
+    if step != 1 {
         expr = Expr::MethodCall(ExprMethodCall {
             attrs: vec![],
             receiver: Box::new(expr),
@@ -479,12 +687,12 @@ fn transpile_for_range(for_stmt: swc::ForStmt) -> Option<Stmt> {
             paren_token: token::Paren(dummy_span()),
             args: Punctuated::from_iter(vec![Expr::Lit(ExprLit {
                 attrs: vec![],
-                lit: Lit::Int(LitInt::new(&range_step.abs().to_string(), dummy_span())),
+                lit: Lit::Int(LitInt::new(&step.to_string(), dummy_span())),
             })]),
         })
     }
 
-    Some(transpile_expr_to_stmt(Expr::ForLoop(ExprForLoop {
+    let for_loop = Expr::ForLoop(ExprForLoop {
         attrs: vec![],
         label: None,
         for_token: token::For(dummy_span()),
@@ -492,11 +700,425 @@ fn transpile_for_range(for_stmt: swc::ForStmt) -> Option<Stmt> {
             attrs: vec![],
             by_ref: None,
             mutability: None,
-            ident: Ident::new(&range_ident, dummy_span()),
+            ident: Ident::new(&range.ident, dummy_span()),
             subpat: None,
         })),
         in_token: token::In(dummy_span()),
         expr: Box::new(expr),
-        body: transpile_stmt_to_block(*for_stmt.body),
-    })))
+        body: ctx.with_scope(|ctx| {
+            ctx.declare(&range.ident, ValueType::Number(number_type));
+            ctx.declare_magnitude(&range.ident, magnitude);
+            transpile_stmt_to_block(ctx, *for_stmt.body)
+        }),
+    });
+
+    Some(transpile_expr_to_stmt(match nan_guard {
+        Some(end) => parse_quote! {
+            if !f64::is_nan(#end) {
+                #for_loop
+            }
+        },
+        None => for_loop,
+    }))
+}
+
+/// Counting loop of the form `for (let <ident> = <start>; <ident> <op> <end>; <ident> += <step>)`.
+#[derive(Clone, Debug)]
+struct ForRange {
+    ident: String,
+    start: swc::Expr,
+    op: swc::BinaryOp,
+    end: swc::Expr,
+    step: i64,
+}
+
+/// Bound of the Rust range equivalent to a [`ForRange`], rounded to an integer if it is a float.
+#[derive(Clone, Debug)]
+enum RangeBound {
+    /// The smallest integer greater than or equal to the bound.
+    Ceil(swc::Expr),
+    /// The largest integer less than or equal to the bound.
+    Floor(swc::Expr),
+    /// The smallest integer greater than the bound.
+    Above(swc::Expr),
+}
+
+impl RangeBound {
+    fn expr(&self) -> &swc::Expr {
+        match self {
+            RangeBound::Ceil(expr) | RangeBound::Floor(expr) | RangeBound::Above(expr) => expr,
+        }
+    }
+}
+
+/// Direction of the Rust range equivalent to a [`ForRange`].
+///
+/// An ascending range iterates `start..end` (or `start..=end`), a descending range iterates `end..=start` (or
+/// `end + 1..=start`) in reverse. In both cases `inclusive` refers to whether `end` itself is visited.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum RangeShape {
+    Ascending { inclusive: bool },
+    Descending { inclusive: bool },
+}
+
+fn match_for_range(ctx: &Context, for_stmt: &swc::ForStmt) -> Option<ForRange> {
+    let (ident, start) = match_range_init(ctx, for_stmt.init.as_ref()?)?;
+    let (op, end) = match_range_test(for_stmt.test.as_ref()?, &ident)?;
+    let step = match_range_update(for_stmt.update.as_ref()?, &ident)?;
+
+    // The test is evaluated on every iteration, so the loop variable and the end bound may only be used if the
+    // body can't change them.
+    let mut modifications = find_modifications(&*for_stmt.body);
+    // Callbacks that the body calls or passes on may assign to the bindings they capture.
+    for callee in find_idents(&*for_stmt.body) {
+        if let Some(assigned) = ctx.closure_assignments(&callee) {
+            modifications.reassigned.extend(assigned.iter().cloned());
+        }
+        if let Some(mutated) = ctx.closure_mutations(&callee) {
+            modifications.mutated.extend(mutated.iter().cloned());
+        }
+    }
+    if modifications.is_reassigned(&ident) {
+        return None;
+    }
+
+    let mut idents = vec![];
+    let mut objects = vec![];
+    if !is_range_bound(ctx, &end, &mut idents, &mut objects)
+        || idents
+            .iter()
+            .any(|end_ident| *end_ident == ident || modifications.is_reassigned(end_ident))
+        || objects
+            .iter()
+            .any(|object| *object == ident || modifications.is_modified(object))
+    {
+        return None;
+    }
+
+    Some(ForRange {
+        ident,
+        start,
+        op,
+        end,
+        step,
+    })
+}
+
+/// Matches `let <ident> = <start>`.
+fn match_range_init(ctx: &Context, init: &swc::VarDeclOrExpr) -> Option<(String, swc::Expr)> {
+    let var = init.as_var_decl()?;
+    if var.kind != swc::VarDeclKind::Let || var.decls.len() != 1 {
+        return None;
+    }
+
+    let decl = &var.decls[0];
+    let ident = decl.name.as_ident()?.id.sym.to_string();
+    let start = *decl.init.clone()?;
+
+    // The loop variable is an integer, so it can't start at a float.
+    if !is_range_bound(ctx, &start, &mut vec![], &mut vec![])
+        || (integer_lit(&start).is_none()
+            && !infer_type(ctx, &start)
+                .number_type()
+                .is_some_and(NumberType::is_integer))
+    {
+        return None;
+    }
+
+    Some((ident, start))
+}
+
+/// Matches `<ident> <op> <end>` or `<end> <op> <ident>`, normalized to the former.
+fn match_range_test(test: &swc::Expr, ident: &str) -> Option<(swc::BinaryOp, swc::Expr)> {
+    let bin = test.as_bin()?;
+
+    if is_ident_named(&bin.left, ident) {
+        Some((bin.op, *bin.right.clone()))
+    } else if is_ident_named(&bin.right, ident) {
+        let op = match bin.op {
+            swc::BinaryOp::Lt => swc::BinaryOp::Gt,
+            swc::BinaryOp::LtEq => swc::BinaryOp::GtEq,
+            swc::BinaryOp::Gt => swc::BinaryOp::Lt,
+            swc::BinaryOp::GtEq => swc::BinaryOp::LtEq,
+            op => op,
+        };
+
+        Some((op, *bin.left.clone()))
+    } else {
+        None
+    }
+}
+
+/// Matches `<ident>++`, `++<ident>`, `<ident>--`, `--<ident>`, `<ident> += <step>` and `<ident> -= <step>`.
+fn match_range_update(update: &swc::Expr, ident: &str) -> Option<i64> {
+    let step = if let Some(update) = update.as_update() {
+        if !is_ident_named(&update.arg, ident) {
+            return None;
+        }
+
+        match update.op {
+            swc::UpdateOp::PlusPlus => 1,
+            swc::UpdateOp::MinusMinus => -1,
+        }
+    } else if let Some(assign) = update.as_assign() {
+        let target = assign.left.as_simple()?.as_ident()?;
+        if target.id.sym.as_str() != ident {
+            return None;
+        }
+
+        let value = integer_lit(&assign.right)?;
+        match assign.op {
+            swc::AssignOp::AddAssign => value,
+            swc::AssignOp::SubAssign => -value,
+            _ => return None,
+        }
+    } else {
+        return None;
+    };
+
+    if step == 0 {
+        return None;
+    }
+
+    Some(step)
+}
+
+/// Determines the range equivalent to a counting loop, if any.
+///
+/// | test        | step > 0                         | step < 0                          |
+/// |-------------|----------------------------------|-----------------------------------|
+/// | `i < end`   | `start..end`                     | -                                 |
+/// | `i <= end`  | `start..=end`                    | -                                 |
+/// | `i > end`   | -                                | `(end + 1..=start).rev()`         |
+/// | `i >= end`  | -                                | `(end..=start).rev()`             |
+/// | `i != end`  | `start..end` if `end` is reached | `(end + 1..=start).rev()` if `end` is reached |
+///
+/// Combinations marked `-` either never run the body or never terminate, so they are left to the `while` loop.
+fn range_shape(range: &ForRange) -> Option<RangeShape> {
+    match (range.op, range.step > 0) {
+        (swc::BinaryOp::Lt, true) => Some(RangeShape::Ascending { inclusive: false }),
+        (swc::BinaryOp::LtEq, true) => Some(RangeShape::Ascending { inclusive: true }),
+        (swc::BinaryOp::Gt, false) => Some(RangeShape::Descending { inclusive: false }),
+        (swc::BinaryOp::GtEq, false) => Some(RangeShape::Descending { inclusive: true }),
+        (swc::BinaryOp::NotEq | swc::BinaryOp::NotEqEq, ascending) => {
+            // Only terminates if the loop variable hits `end` exactly, which can only be proven for literals.
+            let start = integer_lit(&range.start)?;
+            let end = integer_lit(&range.end)?;
+            let distance = end - start;
+
+            if distance % range.step != 0 || (distance != 0 && (distance > 0) != ascending) {
+                None
+            } else if ascending {
+                Some(RangeShape::Ascending { inclusive: false })
+            } else {
+                Some(RangeShape::Descending { inclusive: false })
+            }
+        }
+        _ => None,
+    }
+}
+
+/// The exclusive upper bound equivalent to an inclusive upper bound of the form `<expr> - 1`.
+fn range_exclusive_upper(upper: &swc::Expr) -> Option<swc::Expr> {
+    let bin = upper.as_bin()?;
+
+    if bin.op == swc::BinaryOp::Sub
+        && integer_lit(&bin.right) == Some(1)
+        && integer_lit(&bin.left).is_none()
+    {
+        Some(*bin.left.clone())
+    } else {
+        None
+    }
+}
+
+/// The type of the loop variable, which is the integer type shared by the bounds or `i64` otherwise.
+///
+/// Lengths are `usize`, but computing with them could underflow, so then `i64` is used as well.
+fn range_number_type(ctx: &Context, bounds: [&swc::Expr; 2]) -> NumberType {
+    let mut number_types = vec![];
+    for bound in bounds {
+        match integer_lit(bound) {
+            Some(value) if value < 0 => return NumberType::I64,
+            Some(_) => {}
+            None => number_types.push(
+                infer_type(ctx, bound)
+                    .number_type()
+                    .unwrap_or(NumberType::F64),
+            ),
+        }
+    }
+
+    match number_types.first() {
+        Some(&number_type)
+            if number_type.is_integer()
+                && number_types.iter().all(|other| *other == number_type)
+                && !(number_type == NumberType::Usize
+                    && bounds.into_iter().any(contains_subtraction)) =>
+        {
+            number_type
+        }
+        _ => NumberType::I64,
+    }
+}
+
+fn contains_subtraction(expr: &swc::Expr) -> bool {
+    match expr {
+        swc::Expr::Unary(unary) => {
+            unary.op == swc::UnaryOp::Minus || contains_subtraction(&unary.arg)
+        }
+        swc::Expr::Bin(bin) => {
+            bin.op == swc::BinaryOp::Sub
+                || contains_subtraction(&bin.left)
+                || contains_subtraction(&bin.right)
+        }
+        swc::Expr::Paren(paren) => contains_subtraction(&paren.expr),
+        _ => false,
+    }
+}
+
+fn is_ident_named(expr: &swc::Expr, name: &str) -> bool {
+    expr.as_ident()
+        .is_some_and(|ident| ident.sym.as_str() == name)
+}
+
+/// Checks if an expression is a numeric range bound, e.g. `10`, `n`, `arr.length` or `n - 1`.
+///
+/// Collects the identifiers the bound reads (`idents`) and the objects it reads a length from (`objects`).
+fn is_range_bound(
+    ctx: &Context,
+    expr: &swc::Expr,
+    idents: &mut Vec<String>,
+    objects: &mut Vec<String>,
+) -> bool {
+    match expr {
+        swc::Expr::Lit(swc::Lit::Num(num)) => num.value.trunc() == num.value,
+        swc::Expr::Ident(ident) => {
+            idents.push(ident.sym.to_string());
+
+            infer_type(ctx, expr).number_type().is_some()
+        }
+        swc::Expr::Member(member) => {
+            if !is_length_member(member) {
+                return false;
+            }
+
+            match root_ident(&member.obj) {
+                Some(object) if is_member_chain(&member.obj) => {
+                    objects.push(object);
+                    true
+                }
+                _ => false,
+            }
+        }
+        swc::Expr::Unary(unary) => {
+            unary.op == swc::UnaryOp::Minus && is_range_bound(ctx, &unary.arg, idents, objects)
+        }
+        swc::Expr::Bin(bin) => {
+            matches!(
+                bin.op,
+                swc::BinaryOp::Add | swc::BinaryOp::Sub | swc::BinaryOp::Mul
+            ) && is_range_bound(ctx, &bin.left, idents, objects)
+                && is_range_bound(ctx, &bin.right, idents, objects)
+        }
+        swc::Expr::Paren(paren) => is_range_bound(ctx, &paren.expr, idents, objects),
+        _ => false,
+    }
+}
+
+fn is_length_member(member: &swc::MemberExpr) -> bool {
+    member.prop.is_ident()
+        && member.prop.as_ident().expect("Prop is Ident.").sym.as_str() == "length"
+}
+
+fn is_member_chain(expr: &swc::Expr) -> bool {
+    match expr {
+        swc::Expr::Ident(_) => true,
+        swc::Expr::Member(member) => member.prop.is_ident() && is_member_chain(&member.obj),
+        _ => false,
+    }
+}
+
+fn transpile_range_bound(ctx: &mut Context, bound: RangeBound, number_type: NumberType) -> Expr {
+    if let Some(value) = integer_lit(bound.expr()) {
+        let value = match bound {
+            RangeBound::Above(_) => value + 1,
+            _ => value,
+        };
+
+        return transpile_number_lit(value as f64, number_type);
+    }
+
+    let one = |number_type| transpile_number_lit(1.0, number_type);
+
+    if infer_type(ctx, bound.expr()).number_type() == Some(NumberType::F64) {
+        let (method, expr, above) = match bound {
+            RangeBound::Ceil(expr) => ("ceil", expr, false),
+            RangeBound::Floor(expr) => ("floor", expr, false),
+            RangeBound::Above(expr) => ("floor", expr, true),
+        };
+
+        // `f64::ceil(n)` instead of `n.ceil()`, so the float type doesn't need to be inferred.
+        let mut rounded = generate_path_call(
+            &["f64", method],
+            vec![transpile_range_number(ctx, expr, NumberType::F64)],
+        );
+
+        if above {
+            rounded = Expr::Binary(ExprBinary {
+                attrs: vec![],
+                left: Box::new(rounded),
+                op: BinOp::Add(token::Plus(dummy_span())),
+                right: Box::new(one(NumberType::F64)),
+            });
+        }
+
+        // Casting saturates, so negative bounds of a `usize` range become zero.
+        cast_number(rounded, number_type, Some(NumberType::F64))
+    } else {
+        match bound {
+            RangeBound::Ceil(expr) | RangeBound::Floor(expr) => {
+                transpile_range_number(ctx, expr, number_type)
+            }
+            RangeBound::Above(expr) => Expr::Binary(ExprBinary {
+                attrs: vec![],
+                left: Box::new(transpile_range_number(ctx, expr, number_type)),
+                op: BinOp::Add(token::Plus(dummy_span())),
+                right: Box::new(one(number_type)),
+            }),
+        }
+    }
+}
+
+/// Transpiles a range bound matched by [`is_range_bound`], computing it as `number_type`.
+fn transpile_range_number(ctx: &mut Context, expr: swc::Expr, number_type: NumberType) -> Expr {
+    if let Some(value) = integer_lit(&expr) {
+        return transpile_number_lit(value as f64, number_type);
+    }
+
+    match expr {
+        // Arrays count elements and strings UTF-16 code units, see `transpile_member`.
+        swc::Expr::Member(member) if is_length_member(&member) => cast_number(
+            transpile_member(ctx, member),
+            number_type,
+            Some(NumberType::Usize),
+        ),
+        swc::Expr::Unary(unary) => Expr::Unary(ExprUnary {
+            attrs: vec![],
+            op: UnOp::Neg(token::Minus(dummy_span())),
+            expr: Box::new(transpile_range_number(ctx, *unary.arg, number_type)),
+        }),
+        swc::Expr::Bin(bin) => Expr::Binary(ExprBinary {
+            attrs: vec![],
+            left: Box::new(transpile_range_number(ctx, *bin.left, number_type)),
+            op: transpile_bin_op(bin.op),
+            right: Box::new(transpile_range_number(ctx, *bin.right, number_type)),
+        }),
+        swc::Expr::Paren(paren) => Expr::Paren(ExprParen {
+            attrs: vec![],
+            paren_token: token::Paren(dummy_span()),
+            expr: Box::new(transpile_range_number(ctx, *paren.expr, number_type)),
+        }),
+        expr => transpile_number(ctx, expr, number_type),
+    }
 }
@@ -0,0 +1,1308 @@
+use quote::quote;
+use swc_ecma_ast as swc;
+use syn::{parse_quote, Ident, Lifetime, TraitBound, Type};
+
+use crate::{
+    analysis::{
+        arithmetic_magnitude, integer_lit, remainder_magnitude, I32_MAGNITUDE,
+        MAX_INTEGER_MAGNITUDE,
+    },
+    context::Context,
+    util::dummy_span,
+};
+
+/// Rust representation of a TypeScript `number`.
+///
+/// Numbers are `f64` like in JavaScript, unless they are proven to only hold integers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NumberType {
+    F64,
+    I32,
+    I64,
+    Usize,
+}
+
+impl NumberType {
+    pub fn is_integer(self) -> bool {
+        self != NumberType::F64
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            NumberType::F64 => "f64",
+            NumberType::I32 => "i32",
+            NumberType::I64 => "i64",
+            NumberType::Usize => "usize",
+        }
+    }
+}
+
+/// Statically known type of a TypeScript value.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ValueType {
+    Boolean,
+    Number(NumberType),
+    /// Values that can be `null` or `undefined`, e.g. `number | undefined`, represented as `Option`.
+    Option(Box<ValueType>),
+    String,
+    /// Arrays of one element type, e.g. `number[]`, represented as `Vec`.
+    Array(Box<ValueType>),
+    /// Objects with string keys and one value type, e.g. `Record<string, number>`, represented as `Object` from ts-std.
+    Object(Box<ValueType>),
+    /// Functions, e.g. `(x: number) => string`, represented as closures or `Rc<dyn Fn>` when stored.
+    Function(Box<FnSignature>),
+    /// Results of async functions, e.g. `Promise<number>`, where `None` is `Promise<void>`.
+    Promise(Option<Box<ValueType>>),
+    /// Iterators of generator functions, e.g. `Generator<number, string>`, with the type of the values they yield and
+    /// the type they return, where `None` is `void`. Represented as `Box<dyn Generator>`.
+    Generator(Box<ValueType>, Option<Box<ValueType>>),
+    /// Streams of async generator functions, e.g. `AsyncGenerator<string>`, with the same types as `Generator`.
+    /// Represented as `AsyncGenerator` from `ts-std`.
+    AsyncGenerator(Box<ValueType>, Option<Box<ValueType>>),
+    /// Objects of an interface or an object type alias by its name, e.g. `Point` for `interface Point { x: number }`,
+    /// represented as a struct generated for the declaration, see `Context::declare_struct`.
+    Struct(String),
+    Unknown,
+}
+
+impl ValueType {
+    pub fn number_type(&self) -> Option<NumberType> {
+        match self {
+            ValueType::Number(number_type) => Some(*number_type),
+            _ => None,
+        }
+    }
+}
+
+/// Signature of a function declaration, used to convert the arguments of its calls.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FnSignature {
+    pub params: Vec<ValueType>,
+    /// Element type of a rest parameter, e.g. `number` for `...values: number[]`.
+    pub rest: Option<ValueType>,
+    /// `None` for functions that don't return a value. Async functions have the type of the value they resolve to,
+    /// while async generator functions have their `AsyncGenerator` type.
+    pub return_type: Option<ValueType>,
+    /// Async functions return a `Promise` of their return type to callers.
+    pub is_async: bool,
+    /// Generator functions have a `Generator` return type, and return the value of its return type from their body.
+    pub is_generator: bool,
+}
+
+impl FnSignature {
+    /// The type of the value calls return, e.g. `Promise<T>` for async functions.
+    pub fn call_type(&self) -> Option<ValueType> {
+        if self.returns_promise() {
+            Some(ValueType::Promise(self.return_type.clone().map(Box::new)))
+        } else {
+            self.return_type.clone()
+        }
+    }
+
+    /// Whether calls return a promise, which async generator functions don't, since they return their stream.
+    pub fn returns_promise(&self) -> bool {
+        self.is_async && !self.is_generator
+    }
+
+    /// Whether the function takes its arguments owned, since the futures of async functions and the iterators of
+    /// generator functions can outlive the caller.
+    pub fn takes_owned_args(&self) -> bool {
+        self.is_async || self.is_generator
+    }
+}
+
+/// The bound of a closure with a signature, e.g. `Fn(f64) -> String`, where `fn_trait` is `Fn` or `FnMut`.
+pub fn generate_fn_bound(signature: &FnSignature, fn_trait: &str) -> TraitBound {
+    let fn_trait = Ident::new(fn_trait, dummy_span());
+    let params = signature
+        .params
+        .iter()
+        .cloned()
+        .chain(
+            signature
+                .rest
+                .clone()
+                .map(|rest| ValueType::Array(Box::new(rest))),
+        )
+        .map(|param| param_type(&param));
+    let output = signature.return_type.as_ref().map(|return_type| {
+        let return_type = owned_type(return_type);
+
+        quote!(-> #return_type)
+    });
+
+    parse_quote!(#fn_trait(#(#params),*) #output)
+}
+
+/// A signature of an overloaded function, with the name of the Rust function or enum variant it becomes.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Overload {
+    pub name: String,
+    pub signature: FnSignature,
+}
+
+/// Rust type of an owned value, like a binding or return value, e.g. `String` or `Vec<f64>`.
+pub fn owned_type(value_type: &ValueType) -> Type {
+    match value_type {
+        ValueType::Boolean => parse_quote!(bool),
+        ValueType::Number(number_type) => {
+            let ident = Ident::new(number_type.name(), dummy_span());
+
+            parse_quote!(#ident)
+        }
+        ValueType::Option(inner) => {
+            let inner = owned_type(inner);
+
+            parse_quote!(Option<#inner>)
+        }
+        ValueType::Function(signature) => {
+            let bound = generate_fn_bound(signature, "Fn");
+
+            parse_quote!(Rc<dyn #bound>)
+        }
+        ValueType::String => parse_quote!(String),
+        ValueType::Array(element) => {
+            let element = owned_type(element);
+
+            parse_quote!(Vec<#element>)
+        }
+        ValueType::Object(value_type) => {
+            let value_type = owned_type(value_type);
+
+            parse_quote!(Object<#value_type>)
+        }
+        ValueType::Promise(value_type) => {
+            let value_type = match value_type {
+                Some(value_type) => owned_type(value_type),
+                None => parse_quote!(()),
+            };
+
+            parse_quote!(Promise<#value_type>)
+        }
+        ValueType::Generator(yield_type, return_type) => {
+            let yield_type = owned_type(yield_type);
+            let return_type = match return_type {
+                Some(return_type) => owned_type(return_type),
+                None => parse_quote!(()),
+            };
+
+            parse_quote!(Box<dyn Generator<Item = #yield_type, Return = #return_type>>)
+        }
+        ValueType::AsyncGenerator(yield_type, return_type) => {
+            let yield_type = owned_type(yield_type);
+
+            match return_type {
+                Some(return_type) => {
+                    let return_type = owned_type(return_type);
+
+                    parse_quote!(AsyncGenerator<#yield_type, #return_type>)
+                }
+                None => parse_quote!(AsyncGenerator<#yield_type>),
+            }
+        }
+        ValueType::Struct(name) => {
+            let ident = Ident::new(name, dummy_span());
+
+            parse_quote!(#ident)
+        }
+        ValueType::Unknown => todo!("type of unknown value"),
+    }
+}
+
+/// Rust type of a parameter, where strings and arrays are borrowed as `&str` and `&[T]`.
+pub fn param_type(value_type: &ValueType) -> Type {
+    borrowed_type(value_type, None)
+}
+
+/// Like `param_type`, with the borrows of strings and arrays annotated with `lifetime`, e.g. `&'a str`.
+pub fn borrowed_type(value_type: &ValueType, lifetime: Option<&Lifetime>) -> Type {
+    match value_type {
+        ValueType::Option(inner) => {
+            let inner = borrowed_type(inner, lifetime);
+
+            parse_quote!(Option<#inner>)
+        }
+        ValueType::Function(signature) => {
+            let bound = generate_fn_bound(signature, "Fn");
+
+            match lifetime {
+                Some(lifetime) => parse_quote!(&#lifetime dyn #bound),
+                None => parse_quote!(impl #bound),
+            }
+        }
+        ValueType::String => parse_quote!(&#lifetime str),
+        ValueType::Array(element) => {
+            let element = owned_type(element);
+
+            parse_quote!(&#lifetime [#element])
+        }
+        ValueType::Object(_) | ValueType::Struct(_) => {
+            let object = owned_type(value_type);
+
+            parse_quote!(&#lifetime #object)
+        }
+        value_type => owned_type(value_type),
+    }
+}
+
+pub fn annotation_type(ts_type: &swc::TsType) -> ValueType {
+    match ts_type {
+        swc::TsType::TsParenthesizedType(paren) => annotation_type(&paren.type_ann),
+        swc::TsType::TsArrayType(array) => {
+            ValueType::Array(Box::new(annotation_type(&array.elem_type)))
+        }
+        swc::TsType::TsTypeRef(type_ref)
+            if type_ref
+                .type_name
+                .as_ident()
+                .is_some_and(|ident| ident.sym.as_str() == "Array") =>
+        {
+            match type_ref
+                .type_params
+                .as_deref()
+                .map(|params| &params.params[..])
+            {
+                Some([element]) => ValueType::Array(Box::new(annotation_type(element))),
+                _ => ValueType::Unknown,
+            }
+        }
+        swc::TsType::TsTypeRef(type_ref)
+            if type_ref
+                .type_name
+                .as_ident()
+                .is_some_and(|ident| ident.sym.as_str() == "Record") =>
+        {
+            match type_ref
+                .type_params
+                .as_deref()
+                .map(|params| &params.params[..])
+            {
+                Some([key_type, value_type]) if is_string_type(key_type) => {
+                    ValueType::Object(Box::new(annotation_type(value_type)))
+                }
+                _ => ValueType::Unknown,
+            }
+        }
+        // Object types with only a string index signature, e.g. `{ [key: string]: number }`.
+        swc::TsType::TsTypeLit(type_lit) => match &type_lit.members[..] {
+            [swc::TsTypeElement::TsIndexSignature(index_signature)]
+                if index_signature.params.len() == 1
+                    && index_signature.params[0].as_ident().is_some_and(|param| {
+                        param
+                            .type_ann
+                            .as_ref()
+                            .is_some_and(|type_ann| is_string_type(&type_ann.type_ann))
+                    }) =>
+            {
+                match &index_signature.type_ann {
+                    Some(type_ann) => {
+                        ValueType::Object(Box::new(annotation_type(&type_ann.type_ann)))
+                    }
+                    None => ValueType::Unknown,
+                }
+            }
+            _ => ValueType::Unknown,
+        },
+        swc::TsType::TsTypeRef(type_ref)
+            if type_ref
+                .type_name
+                .as_ident()
+                .is_some_and(|ident| ident.sym.as_str() == "Promise") =>
+        {
+            match type_ref
+                .type_params
+                .as_deref()
+                .map(|params| &params.params[..])
+            {
+                Some([value_type]) => {
+                    ValueType::Promise(return_annotation_type(value_type).map(Box::new))
+                }
+                _ => ValueType::Unknown,
+            }
+        }
+        swc::TsType::TsTypeRef(type_ref)
+            if type_ref.type_name.as_ident().is_some_and(|ident| {
+                matches!(
+                    ident.sym.as_str(),
+                    "Generator"
+                        | "IterableIterator"
+                        | "Iterator"
+                        | "AsyncGenerator"
+                        | "AsyncIterableIterator"
+                        | "AsyncIterator"
+                )
+            }) =>
+        {
+            let is_async = type_ref
+                .type_name
+                .as_ident()
+                .is_some_and(|ident| ident.sym.starts_with("Async"));
+            let (yield_type, return_type) = match type_ref
+                .type_params
+                .as_deref()
+                .map(|params| &params.params[..])
+            {
+                Some([yield_type]) => (Box::new(annotation_type(yield_type)), None),
+                Some([yield_type, return_type, ..]) => (
+                    Box::new(annotation_type(yield_type)),
+                    return_annotation_type(return_type).map(Box::new),
+                ),
+                _ => return ValueType::Unknown,
+            };
+
+            if is_async {
+                ValueType::AsyncGenerator(yield_type, return_type)
+            } else {
+                ValueType::Generator(yield_type, return_type)
+            }
+        }
+        // Other named types are interfaces or object type aliases.
+        swc::TsType::TsTypeRef(swc::TsTypeRef {
+            type_name: swc::TsEntityName::Ident(ident),
+            type_params: None,
+            ..
+        }) => ValueType::Struct(ident.sym.to_string()),
+        swc::TsType::TsFnOrConstructorType(swc::TsFnOrConstructorType::TsFnType(fn_type)) => {
+            let mut signature = FnSignature {
+                params: vec![],
+                rest: None,
+                return_type: return_annotation_type(&fn_type.type_ann.type_ann),
+                is_async: false,
+                is_generator: false,
+            };
+
+            for param in &fn_type.params {
+                match param {
+                    swc::TsFnParam::Ident(ident) => {
+                        let param_type = ident
+                            .type_ann
+                            .as_ref()
+                            .map(|type_ann| annotation_type(&type_ann.type_ann))
+                            .unwrap_or(ValueType::Unknown);
+
+                        signature.params.push(match param_type {
+                            param_type if ident.id.optional => {
+                                ValueType::Option(Box::new(param_type))
+                            }
+                            param_type => param_type,
+                        });
+                    }
+                    swc::TsFnParam::Rest(rest) => {
+                        signature.rest = match rest.type_ann.as_ref() {
+                            Some(type_ann) => match annotation_type(&type_ann.type_ann) {
+                                ValueType::Array(element_type) => Some(*element_type),
+                                _ => Some(ValueType::Unknown),
+                            },
+                            None => Some(ValueType::Unknown),
+                        }
+                    }
+                    _ => todo!("function type param pattern"),
+                }
+            }
+
+            ValueType::Function(Box::new(signature))
+        }
+        swc::TsType::TsKeywordType(keyword) => match keyword.kind {
+            swc::TsKeywordTypeKind::TsBooleanKeyword => ValueType::Boolean,
+            swc::TsKeywordTypeKind::TsNumberKeyword => ValueType::Number(NumberType::F64),
+            swc::TsKeywordTypeKind::TsStringKeyword => ValueType::String,
+            _ => ValueType::Unknown,
+        },
+        swc::TsType::TsUnionOrIntersectionType(swc::TsUnionOrIntersectionType::TsUnionType(
+            union,
+        )) => {
+            let types: Vec<&swc::TsType> = union
+                .types
+                .iter()
+                .map(|ts_type| &**ts_type)
+                .filter(|ts_type| !is_nullish_type(ts_type))
+                .collect();
+
+            match types[..] {
+                [ts_type] if types.len() < union.types.len() => {
+                    ValueType::Option(Box::new(annotation_type(ts_type)))
+                }
+                _ => ValueType::Unknown,
+            }
+        }
+        _ => ValueType::Unknown,
+    }
+}
+
+/// The fields of the struct of an interface or object type alias, e.g. `x: f64` and `y: Option<f64>` for
+/// `{ x: number; y?: number }`, by their property keys. `None` if a member isn't a property whose key is a field name.
+pub fn struct_fields(members: &[swc::TsTypeElement]) -> Option<Vec<(String, ValueType)>> {
+    members
+        .iter()
+        .map(|member| {
+            let property = member.as_ts_property_signature()?;
+            let key = match &*property.key {
+                swc::Expr::Ident(ident) if !property.computed => ident.sym.to_string(),
+                key => literal_key(key)?,
+            };
+            let value_type = property
+                .type_ann
+                .as_ref()
+                .map(|type_ann| annotation_type(&type_ann.type_ann))
+                .unwrap_or(ValueType::Unknown);
+            let value_type = match value_type {
+                value_type @ ValueType::Option(_) => value_type,
+                value_type if property.optional => ValueType::Option(Box::new(value_type)),
+                value_type => value_type,
+            };
+
+            struct_field_name(&key).map(|_| (key, value_type))
+        })
+        .collect()
+}
+
+/// The name of the field of a struct for a property key, which is the key itself or `_` followed by an integer key,
+/// e.g. `_2` for `2`.
+pub fn struct_field_name(key: &str) -> Option<String> {
+    if is_field_name(key) {
+        Some(key.to_string())
+    } else if key.bytes().all(|byte| byte.is_ascii_digit()) && (key == "0" || !key.starts_with('0'))
+    {
+        Some(format!("_{key}"))
+    } else {
+        None
+    }
+}
+
+/// The key of a property that is known without evaluating an expression, e.g. `"b"` for `a.b`, `a["b"]` and `2` for
+/// `a[2]`.
+pub fn member_prop_key(prop: &swc::MemberProp) -> Option<String> {
+    match prop {
+        swc::MemberProp::Ident(ident) => Some(ident.sym.to_string()),
+        swc::MemberProp::Computed(computed) => literal_key(&computed.expr),
+        swc::MemberProp::PrivateName(_) => None,
+    }
+}
+
+/// The key of a string literal or a non-negative integer literal.
+pub fn literal_key(expr: &swc::Expr) -> Option<String> {
+    match expr {
+        swc::Expr::Lit(swc::Lit::Str(str)) => Some(str.value.to_string()),
+        expr => integer_lit(expr)
+            .filter(|value| *value >= 0)
+            .map(|value| value.to_string()),
+    }
+}
+
+/// Whether a string key is also a valid Rust field name.
+pub fn is_field_name(key: &str) -> bool {
+    key.chars()
+        .next()
+        .is_some_and(|char| char.is_alphabetic() || char == '_')
+        && key
+            .chars()
+            .all(|char| char.is_alphanumeric() || char == '_')
+}
+
+fn is_string_type(ts_type: &swc::TsType) -> bool {
+    matches!(
+        ts_type,
+        swc::TsType::TsKeywordType(swc::TsKeywordType {
+            kind: swc::TsKeywordTypeKind::TsStringKeyword,
+            ..
+        })
+    )
+}
+
+/// The type of a return type annotation, where `void` means no return value.
+pub fn return_annotation_type(ts_type: &swc::TsType) -> Option<ValueType> {
+    match ts_type {
+        swc::TsType::TsKeywordType(swc::TsKeywordType {
+            kind: swc::TsKeywordTypeKind::TsVoidKeyword,
+            ..
+        }) => None,
+        ts_type => Some(annotation_type(ts_type)),
+    }
+}
+
+fn is_nullish_type(ts_type: &swc::TsType) -> bool {
+    matches!(
+        ts_type,
+        swc::TsType::TsKeywordType(swc::TsKeywordType {
+            kind: swc::TsKeywordTypeKind::TsNullKeyword
+                | swc::TsKeywordTypeKind::TsUndefinedKeyword,
+            ..
+        })
+    )
+}
+
+pub fn infer_type(ctx: &Context, expr: &swc::Expr) -> ValueType {
+    match expr {
+        swc::Expr::Lit(swc::Lit::Num(_)) => ValueType::Number(NumberType::F64),
+        swc::Expr::Lit(swc::Lit::Str(_)) => ValueType::String,
+        swc::Expr::Lit(swc::Lit::Bool(_)) => ValueType::Boolean,
+        swc::Expr::Ident(ident) => match ctx.lookup(ident.sym.as_str()) {
+            Some(value_type) => value_type.clone(),
+            None if matches!(ident.sym.as_str(), "NaN" | "Infinity") => {
+                ValueType::Number(NumberType::F64)
+            }
+            None => match ctx.function(ident.sym.as_str()) {
+                Some(signature) => ValueType::Function(Box::new(signature.clone())),
+                None => ValueType::Unknown,
+            },
+        },
+        swc::Expr::Arrow(_) | swc::Expr::Fn(_) => match function_expr_signature(ctx, expr, &[]) {
+            Some(signature) => ValueType::Function(Box::new(signature)),
+            None => ValueType::Unknown,
+        },
+        swc::Expr::Paren(paren) => infer_type(ctx, &paren.expr),
+        swc::Expr::Unary(unary) => match unary.op {
+            // Negated integers are `-0` for zero, which integers can't represent.
+            swc::UnaryOp::Minus => ValueType::Number(NumberType::F64),
+            swc::UnaryOp::Plus => match infer_type(ctx, &unary.arg) {
+                ValueType::Number(number_type) => ValueType::Number(number_type),
+                _ => ValueType::Number(NumberType::F64),
+            },
+            swc::UnaryOp::Tilde => match infer_type(ctx, &unary.arg) {
+                ValueType::Number(NumberType::I32) => ValueType::Number(NumberType::I32),
+                _ => ValueType::Number(NumberType::F64),
+            },
+            swc::UnaryOp::Bang | swc::UnaryOp::Delete => ValueType::Boolean,
+            swc::UnaryOp::TypeOf => ValueType::String,
+            swc::UnaryOp::Void => ValueType::Unknown,
+        },
+        swc::Expr::Update(update) => infer_type(ctx, &update.arg),
+        swc::Expr::Assign(assign) => infer_type(ctx, &assign.right),
+        swc::Expr::Bin(bin) => infer_bin_type(ctx, bin),
+        swc::Expr::Cond(cond) => {
+            if let Some(number_type) = operand_number_type(ctx, &cond.cons, &cond.alt, false) {
+                ValueType::Number(number_type)
+            } else {
+                match (infer_type(ctx, &cond.cons), infer_type(ctx, &cond.alt)) {
+                    (cons, alt) if cons == alt => cons,
+                    _ => ValueType::Unknown,
+                }
+            }
+        }
+        swc::Expr::Seq(seq) => infer_type(ctx, seq.exprs.last().expect("Seq has Exprs.")),
+        swc::Expr::Member(member) => {
+            let obj_type = infer_type(ctx, &member.obj);
+
+            if member
+                .prop
+                .as_ident()
+                .is_some_and(|prop| prop.sym.as_str() == "length")
+                && !matches!(obj_type, ValueType::Struct(_))
+            {
+                ValueType::Number(NumberType::Usize)
+            } else {
+                match (obj_type, &member.prop) {
+                    (ValueType::Array(element_type), swc::MemberProp::Computed(_)) => *element_type,
+                    (ValueType::Object(value_type), _) => *value_type,
+                    (ValueType::Struct(name), prop) => member_prop_key(prop)
+                        .and_then(|key| ctx.struct_field(&name, &key).cloned())
+                        .unwrap_or(ValueType::Unknown),
+                    _ => ValueType::Unknown,
+                }
+            }
+        }
+        swc::Expr::Call(call) if is_math_call(ctx, call) => ValueType::Number(NumberType::F64),
+        swc::Expr::Call(call) if promise_call(ctx, call).is_some() => {
+            let promise_call = promise_call(ctx, call).expect("Call is a promise call.");
+
+            promise_call_type(ctx, &promise_call, &call.args)
+        }
+        swc::Expr::New(new) if is_promise_constructor(ctx, &new.callee) => {
+            match new
+                .type_args
+                .as_deref()
+                .map(|type_args| &type_args.params[..])
+            {
+                Some([value_type]) => {
+                    ValueType::Promise(return_annotation_type(value_type).map(Box::new))
+                }
+                _ => ValueType::Promise(Some(Box::new(ValueType::Unknown))),
+            }
+        }
+        swc::Expr::Call(call) => match array_method(ctx, call) {
+            Some((method, element_type)) => {
+                array_method_type(ctx, method, element_type, &call.args)
+            }
+            None => call_signature(ctx, call)
+                .and_then(|signature| signature.call_type())
+                .unwrap_or(ValueType::Unknown),
+        },
+        swc::Expr::Yield(swc::YieldExpr {
+            arg: Some(arg),
+            delegate: true,
+            ..
+        }) => match infer_type(ctx, arg) {
+            ValueType::AsyncGenerator(_, Some(return_type)) => *return_type,
+            _ => ValueType::Unknown,
+        },
+        swc::Expr::Await(await_expr) => match infer_type(ctx, &await_expr.arg) {
+            ValueType::Promise(Some(value_type)) => *value_type,
+            ValueType::Promise(None) => ValueType::Unknown,
+            value_type => value_type,
+        },
+        swc::Expr::Array(array) => {
+            let mut element_types = array.elems.iter().map(|element| match element {
+                Some(swc::ExprOrSpread { spread: None, expr }) => infer_type(ctx, expr),
+                Some(swc::ExprOrSpread {
+                    spread: Some(_),
+                    expr,
+                }) => match infer_type(ctx, expr) {
+                    ValueType::Array(element_type) => *element_type,
+                    _ => ValueType::Unknown,
+                },
+                None => ValueType::Unknown,
+            });
+
+            match element_types.next() {
+                Some(first) if element_types.all(|element_type| element_type == first) => {
+                    ValueType::Array(Box::new(first))
+                }
+                _ => ValueType::Array(Box::new(ValueType::Unknown)),
+            }
+        }
+        // Object literals are dynamic objects if all their values have the same type.
+        swc::Expr::Object(object) => {
+            let mut value_types = object.props.iter().map(|prop| match prop {
+                swc::PropOrSpread::Prop(prop) => match &**prop {
+                    swc::Prop::KeyValue(key_value) => infer_type(ctx, &key_value.value),
+                    swc::Prop::Shorthand(ident) => {
+                        infer_type(ctx, &swc::Expr::Ident(ident.clone()))
+                    }
+                    _ => ValueType::Unknown,
+                },
+                swc::PropOrSpread::Spread(_) => ValueType::Unknown,
+            });
+
+            match value_types.next() {
+                Some(first) if value_types.all(|value_type| value_type == first) => {
+                    ValueType::Object(Box::new(first))
+                }
+                _ => ValueType::Object(Box::new(ValueType::Unknown)),
+            }
+        }
+        _ => ValueType::Unknown,
+    }
+}
+
+/// The signature of the function a call calls, if it is a declared function or a value with a function type.
+pub fn call_signature(ctx: &Context, call: &swc::CallExpr) -> Option<FnSignature> {
+    let callee = call.callee.as_expr()?;
+
+    if let Some(signature) = callee
+        .as_ident()
+        .and_then(|ident| ctx.function(ident.sym.as_str()))
+    {
+        return Some(signature.clone());
+    }
+
+    if let Some(overload) = call_overload(ctx, call) {
+        return Some(overload.signature.clone());
+    }
+
+    match infer_type(ctx, callee) {
+        ValueType::Function(signature) => Some(*signature),
+        _ => None,
+    }
+}
+
+/// The parts of an arrow function or `function` expression: its parameters, return type annotation and body.
+pub fn function_expr_parts(
+    expr: &swc::Expr,
+) -> Option<(Vec<&swc::Pat>, Option<&swc::TsTypeAnn>, FunctionBody<'_>)> {
+    match expr {
+        swc::Expr::Paren(paren) => function_expr_parts(&paren.expr),
+        swc::Expr::Arrow(arrow) => Some((
+            arrow.params.iter().collect(),
+            arrow.return_type.as_deref(),
+            match &*arrow.body {
+                swc::BlockStmtOrExpr::BlockStmt(block) => FunctionBody::Block(block),
+                swc::BlockStmtOrExpr::Expr(expr) => FunctionBody::Expr(expr),
+            },
+        )),
+        swc::Expr::Fn(fn_expr) => Some((
+            fn_expr
+                .function
+                .params
+                .iter()
+                .map(|param| &param.pat)
+                .collect(),
+            fn_expr.function.return_type.as_deref(),
+            FunctionBody::Block(
+                fn_expr
+                    .function
+                    .body
+                    .as_ref()
+                    .expect("Function expression has a body."),
+            ),
+        )),
+        _ => None,
+    }
+}
+
+/// The body of a function expression, where arrow functions can have an expression instead of a block.
+pub enum FunctionBody<'a> {
+    Block(&'a swc::BlockStmt),
+    Expr(&'a swc::Expr),
+}
+
+/// The signature of a function expression, where parameters without annotations have the types of `param_types`,
+/// e.g. the element type for the callback of `Array.map`.
+///
+/// Without an annotation, the return type is the type of the returned expression.
+pub fn function_expr_signature(
+    ctx: &Context,
+    expr: &swc::Expr,
+    param_types: &[ValueType],
+) -> Option<FnSignature> {
+    let (params, return_type, body) = function_expr_parts(expr)?;
+    let params: Vec<(Option<&str>, ValueType)> = params
+        .into_iter()
+        .enumerate()
+        .map(|(index, pat)| match pat {
+            swc::Pat::Ident(ident) => (
+                Some(ident.id.sym.as_str()),
+                match &ident.type_ann {
+                    Some(type_ann) => annotation_type(&type_ann.type_ann),
+                    None => param_types
+                        .get(index)
+                        .cloned()
+                        .unwrap_or(ValueType::Unknown),
+                },
+            ),
+            _ => (None, ValueType::Unknown),
+        })
+        .collect();
+
+    let return_type = match return_type {
+        Some(type_ann) => return_annotation_type(&type_ann.type_ann),
+        None => {
+            let mut body_ctx = ctx.clone();
+
+            for (ident, param_type) in &params {
+                if let Some(ident) = ident {
+                    body_ctx.declare(ident, param_type.clone());
+                }
+            }
+
+            match body {
+                FunctionBody::Expr(expr) => Some(infer_type(&body_ctx, expr)),
+                FunctionBody::Block(block) => find_return_arg(&block.stmts)
+                    .map(|return_arg| infer_type(&body_ctx, return_arg)),
+            }
+        }
+    };
+
+    Some(FnSignature {
+        params: params
+            .into_iter()
+            .map(|(_, param_type)| param_type)
+            .collect(),
+        rest: None,
+        return_type,
+        is_async: false,
+        is_generator: false,
+    })
+}
+
+/// The value of the first `return` statement in a function body, outside of nested functions.
+fn find_return_arg(stmts: &[swc::Stmt]) -> Option<&swc::Expr> {
+    stmts.iter().find_map(|stmt| match stmt {
+        swc::Stmt::Return(return_stmt) => return_stmt.arg.as_deref(),
+        swc::Stmt::Block(block) => find_return_arg(&block.stmts),
+        swc::Stmt::If(if_stmt) => {
+            find_return_arg(std::slice::from_ref(&*if_stmt.cons)).or_else(|| {
+                if_stmt
+                    .alt
+                    .as_deref()
+                    .and_then(|alt| find_return_arg(std::slice::from_ref(alt)))
+            })
+        }
+        swc::Stmt::For(swc::ForStmt { body, .. })
+        | swc::Stmt::ForIn(swc::ForInStmt { body, .. })
+        | swc::Stmt::ForOf(swc::ForOfStmt { body, .. })
+        | swc::Stmt::While(swc::WhileStmt { body, .. })
+        | swc::Stmt::DoWhile(swc::DoWhileStmt { body, .. }) => {
+            find_return_arg(std::slice::from_ref(&**body))
+        }
+        _ => None,
+    })
+}
+
+/// The array methods that take a callback, e.g. `map` in `xs.map((x) => x * 2)`, with the element type of the array.
+pub fn array_method<'a>(ctx: &Context, call: &'a swc::CallExpr) -> Option<(&'a str, ValueType)> {
+    let member = call.callee.as_expr()?.as_member()?;
+    let method = member.prop.as_ident()?.sym.as_str();
+
+    if !matches!(
+        method,
+        "map" | "filter" | "reduce" | "forEach" | "some" | "every" | "find"
+    ) {
+        return None;
+    }
+
+    match infer_type(ctx, &member.obj) {
+        ValueType::Array(element_type) => Some((method, *element_type)),
+        _ => None,
+    }
+}
+
+/// The result type of an array method, e.g. an array of the callback results for `map`.
+pub fn array_method_type(
+    ctx: &Context,
+    method: &str,
+    element_type: ValueType,
+    args: &[swc::ExprOrSpread],
+) -> ValueType {
+    match method {
+        "map" => {
+            let return_type = args
+                .first()
+                .and_then(|callback| callback_return_type(ctx, &callback.expr, &element_type));
+
+            ValueType::Array(Box::new(return_type.unwrap_or(ValueType::Unknown)))
+        }
+        "filter" => ValueType::Array(Box::new(element_type)),
+        // Without an initial value, the first element is the initial value.
+        "reduce" => match args.get(1) {
+            Some(initial) => infer_type(ctx, &initial.expr),
+            None => element_type,
+        },
+        "some" | "every" => ValueType::Boolean,
+        "find" => ValueType::Option(Box::new(element_type)),
+        _ => ValueType::Unknown,
+    }
+}
+
+/// The return type of a callback that is called with the elements of an array and their indices.
+fn callback_return_type(
+    ctx: &Context,
+    callback: &swc::Expr,
+    element_type: &ValueType,
+) -> Option<ValueType> {
+    let signature = match function_expr_signature(
+        ctx,
+        callback,
+        &[element_type.clone(), ValueType::Number(NumberType::Usize)],
+    ) {
+        Some(signature) => signature,
+        None => match infer_type(ctx, callback) {
+            ValueType::Function(signature) => *signature,
+            _ => return None,
+        },
+    };
+
+    signature.return_type
+}
+
+/// A call of a promise method, e.g. `then` in `p.then((x) => x * 2)`, or of a static `Promise` function, e.g. `all`
+/// in `Promise.all(promises)`.
+pub enum PromiseCall<'a> {
+    /// A method with the type of the value that the promise resolves to, where `None` is `void`.
+    Method(&'a str, Option<ValueType>),
+    Static(&'a str),
+}
+
+pub fn promise_call<'a>(ctx: &Context, call: &'a swc::CallExpr) -> Option<PromiseCall<'a>> {
+    let member = call.callee.as_expr()?.as_member()?;
+    let name = member.prop.as_ident()?.sym.as_str();
+
+    if is_promise_constructor(ctx, &member.obj) {
+        return matches!(
+            name,
+            "resolve" | "reject" | "all" | "allSettled" | "race" | "any"
+        )
+        .then_some(PromiseCall::Static(name));
+    }
+
+    match infer_type(ctx, &member.obj) {
+        ValueType::Promise(value_type) if matches!(name, "then" | "catch" | "finally") => Some(
+            PromiseCall::Method(name, value_type.map(|value_type| *value_type)),
+        ),
+        _ => None,
+    }
+}
+
+/// Whether an expression is the global `Promise`.
+pub fn is_promise_constructor(ctx: &Context, expr: &swc::Expr) -> bool {
+    expr.as_ident()
+        .is_some_and(|ident| ident.sym.as_str() == "Promise" && ctx.lookup("Promise").is_none())
+}
+
+/// The promise that a promise call results in, e.g. a promise of the callback result for `then`.
+pub fn promise_call_type(
+    ctx: &Context,
+    promise_call: &PromiseCall,
+    args: &[swc::ExprOrSpread],
+) -> ValueType {
+    let value_type = match promise_call {
+        // Callbacks that return a promise are flattened.
+        PromiseCall::Method("then", value_type) => match args
+            .first()
+            .and_then(|callback| promise_callback_type(ctx, &callback.expr, value_type))
+        {
+            Some(ValueType::Promise(value_type)) => return ValueType::Promise(value_type),
+            return_type => return_type,
+        },
+        PromiseCall::Method(_, value_type) => value_type.clone(),
+        PromiseCall::Static("resolve") => args.first().map(|arg| infer_type(ctx, &arg.expr)),
+        PromiseCall::Static("all") => Some(ValueType::Array(Box::new(
+            promise_elements_type(ctx, args).unwrap_or(ValueType::Unknown),
+        ))),
+        PromiseCall::Static("race" | "any") => promise_elements_type(ctx, args),
+        PromiseCall::Static("allSettled") => Some(ValueType::Array(Box::new(ValueType::Unknown))),
+        PromiseCall::Static(_) => Some(ValueType::Unknown),
+    };
+
+    ValueType::Promise(value_type.map(Box::new))
+}
+
+/// The return type of a callback that is called with the value of a promise.
+pub fn promise_callback_type(
+    ctx: &Context,
+    callback: &swc::Expr,
+    value_type: &Option<ValueType>,
+) -> Option<ValueType> {
+    let param_types: Vec<ValueType> = value_type.iter().cloned().collect();
+
+    match function_expr_signature(ctx, callback, &param_types) {
+        Some(signature) => signature.return_type,
+        None => match infer_type(ctx, callback) {
+            ValueType::Function(signature) => signature.call_type(),
+            _ => None,
+        },
+    }
+}
+
+/// The type of the values of an array of promises, like the argument of `Promise.all`.
+fn promise_elements_type(ctx: &Context, args: &[swc::ExprOrSpread]) -> Option<ValueType> {
+    match infer_type(ctx, &args.first()?.expr) {
+        ValueType::Array(element_type) => match *element_type {
+            ValueType::Promise(value_type) => value_type.map(|value_type| *value_type),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// The overload of an overloaded function that a call resolves to.
+pub fn call_overload<'a>(ctx: &'a Context, call: &swc::CallExpr) -> Option<&'a Overload> {
+    let overloads = call
+        .callee
+        .as_expr()
+        .and_then(|callee| callee.as_ident())
+        .and_then(|ident| ctx.overloads(ident.sym.as_str()))?;
+
+    resolve_overload(ctx, overloads, &call.args)
+}
+
+/// The first overload whose parameters accept the arguments, like TypeScript resolves overloads.
+pub fn resolve_overload<'a>(
+    ctx: &Context,
+    overloads: &'a [Overload],
+    args: &[swc::ExprOrSpread],
+) -> Option<&'a Overload> {
+    if args.iter().any(|arg| arg.spread.is_some()) {
+        todo!("spread into overloaded function")
+    }
+
+    let arg_types: Vec<ValueType> = args.iter().map(|arg| infer_type(ctx, &arg.expr)).collect();
+
+    overloads.iter().find(|overload| {
+        let signature = &overload.signature;
+        let required = signature
+            .params
+            .iter()
+            .filter(|param_type| !matches!(param_type, ValueType::Option(_)))
+            .count();
+
+        arg_types.len() >= required
+            && (arg_types.len() <= signature.params.len() || signature.rest.is_some())
+            && arg_types.iter().enumerate().all(|(index, arg_type)| {
+                match signature.params.get(index).or(signature.rest.as_ref()) {
+                    Some(param_type) => accepts_type(param_type, arg_type),
+                    None => false,
+                }
+            })
+    })
+}
+
+/// Whether a parameter of `param_type` accepts an argument of `arg_type`, where unknown types accept anything.
+fn accepts_type(param_type: &ValueType, arg_type: &ValueType) -> bool {
+    match (param_type, arg_type) {
+        (ValueType::Unknown, _) | (_, ValueType::Unknown) => true,
+        (ValueType::Number(_), ValueType::Number(_)) => true,
+        (ValueType::Option(param_type), ValueType::Option(arg_type)) => {
+            accepts_type(param_type, arg_type)
+        }
+        (ValueType::Option(param_type), arg_type) => accepts_type(param_type, arg_type),
+        (ValueType::Array(param_type), ValueType::Array(arg_type)) => {
+            accepts_type(param_type, arg_type)
+        }
+        (param_type, arg_type) => param_type == arg_type,
+    }
+}
+
+/// Whether a call is a method of `Math`, which all return numbers.
+pub fn is_math_call(ctx: &Context, call: &swc::CallExpr) -> bool {
+    call.callee
+        .as_expr()
+        .and_then(|callee| callee.as_member())
+        .and_then(|member| member.obj.as_ident())
+        .is_some_and(|obj| obj.sym.as_str() == "Math" && ctx.lookup("Math").is_none())
+}
+
+fn infer_bin_type(ctx: &Context, bin: &swc::BinExpr) -> ValueType {
+    match bin.op {
+        swc::BinaryOp::EqEq
+        | swc::BinaryOp::NotEq
+        | swc::BinaryOp::EqEqEq
+        | swc::BinaryOp::NotEqEq
+        | swc::BinaryOp::Lt
+        | swc::BinaryOp::LtEq
+        | swc::BinaryOp::Gt
+        | swc::BinaryOp::GtEq
+        | swc::BinaryOp::In
+        | swc::BinaryOp::InstanceOf => ValueType::Boolean,
+        swc::BinaryOp::Add => {
+            if infer_type(ctx, &bin.left) == ValueType::String
+                || infer_type(ctx, &bin.right) == ValueType::String
+            {
+                ValueType::String
+            } else {
+                operand_number_type(ctx, &bin.left, &bin.right, false)
+                    .map(|number_type| {
+                        ValueType::Number(arithmetic_number_type(ctx, bin, number_type))
+                    })
+                    .unwrap_or(ValueType::Unknown)
+            }
+        }
+        swc::BinaryOp::Exp => ValueType::Number(exp_number_type(ctx, &bin.left, &bin.right)),
+        swc::BinaryOp::Sub | swc::BinaryOp::Mul | swc::BinaryOp::Mod => {
+            ValueType::Number(arithmetic_number_type(
+                ctx,
+                bin,
+                operand_number_type(ctx, &bin.left, &bin.right, true).unwrap_or(NumberType::F64),
+            ))
+        }
+        swc::BinaryOp::Div => ValueType::Number(NumberType::F64),
+        swc::BinaryOp::BitAnd | swc::BinaryOp::BitOr | swc::BinaryOp::BitXor => {
+            ValueType::Number(bitwise_number_type(ctx, &bin.left, &bin.right))
+        }
+        swc::BinaryOp::LShift | swc::BinaryOp::RShift | swc::BinaryOp::ZeroFillRShift => {
+            ValueType::Number(NumberType::F64)
+        }
+        swc::BinaryOp::NullishCoalescing
+            if matches!(infer_type(ctx, &bin.left), ValueType::Option(_)) =>
+        {
+            match (infer_type(ctx, &bin.left), infer_type(ctx, &bin.right)) {
+                (left, ValueType::Option(_)) => left,
+                (ValueType::Option(inner), _) => *inner,
+                _ => unreachable!("Left is Option."),
+            }
+        }
+        swc::BinaryOp::LogicalOr | swc::BinaryOp::LogicalAnd | swc::BinaryOp::NullishCoalescing => {
+            let left = infer_type(ctx, &bin.left);
+
+            if let Some(number_type) = operand_number_type(ctx, &bin.left, &bin.right, false) {
+                ValueType::Number(number_type)
+            } else if left == infer_type(ctx, &bin.right) {
+                left
+            } else {
+                ValueType::Unknown
+            }
+        }
+    }
+}
+
+/// Number operand of an operator, where integer literals can take the type of the other operand.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Operand {
+    IntegerLiteral,
+    Number(NumberType),
+    Unknown,
+    Other,
+}
+
+fn operand(ctx: &Context, expr: &swc::Expr) -> Operand {
+    if integer_lit(expr).is_some() {
+        return Operand::IntegerLiteral;
+    }
+
+    match infer_type(ctx, expr) {
+        ValueType::Number(number_type) => Operand::Number(number_type),
+        ValueType::Unknown => Operand::Unknown,
+        _ => Operand::Other,
+    }
+}
+
+/// The number type both operands of an arithmetic or comparison operator are converted to, if they are numbers.
+///
+/// Mixed integer types are computed as `i64`, and mixed integer and float operands as `f64`. Operands of unknown type
+/// are assumed to be `f64` if `unknown_as_number` is set, otherwise no number type is determined.
+pub fn operand_number_type(
+    ctx: &Context,
+    left: &swc::Expr,
+    right: &swc::Expr,
+    unknown_as_number: bool,
+) -> Option<NumberType> {
+    match (operand(ctx, left), operand(ctx, right)) {
+        (Operand::IntegerLiteral, Operand::IntegerLiteral) => Some(NumberType::F64),
+        (Operand::IntegerLiteral, Operand::Number(number_type))
+        | (Operand::Number(number_type), Operand::IntegerLiteral) => Some(number_type),
+        (Operand::Number(left), Operand::Number(right)) => Some(if left == right {
+            left
+        } else if left.is_integer() && right.is_integer() {
+            NumberType::I64
+        } else {
+            NumberType::F64
+        }),
+        (Operand::Unknown, Operand::IntegerLiteral | Operand::Number(_) | Operand::Unknown)
+        | (Operand::IntegerLiteral | Operand::Number(_), Operand::Unknown)
+            if unknown_as_number =>
+        {
+            Some(NumberType::F64)
+        }
+        _ => None,
+    }
+}
+
+/// The number type of `&`, `|` and `^`, which can stay native for `i32` operands since they already have `ToInt32`
+/// semantics.
+pub fn bitwise_number_type(ctx: &Context, left: &swc::Expr, right: &swc::Expr) -> NumberType {
+    match operand_number_type(ctx, left, right, true) {
+        Some(NumberType::I32) => NumberType::I32,
+        _ => NumberType::F64,
+    }
+}
+
+/// The number type `+`, `-`, `*` or `%` is computed in for operands of the given type.
+///
+/// `+`, `-` and `*` on `i32` operands, and `-` on `usize` operands like lengths, are computed as `i64`, since the
+/// result may not fit, and TypeScript would compute them as floats instead of wrapping. Integer results that could
+/// overflow `i64` are computed as `f64` for the same reason, and so are remainders by divisors that may be zero,
+/// which are NaN. Results that may be `-0`, like zero times a negative number or the remainder of a negative
+/// multiple, are computed as `f64` too, since integers can't represent it.
+pub fn arithmetic_number_type(
+    ctx: &Context,
+    bin: &swc::BinExpr,
+    number_type: NumberType,
+) -> NumberType {
+    if !number_type.is_integer() {
+        return number_type;
+    }
+
+    let may_be_zero = |expr| integer_lit(expr).is_none_or(|value| value == 0);
+
+    match (bin.op, number_type) {
+        (swc::BinaryOp::Mul, _)
+            if (may_be_zero(&bin.left) && !is_non_negative(ctx, &bin.right))
+                || (!is_non_negative(ctx, &bin.left) && may_be_zero(&bin.right)) =>
+        {
+            NumberType::F64
+        }
+        (swc::BinaryOp::Mod, _) if !is_non_negative(ctx, &bin.left) => NumberType::F64,
+        (swc::BinaryOp::Mod, _) => {
+            match remainder_magnitude(integer_magnitude(ctx, &bin.left), &bin.right) {
+                Some(_) => number_type,
+                None => NumberType::F64,
+            }
+        }
+        _ if bin_magnitude(ctx, bin) > MAX_INTEGER_MAGNITUDE => NumberType::F64,
+        (swc::BinaryOp::Add | swc::BinaryOp::Sub | swc::BinaryOp::Mul, NumberType::I32)
+        | (swc::BinaryOp::Sub, NumberType::Usize) => NumberType::I64,
+        (_, number_type) => number_type,
+    }
+}
+
+/// Whether an integer expression is never negative, e.g. a length or a counter counting up from zero.
+fn is_non_negative(ctx: &Context, expr: &swc::Expr) -> bool {
+    if let Some(value) = integer_lit(expr) {
+        return value >= 0;
+    }
+
+    match expr {
+        swc::Expr::Ident(ident) if ctx.is_non_negative_integer(ident.sym.as_str()) => true,
+        swc::Expr::Paren(paren) => is_non_negative(ctx, &paren.expr),
+        swc::Expr::Bin(bin) => match bin.op {
+            swc::BinaryOp::Add | swc::BinaryOp::Mul => {
+                is_non_negative(ctx, &bin.left) && is_non_negative(ctx, &bin.right)
+            }
+            swc::BinaryOp::Mod => is_non_negative(ctx, &bin.left),
+            _ => false,
+        },
+        expr => infer_type(ctx, expr) == ValueType::Number(NumberType::Usize),
+    }
+}
+
+/// The largest magnitude of an integer expression, e.g. `10` for `i % 10`, see `find_integer_bindings`.
+///
+/// Integers of unknown magnitude are assumed to fill their type, and `i64`s to possibly overflow.
+pub fn integer_magnitude(ctx: &Context, expr: &swc::Expr) -> u64 {
+    if let Some(value) = integer_lit(expr) {
+        return value.unsigned_abs();
+    }
+
+    match expr {
+        swc::Expr::Ident(ident) => match ctx.integer_magnitude(ident.sym.as_str()) {
+            Some(magnitude) => magnitude,
+            None => type_magnitude(ctx, expr),
+        },
+        swc::Expr::Paren(paren) => integer_magnitude(ctx, &paren.expr),
+        swc::Expr::Unary(unary) if matches!(unary.op, swc::UnaryOp::Minus | swc::UnaryOp::Plus) => {
+            integer_magnitude(ctx, &unary.arg)
+        }
+        swc::Expr::Bin(bin) => bin_magnitude(ctx, bin),
+        swc::Expr::Member(member)
+            if member
+                .prop
+                .as_ident()
+                .is_some_and(|prop| prop.sym.as_str() == "length") =>
+        {
+            u32::MAX as u64
+        }
+        expr => type_magnitude(ctx, expr),
+    }
+}
+
+fn bin_magnitude(ctx: &Context, bin: &swc::BinExpr) -> u64 {
+    match bin.op {
+        swc::BinaryOp::Add | swc::BinaryOp::Sub | swc::BinaryOp::Mul => arithmetic_magnitude(
+            bin.op,
+            integer_magnitude(ctx, &bin.left),
+            integer_magnitude(ctx, &bin.right),
+        ),
+        swc::BinaryOp::Mod => {
+            remainder_magnitude(integer_magnitude(ctx, &bin.left), &bin.right).unwrap_or(u64::MAX)
+        }
+        _ => type_magnitude(ctx, &swc::Expr::Bin(bin.clone())),
+    }
+}
+
+fn type_magnitude(ctx: &Context, expr: &swc::Expr) -> u64 {
+    match infer_type(ctx, expr).number_type() {
+        Some(NumberType::I32) => I32_MAGNITUDE,
+        Some(NumberType::Usize) => u32::MAX as u64,
+        _ => u64::MAX,
+    }
+}
+
+/// The number type of `base ** exponent`.
+///
+/// Integers stay integers when raised to a non-negative integer literal, if the power can't overflow `i64`, see
+/// `MAX_INTEGER_MAGNITUDE`. `i32` powers are computed as `i64`, and everything else as `f64`.
+pub fn exp_number_type(ctx: &Context, base: &swc::Expr, exponent: &swc::Expr) -> NumberType {
+    let fits = |exponent: i64| {
+        u32::try_from(exponent)
+            .ok()
+            .and_then(|exponent| integer_magnitude(ctx, base).checked_pow(exponent))
+            .is_some_and(|magnitude| magnitude <= MAX_INTEGER_MAGNITUDE)
+    };
+
+    match (infer_type(ctx, base).number_type(), integer_lit(exponent)) {
+        (Some(NumberType::I32), Some(exponent)) if fits(exponent) => NumberType::I64,
+        (Some(number_type), Some(exponent)) if number_type.is_integer() && fits(exponent) => {
+            number_type
+        }
+        _ => NumberType::F64,
+    }
+}
@@ -0,0 +1,180 @@
+use std::{
+    cell::RefCell,
+    future::Future,
+    pin::Pin,
+    rc::Rc,
+    task::{Context, Poll},
+};
+
+/// The stream of an async generator function, which runs its body whenever `next` is awaited, until the body yields
+/// the next value.
+///
+/// Like JavaScript objects, clones share the same generator, so a generator that a `for await` loop closed with
+/// `break` is also done for the other references to it.
+pub struct AsyncGenerator<T, R = ()> {
+    state: Rc<RefCell<State<R>>>,
+    yielded: Rc<RefCell<Option<T>>>,
+}
+
+struct State<R> {
+    /// The future of the body, which is `None` while it is polled and once the generator is done.
+    body: Option<Pin<Box<dyn Future<Output = R>>>>,
+    returned: Option<R>,
+    done: bool,
+}
+
+impl<T: 'static, R: 'static> AsyncGenerator<T, R> {
+    /// Creates the generator of a body, which yields its values with the `Yielder` it is called with.
+    ///
+    /// Like in JavaScript, the body doesn't start running before `next` is awaited for the first time.
+    pub fn new<F, B>(body: F) -> Self
+    where
+        F: FnOnce(Yielder<T>) -> B,
+        B: Future<Output = R> + 'static,
+    {
+        let yielded = Rc::new(RefCell::new(None));
+        let body = body(Yielder {
+            yielded: yielded.clone(),
+        });
+
+        Self {
+            state: Rc::new(RefCell::new(State {
+                body: Some(Box::pin(body)),
+                returned: None,
+                done: false,
+            })),
+            yielded,
+        }
+    }
+
+    /// `generator.next()`, which resolves to the next value, or to `None` once the body returned.
+    pub fn next(&self) -> Next<T, R> {
+        Next {
+            state: self.state.clone(),
+            yielded: self.yielded.clone(),
+        }
+    }
+
+    /// `generator.return()`, which finishes the generator without running the rest of its body, like `break` in a
+    /// `for await` loop does.
+    pub fn close(&self) {
+        let mut state = self.state.borrow_mut();
+
+        state.body = None;
+        state.done = true;
+    }
+
+    /// Takes the value the body returned, after `next` resolved to `None`.
+    pub fn take_return(&self) -> R {
+        self.state
+            .borrow_mut()
+            .returned
+            .take()
+            .expect("The async generator returned.")
+    }
+}
+
+impl<T, R> Clone for AsyncGenerator<T, R> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            yielded: self.yielded.clone(),
+        }
+    }
+}
+
+/// The future of `generator.next()`, which polls the body until it yields or returns.
+pub struct Next<T, R> {
+    state: Rc<RefCell<State<R>>>,
+    yielded: Rc<RefCell<Option<T>>>,
+}
+
+impl<T, R> Future for Next<T, R> {
+    type Output = Option<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        // The body is taken out of the state while it is polled, since it may close the generator.
+        let mut body = {
+            let mut state = self.state.borrow_mut();
+
+            if state.done {
+                return Poll::Ready(None);
+            }
+
+            state
+                .body
+                .take()
+                .expect("The async generator isn't already running.")
+        };
+        let poll = body.as_mut().poll(cx);
+        let mut state = self.state.borrow_mut();
+
+        match poll {
+            Poll::Ready(returned) => {
+                state.returned = Some(returned);
+                state.done = true;
+
+                Poll::Ready(None)
+            }
+            Poll::Pending => {
+                if !state.done {
+                    state.body = Some(body);
+                }
+
+                match self.yielded.borrow_mut().take() {
+                    Some(value) => Poll::Ready(Some(value)),
+                    None => Poll::Pending,
+                }
+            }
+        }
+    }
+}
+
+/// The handle the body of an async generator yields its values with.
+pub struct Yielder<T> {
+    yielded: Rc<RefCell<Option<T>>>,
+}
+
+impl<T: 'static> Yielder<T> {
+    /// `yield value`, which suspends the body until `next` is awaited again.
+    pub fn yield_value(&self, value: T) -> Yielding<T> {
+        Yielding {
+            yielded: self.yielded.clone(),
+            value: Some(value),
+        }
+    }
+
+    /// `yield* generator`, which yields all values of another async generator and evaluates to what it returned.
+    pub async fn yield_all<S: 'static>(&self, generator: AsyncGenerator<T, S>) -> S {
+        while let Some(value) = generator.next().await {
+            self.yield_value(value).await;
+        }
+
+        generator.take_return()
+    }
+}
+
+/// The future of `yield value`, which is pending once to hand the value to `next`.
+pub struct Yielding<T> {
+    yielded: Rc<RefCell<Option<T>>>,
+    value: Option<T>,
+}
+
+// The value is moved out of the future, but never pinned.
+impl<T> Unpin for Yielding<T> {}
+
+impl<T> Future for Yielding<T> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<()> {
+        match self.value.take() {
+            // `next` takes the value right after the body is pending, so it doesn't have to be woken.
+            Some(value) => {
+                *self.yielded.borrow_mut() = Some(value);
+
+                Poll::Pending
+            }
+            None => Poll::Ready(()),
+        }
+    }
+}
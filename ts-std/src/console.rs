@@ -1,4 +1,4 @@
-use std::fmt::Display;
+use crate::string::ToJsString;
 
 pub struct Console {}
 
@@ -7,8 +7,8 @@ impl Console {
         assert!(assertion);
     }
 
-    pub fn log<V: Display>(&self, value: V) {
-        println!("{}", value);
+    pub fn log<V: ToJsString>(&self, value: V) {
+        println!("{}", value.to_js_string());
     }
 }
 
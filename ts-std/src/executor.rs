@@ -0,0 +1,102 @@
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Wake, Waker},
+};
+
+use crate::timers::{run_next_timer, set_clock, Clock};
+
+type Task = Pin<Box<dyn Future<Output = ()>>>;
+
+/// A single-threaded executor for the futures of async functions, with a microtask queue like the job queue of
+/// JavaScript.
+#[derive(Default)]
+struct Executor {
+    /// Spawned futures by id, which are `None` while they are polled and once they completed.
+    tasks: Vec<Option<Task>>,
+    microtasks: VecDeque<Box<dyn FnOnce()>>,
+}
+
+thread_local! {
+    static EXECUTOR: RefCell<Executor> = RefCell::default();
+}
+
+/// Wakes a task by polling it right away.
+///
+/// Tasks are only woken by promise reactions, which already run in their own microtask, so polling them in another
+/// one would resume `await` a microtask later than JavaScript does.
+struct TaskWaker(usize);
+
+impl Wake for TaskWaker {
+    fn wake(self: Arc<Self>) {
+        poll_task(self.0);
+    }
+}
+
+/// `queueMicrotask(microtask)`, which runs the function after the current task, and after the microtasks queued
+/// before it.
+pub fn queue_microtask<F: FnOnce() + 'static>(microtask: F) {
+    EXECUTOR.with(|executor| {
+        executor
+            .borrow_mut()
+            .microtasks
+            .push_back(Box::new(microtask))
+    });
+}
+
+/// Runs a future until its first `await` that isn't ready, and then whenever it is woken.
+///
+/// Like an async function in JavaScript, the future starts running synchronously.
+pub fn spawn<F: Future<Output = ()> + 'static>(future: F) {
+    let id = EXECUTOR.with(|executor| {
+        let mut executor = executor.borrow_mut();
+        executor.tasks.push(Some(Box::pin(future)));
+        executor.tasks.len() - 1
+    });
+
+    poll_task(id);
+}
+
+fn poll_task(id: usize) {
+    // Completed tasks aren't polled again.
+    let Some(mut task) = EXECUTOR.with(|executor| executor.borrow_mut().tasks[id].take()) else {
+        return;
+    };
+    let waker = Waker::from(Arc::new(TaskWaker(id)));
+
+    if task
+        .as_mut()
+        .poll(&mut Context::from_waker(&waker))
+        .is_pending()
+    {
+        EXECUTOR.with(|executor| executor.borrow_mut().tasks[id] = Some(task));
+    }
+}
+
+/// Runs the top-level code of a module on the event loop with real time, see `run_main_with_clock`.
+pub fn run_main<F: Future<Output = ()> + 'static>(main: F) {
+    run_main_with_clock(Clock::Real, main);
+}
+
+/// Runs the top-level code of a module, and then the event loop until there is nothing left to run, like Node.
+///
+/// The microtasks are run after the top-level code and after every timer callback, before the next timer fires.
+pub fn run_main_with_clock<F: Future<Output = ()> + 'static>(clock: Clock, main: F) {
+    set_clock(clock);
+    spawn(main);
+
+    loop {
+        while let Some(microtask) =
+            EXECUTOR.with(|executor| executor.borrow_mut().microtasks.pop_front())
+        {
+            microtask();
+        }
+
+        if !run_next_timer() {
+            break;
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use std::vec;
+
+/// The iterator of a generator function, which also has the value the function returned once it is done.
+///
+/// Transpiled generator functions return a `Box<dyn Generator>`, so generators of different functions with the same
+/// types can be stored and delegated to with `yield*`.
+pub trait Generator: Iterator {
+    type Return;
+
+    /// Takes the value the generator function returned, after `next` returned `None`.
+    fn take_return(&mut self) -> Self::Return;
+}
+
+/// Arrays iterated by `yield*` or `for...of` in a generator, which return `undefined` when they are done.
+impl<T> Generator for vec::IntoIter<T> {
+    type Return = ();
+
+    fn take_return(&mut self) {}
+}
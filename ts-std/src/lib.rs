@@ -1,3 +1,33 @@
+mod async_generator;
 mod console;
+mod executor;
+mod generator;
+mod math;
+pub mod number;
+mod object;
+mod promise;
+mod shared;
+pub mod string;
+mod timers;
+mod truthy;
+mod value;
 
+pub use async_generator::{AsyncGenerator, Next, Yielder, Yielding};
 pub use console::console;
+pub use executor::{queue_microtask, run_main, run_main_with_clock};
+pub use generator::Generator;
+pub use math::Math;
+pub use number::ToNumber;
+pub use object::{ordered_keys, Delete, HasProperty, InstanceOf, Object, OwnKeys};
+pub use promise::{Promise, Reject, Resolve, ResolveVoid, Settled};
+pub use shared::Shared;
+pub use string::ToJsString;
+pub use timers::{clear_interval, clear_timeout, set_interval, set_timeout, Clock, Timer};
+pub use truthy::Truthy;
+pub use value::{
+    empty_array, loose_eq, null, strict_eq, type_of, undefined, Infinity, NaN, Null, ToValue,
+    Undefined, Value,
+};
+
+// Functions stored in arrays or reassigned bindings are transpiled to `Rc<dyn Fn>`.
+pub use std::rc::Rc;
@@ -0,0 +1,51 @@
+use crate::number::ToNumber;
+
+pub struct MathObject {}
+
+impl MathObject {
+    pub fn abs<N: ToNumber>(&self, x: N) -> f64 {
+        x.to_number().abs()
+    }
+
+    pub fn ceil<N: ToNumber>(&self, x: N) -> f64 {
+        x.to_number().ceil()
+    }
+
+    pub fn floor<N: ToNumber>(&self, x: N) -> f64 {
+        x.to_number().floor()
+    }
+
+    /// Rounds half-way cases towards positive infinity, unlike `f64::round` which rounds them away from zero.
+    pub fn round<N: ToNumber>(&self, x: N) -> f64 {
+        let x = x.to_number();
+        let floor = x.floor();
+
+        // Negative numbers that round up to zero round to `-0`, e.g. `-0.25`.
+        if x - floor >= 0.5 {
+            (floor + 1.0).copysign(x)
+        } else {
+            floor
+        }
+    }
+
+    pub fn sign<N: ToNumber>(&self, x: N) -> f64 {
+        let x = x.to_number();
+
+        if x == 0.0 || x.is_nan() {
+            x
+        } else {
+            x.signum()
+        }
+    }
+
+    pub fn sqrt<N: ToNumber>(&self, x: N) -> f64 {
+        x.to_number().sqrt()
+    }
+
+    pub fn trunc<N: ToNumber>(&self, x: N) -> f64 {
+        x.to_number().trunc()
+    }
+}
+
+#[allow(non_upper_case_globals)]
+pub const Math: MathObject = MathObject {};
@@ -0,0 +1,229 @@
+//! Number operators with JavaScript semantics, for operators that work differently on Rust floats.
+
+use crate::value::{Null, Undefined, Value};
+
+/// Converts a number to a signed 32-bit integer, like `ToInt32`.
+pub fn to_int32(value: f64) -> i32 {
+    to_uint32(value) as i32
+}
+
+/// Converts a number to an unsigned 32-bit integer, like `ToUint32`.
+pub fn to_uint32(value: f64) -> u32 {
+    if !value.is_finite() {
+        return 0;
+    }
+
+    value.trunc().rem_euclid(4294967296.0) as u32
+}
+
+/// `left & right`
+pub fn bit_and(left: f64, right: f64) -> f64 {
+    (to_int32(left) & to_int32(right)) as f64
+}
+
+/// `left | right`
+pub fn bit_or(left: f64, right: f64) -> f64 {
+    (to_int32(left) | to_int32(right)) as f64
+}
+
+/// `left ^ right`
+pub fn bit_xor(left: f64, right: f64) -> f64 {
+    (to_int32(left) ^ to_int32(right)) as f64
+}
+
+/// `left << right`
+pub fn shl(left: f64, right: f64) -> f64 {
+    to_int32(left).wrapping_shl(to_uint32(right) & 31) as f64
+}
+
+/// `left >> right`
+pub fn shr(left: f64, right: f64) -> f64 {
+    (to_int32(left) >> (to_uint32(right) & 31)) as f64
+}
+
+/// `left >>> right`
+pub fn ushr(left: f64, right: f64) -> f64 {
+    (to_uint32(left) >> (to_uint32(right) & 31)) as f64
+}
+
+/// `~value`
+pub fn bit_not(value: f64) -> f64 {
+    !to_int32(value) as f64
+}
+
+/// `base ** exponent`
+///
+/// Unlike `f64::powf`, `1` and `-1` raised to `NaN` or an infinity are `NaN`.
+pub fn pow(base: f64, exponent: f64) -> f64 {
+    if base.abs() == 1.0 && !exponent.is_finite() {
+        f64::NAN
+    } else {
+        base.powf(exponent)
+    }
+}
+
+/// `Math.max(left, right)`
+///
+/// Unlike `f64::max`, `NaN` wins over any number and `0` over `-0`.
+pub fn max(left: f64, right: f64) -> f64 {
+    if left.is_nan() || right.is_nan() {
+        f64::NAN
+    } else if left == right {
+        if left.is_sign_positive() {
+            left
+        } else {
+            right
+        }
+    } else {
+        left.max(right)
+    }
+}
+
+/// `Math.min(left, right)`
+///
+/// Unlike `f64::min`, `NaN` wins over any number and `-0` over `0`.
+pub fn min(left: f64, right: f64) -> f64 {
+    if left.is_nan() || right.is_nan() {
+        f64::NAN
+    } else if left == right {
+        if left.is_sign_negative() {
+            left
+        } else {
+            right
+        }
+    } else {
+        left.min(right)
+    }
+}
+
+/// Conversion of a value to a `number`, like `ToNumber`.
+///
+/// Integer types are included so that bindings the transpiler narrowed to integers can be passed where a `number` is
+/// expected.
+pub trait ToNumber {
+    fn to_number(&self) -> f64;
+}
+
+impl<T: ToNumber + ?Sized> ToNumber for &T {
+    fn to_number(&self) -> f64 {
+        (**self).to_number()
+    }
+}
+
+impl ToNumber for f64 {
+    fn to_number(&self) -> f64 {
+        *self
+    }
+}
+
+impl ToNumber for i32 {
+    fn to_number(&self) -> f64 {
+        *self as f64
+    }
+}
+
+impl ToNumber for i64 {
+    fn to_number(&self) -> f64 {
+        *self as f64
+    }
+}
+
+impl ToNumber for usize {
+    fn to_number(&self) -> f64 {
+        *self as f64
+    }
+}
+
+impl ToNumber for bool {
+    fn to_number(&self) -> f64 {
+        *self as u8 as f64
+    }
+}
+
+impl ToNumber for str {
+    fn to_number(&self) -> f64 {
+        string_to_number(self)
+    }
+}
+
+impl ToNumber for String {
+    fn to_number(&self) -> f64 {
+        string_to_number(self)
+    }
+}
+
+impl ToNumber for Null {
+    fn to_number(&self) -> f64 {
+        0.0
+    }
+}
+
+impl ToNumber for Undefined {
+    fn to_number(&self) -> f64 {
+        f64::NAN
+    }
+}
+
+/// Absent optional values are `undefined`.
+impl<T: ToNumber> ToNumber for Option<T> {
+    fn to_number(&self) -> f64 {
+        self.as_ref().map_or(f64::NAN, ToNumber::to_number)
+    }
+}
+
+/// Objects are `NaN`, since they aren't converted to primitives.
+impl ToNumber for Value<'_> {
+    fn to_number(&self) -> f64 {
+        match self {
+            Value::Undefined | Value::Object(_) => f64::NAN,
+            Value::Null => 0.0,
+            Value::Boolean(value) => value.to_number(),
+            Value::Number(value) => *value,
+            Value::String(value) => string_to_number(value),
+        }
+    }
+}
+
+/// Converts a string to a number, like `StringToNumber`.
+///
+/// Surrounding whitespace is ignored, an empty string is `0` and anything that isn't a numeric literal is `NaN`.
+pub fn string_to_number(value: &str) -> f64 {
+    let value = value.trim();
+
+    if value.is_empty() {
+        return 0.0;
+    }
+
+    let radix = match value.get(..2) {
+        Some("0x" | "0X") => Some(16),
+        Some("0o" | "0O") => Some(8),
+        Some("0b" | "0B") => Some(2),
+        _ => None,
+    };
+
+    if let Some(radix) = radix {
+        let digits = &value[2..];
+
+        return if !digits.is_empty() && digits.chars().all(|digit| digit.is_digit(radix)) {
+            digits.chars().fold(0.0, |number, digit| {
+                number * radix as f64 + digit.to_digit(radix).expect("Digit is valid.") as f64
+            })
+        } else {
+            f64::NAN
+        };
+    }
+
+    match value.strip_prefix(['+', '-']).unwrap_or(value) {
+        "Infinity" if value.starts_with('-') => f64::NEG_INFINITY,
+        "Infinity" => f64::INFINITY,
+        // Rust also accepts names like `inf` and `NaN`, which aren't numeric literals.
+        unsigned
+            if unsigned.chars().all(|char| {
+                char.is_ascii_digit() || matches!(char, '.' | 'e' | 'E' | '+' | '-')
+            }) =>
+        {
+            value.parse().unwrap_or(f64::NAN)
+        }
+        _ => f64::NAN,
+    }
+}
@@ -0,0 +1,227 @@
+use std::{
+    ops::{Index, IndexMut},
+    rc::Rc,
+};
+
+use crate::value::Value;
+
+/// Object with dynamic properties, used for values that don't map to a generated struct.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Object<V> {
+    properties: Vec<(String, V)>,
+}
+
+impl<V> Object<V> {
+    pub fn new() -> Self {
+        Object { properties: vec![] }
+    }
+
+    pub fn get(&self, key: &str) -> Option<&V> {
+        self.properties
+            .iter()
+            .find(|(property, _)| property == key)
+            .map(|(_, value)| value)
+    }
+
+    pub fn set<K: Into<String>>(&mut self, key: K, value: V) {
+        let key = key.into();
+
+        match self
+            .properties
+            .iter_mut()
+            .find(|(property, _)| *property == key)
+        {
+            Some((_, existing)) => *existing = value,
+            None => self.properties.push((key, value)),
+        }
+    }
+
+    pub fn has(&self, key: &str) -> bool {
+        self.properties.iter().any(|(property, _)| property == key)
+    }
+
+    pub fn remove(&mut self, key: &str) -> Option<V> {
+        self.properties
+            .iter()
+            .position(|(property, _)| property == key)
+            .map(|index| self.properties.remove(index).1)
+    }
+}
+
+/// Object literals, e.g. `{ a: 1, b: 2 }`, where later properties replace earlier ones with the same key.
+impl<K: Into<String>, V, const N: usize> From<[(K, V); N]> for Object<V> {
+    fn from(properties: [(K, V); N]) -> Self {
+        let mut object = Object::new();
+
+        for (key, value) in properties {
+            object.set(key, value);
+        }
+
+        object
+    }
+}
+
+/// Property reads, e.g. `object[key]`, of properties that the type of the object says are present.
+impl<K: AsRef<str>, V> Index<K> for Object<V> {
+    type Output = V;
+
+    fn index(&self, key: K) -> &V {
+        self.get(key.as_ref()).expect("Object has the property.")
+    }
+}
+
+/// Property updates, e.g. `object[key] *= 2`, of properties that the type of the object says are present.
+impl<K: AsRef<str>, V> IndexMut<K> for Object<V> {
+    fn index_mut(&mut self, key: K) -> &mut V {
+        let key = key.as_ref();
+
+        self.properties
+            .iter_mut()
+            .find(|(property, _)| property == key)
+            .map(|(_, value)| value)
+            .expect("Object has the property.")
+    }
+}
+
+impl<V> Default for Object<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Removal of a property with `delete`, which always succeeds for the properties that can be removed.
+pub trait Delete {
+    fn delete(&mut self, key: &str) -> bool;
+}
+
+impl<V> Delete for Object<V> {
+    fn delete(&mut self, key: &str) -> bool {
+        self.remove(key);
+        true
+    }
+}
+
+/// Property lookup with `in`, which also finds properties that are present but `undefined`.
+pub trait HasProperty {
+    fn has_property(&self, key: &str) -> bool;
+}
+
+impl<T: HasProperty + ?Sized> HasProperty for &T {
+    fn has_property(&self, key: &str) -> bool {
+        (**self).has_property(key)
+    }
+}
+
+impl<V> HasProperty for Object<V> {
+    fn has_property(&self, key: &str) -> bool {
+        self.has(key)
+    }
+}
+
+impl<T> HasProperty for [T] {
+    fn has_property(&self, key: &str) -> bool {
+        key == "length" || array_index(key).is_some_and(|index| (index as usize) < self.len())
+    }
+}
+
+impl<T> HasProperty for Vec<T> {
+    fn has_property(&self, key: &str) -> bool {
+        self.as_slice().has_property(key)
+    }
+}
+
+/// Class membership with `instanceof`, by the names of the class of a value and its superclasses.
+pub trait InstanceOf {
+    fn instance_of(&self, class: &str) -> bool;
+}
+
+impl<T: InstanceOf + ?Sized> InstanceOf for &T {
+    fn instance_of(&self, class: &str) -> bool {
+        (**self).instance_of(class)
+    }
+}
+
+impl<T: InstanceOf + ?Sized> InstanceOf for Rc<T> {
+    fn instance_of(&self, class: &str) -> bool {
+        (**self).instance_of(class)
+    }
+}
+
+/// Absent optional values are `undefined`, which isn't an instance of any class.
+impl<T: InstanceOf> InstanceOf for Option<T> {
+    fn instance_of(&self, class: &str) -> bool {
+        self.as_ref().is_some_and(|value| value.instance_of(class))
+    }
+}
+
+/// Primitives are never instances, and dynamic objects only of `Object`, since their class isn't known.
+impl InstanceOf for Value<'_> {
+    fn instance_of(&self, class: &str) -> bool {
+        matches!(self, Value::Object(_)) && class == "Object"
+    }
+}
+
+impl<V> InstanceOf for Object<V> {
+    fn instance_of(&self, class: &str) -> bool {
+        class == "Object"
+    }
+}
+
+impl<T> InstanceOf for [T] {
+    fn instance_of(&self, class: &str) -> bool {
+        matches!(class, "Array" | "Object")
+    }
+}
+
+impl<T> InstanceOf for Vec<T> {
+    fn instance_of(&self, class: &str) -> bool {
+        self.as_slice().instance_of(class)
+    }
+}
+
+/// Enumerable own property keys, in the order used by `for...in` and `Object.keys`.
+pub trait OwnKeys {
+    fn own_keys(&self) -> Vec<String>;
+}
+
+impl<V> OwnKeys for Object<V> {
+    fn own_keys(&self) -> Vec<String> {
+        ordered_keys(self.properties.iter().map(|(property, _)| property.clone()))
+    }
+}
+
+impl<T> OwnKeys for [T] {
+    fn own_keys(&self) -> Vec<String> {
+        (0..self.len()).map(|index| index.to_string()).collect()
+    }
+}
+
+impl<T> OwnKeys for Vec<T> {
+    fn own_keys(&self) -> Vec<String> {
+        self.as_slice().own_keys()
+    }
+}
+
+/// Orders keys like JavaScript: array index keys ascending, followed by the other keys in insertion order.
+pub fn ordered_keys<K: Into<String>, I: IntoIterator<Item = K>>(keys: I) -> Vec<String> {
+    let (mut indices, names): (Vec<String>, Vec<String>) = keys
+        .into_iter()
+        .map(Into::into)
+        .partition(|key| array_index(key).is_some());
+
+    indices.sort_by_key(|key| array_index(key));
+    indices.extend(names);
+    indices
+}
+
+/// Parses a canonical array index (`0` to `2^32 - 2`), e.g. `"7"` but not `"07"` or `"-1"`.
+fn array_index(key: &str) -> Option<u32> {
+    if key.is_empty()
+        || (key.len() > 1 && key.starts_with('0'))
+        || !key.bytes().all(|byte| byte.is_ascii_digit())
+    {
+        return None;
+    }
+
+    key.parse::<u32>().ok().filter(|index| *index != u32::MAX)
+}
@@ -0,0 +1,362 @@
+use std::{
+    cell::{Cell, RefCell},
+    future::{Future, IntoFuture},
+    pin::Pin,
+    rc::Rc,
+    task::{Context, Poll},
+};
+
+use crate::{
+    executor::{queue_microtask, spawn},
+    string::ToJsString,
+};
+
+/// How a promise settled, with its value or the reason it was rejected for.
+///
+/// Reasons are kept as their string conversion, e.g. `"timeout"` for `reject("timeout")`.
+type Settlement<T> = Result<T, String>;
+
+const ALL_REJECTED: &str = "AggregateError: All promises were rejected";
+
+/// The `resolve` function that `Promise::new` passes to its executor.
+pub type Resolve<T> = Rc<dyn Fn(T)>;
+
+/// The `resolve` function that `Promise::new_void` passes to its executor, which takes no value.
+pub type ResolveVoid = Rc<dyn Fn()>;
+
+/// The `reject` function that `Promise::new` passes to its executor.
+pub type Reject = Rc<dyn Fn(&str)>;
+
+/// The eventual result of an async operation, which can be awaited and chained any number of times.
+///
+/// Clones share the result, like references to the same promise in JavaScript. Callbacks run in microtasks in the
+/// same order as in Node, so the order of their side effects matches.
+pub struct Promise<T> {
+    state: Rc<RefCell<PromiseState<T>>>,
+}
+
+struct PromiseState<T> {
+    settlement: Option<Settlement<T>>,
+    /// Callbacks waiting for the promise to settle, which run in microtasks in the order they were added.
+    reactions: Vec<Box<dyn FnOnce(Settlement<T>)>>,
+}
+
+/// The outcome of a promise in the result of `Promise.allSettled`, like its `{ status, value, reason }` objects.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Settled<T> {
+    /// `"fulfilled"` or `"rejected"`.
+    pub status: &'static str,
+    pub value: Option<T>,
+    pub reason: Option<String>,
+}
+
+impl<T: Clone + 'static> Promise<T> {
+    fn with_settlement(settlement: Option<Settlement<T>>) -> Self {
+        Promise {
+            state: Rc::new(RefCell::new(PromiseState {
+                settlement,
+                reactions: vec![],
+            })),
+        }
+    }
+
+    /// `new Promise(executor)`, which calls the executor with functions that resolve or reject the promise.
+    pub fn new<F: FnOnce(Resolve<T>, Reject)>(executor: F) -> Self {
+        let promise = Promise::with_settlement(None);
+        let resolved = promise.clone();
+        let rejected = promise.clone();
+
+        executor(
+            Rc::new(move |value| resolved.settle(Ok(value))),
+            Rc::new(move |reason| rejected.settle(Err(reason.to_string()))),
+        );
+
+        promise
+    }
+
+    /// `Promise.resolve(value)`.
+    pub fn resolve(value: T) -> Self {
+        Promise::with_settlement(Some(Ok(value)))
+    }
+
+    /// `Promise.reject(reason)`.
+    pub fn reject<R: ToJsString>(reason: R) -> Self {
+        Promise::with_settlement(Some(Err(reason.to_js_string())))
+    }
+
+    /// Runs a future like an async function and returns a promise of its output, e.g. for `const p = f();`.
+    pub fn from_future<F: Future<Output = T> + 'static>(future: F) -> Self {
+        let promise = Promise::with_settlement(None);
+        let settled = promise.clone();
+
+        spawn(async move {
+            let value = future.await;
+            settled.settle(Ok(value));
+        });
+
+        promise
+    }
+
+    /// `Promise.all(promises)`, which fulfills with all values once every promise is fulfilled, or rejects like the
+    /// first promise that is rejected.
+    pub fn all(promises: Vec<Promise<T>>) -> Promise<Vec<T>> {
+        Promise::collect(promises, |settlement| settlement)
+    }
+
+    /// `Promise.allSettled(promises)`, which fulfills with the outcome of every promise once they are all settled.
+    pub fn all_settled(promises: Vec<Promise<T>>) -> Promise<Vec<Settled<T>>> {
+        Promise::collect(promises, |settlement| {
+            Ok(match settlement {
+                Ok(value) => Settled {
+                    status: "fulfilled",
+                    value: Some(value),
+                    reason: None,
+                },
+                Err(reason) => Settled {
+                    status: "rejected",
+                    value: None,
+                    reason: Some(reason),
+                },
+            })
+        })
+    }
+
+    /// `Promise.race(promises)`, which settles like the first promise that settles.
+    pub fn race(promises: Vec<Promise<T>>) -> Promise<T> {
+        let promise = Promise::with_settlement(None);
+
+        for racing in promises {
+            let settled = promise.clone();
+
+            racing.react(move |settlement| settled.settle(settlement));
+        }
+
+        promise
+    }
+
+    /// `Promise.any(promises)`, which fulfills like the first promise that is fulfilled, or rejects once they are all
+    /// rejected.
+    pub fn any(promises: Vec<Promise<T>>) -> Promise<T> {
+        let promise = Promise::with_settlement(None);
+        let remaining = Rc::new(Cell::new(promises.len()));
+
+        if promises.is_empty() {
+            promise.settle(Err(ALL_REJECTED.to_string()));
+        }
+
+        for racing in promises {
+            let settled = promise.clone();
+            let remaining = remaining.clone();
+
+            racing.react(move |settlement| match settlement {
+                Ok(value) => settled.settle(Ok(value)),
+                Err(_) => {
+                    remaining.set(remaining.get() - 1);
+
+                    if remaining.get() == 0 {
+                        settled.settle(Err(ALL_REJECTED.to_string()));
+                    }
+                }
+            });
+        }
+
+        promise
+    }
+
+    /// `promise.then(on_fulfilled)` for callbacks that return a value.
+    pub fn then<U: Clone + 'static, F: FnOnce(T) -> U + 'static>(
+        &self,
+        on_fulfilled: F,
+    ) -> Promise<U> {
+        self.map_settlement(move |settlement| settlement.map(on_fulfilled))
+    }
+
+    /// `promise.then(on_fulfilled)` for callbacks that return another promise, which the result settles like.
+    pub fn and_then<U: Clone + 'static, F: FnOnce(T) -> Promise<U> + 'static>(
+        &self,
+        on_fulfilled: F,
+    ) -> Promise<U> {
+        let promise = Promise::with_settlement(None);
+        let settled = promise.clone();
+
+        self.react(move |settlement| match settlement {
+            Ok(value) => settled.settle_with(on_fulfilled(value)),
+            Err(reason) => settled.settle(Err(reason)),
+        });
+
+        promise
+    }
+
+    /// `promise.catch(on_rejected)`, which fulfills with the value of the callback if the promise is rejected.
+    pub fn catch<F: FnOnce(String) -> T + 'static>(&self, on_rejected: F) -> Promise<T> {
+        self.map_settlement(move |settlement| Ok(settlement.unwrap_or_else(on_rejected)))
+    }
+
+    /// `promise.finally(on_finally)`, which settles like the promise after calling the callback.
+    pub fn finally<F: FnOnce() + 'static>(&self, on_finally: F) -> Promise<T> {
+        let promise = Promise::with_settlement(None);
+        let settled = promise.clone();
+
+        // The settlement is passed on through another promise, which takes as many microtasks as in JavaScript.
+        self.react(move |settlement| {
+            on_finally();
+            settled.settle_with(Promise::resolve(()).map_settlement(move |_| settlement));
+        });
+
+        promise
+    }
+
+    fn map_settlement<U: Clone + 'static, F: FnOnce(Settlement<T>) -> Settlement<U> + 'static>(
+        &self,
+        f: F,
+    ) -> Promise<U> {
+        let promise = Promise::with_settlement(None);
+        let settled = promise.clone();
+
+        self.react(move |settlement| settled.settle(f(settlement)));
+
+        promise
+    }
+
+    /// Fulfills with the values of all promises once they are all settled, where `f` converts their settlements and
+    /// the first one it converts to an error rejects the result right away.
+    fn collect<U: Clone + 'static>(
+        promises: Vec<Promise<T>>,
+        f: fn(Settlement<T>) -> Settlement<U>,
+    ) -> Promise<Vec<U>> {
+        let promise = Promise::with_settlement(None);
+
+        if promises.is_empty() {
+            promise.settle(Ok(vec![]));
+        }
+
+        let values = Rc::new(RefCell::new(vec![None; promises.len()]));
+        let remaining = Rc::new(Cell::new(promises.len()));
+
+        for (index, collected) in promises.into_iter().enumerate() {
+            let settled = promise.clone();
+            let values = values.clone();
+            let remaining = remaining.clone();
+
+            collected.react(move |settlement| match f(settlement) {
+                Ok(value) => {
+                    values.borrow_mut()[index] = Some(value);
+                    remaining.set(remaining.get() - 1);
+
+                    if remaining.get() == 0 {
+                        settled.settle(Ok(values.take().into_iter().flatten().collect()));
+                    }
+                }
+                Err(reason) => settled.settle(Err(reason)),
+            });
+        }
+
+        promise
+    }
+
+    /// Settles the promise, unless it already settled, and queues its reactions.
+    fn settle(&self, settlement: Settlement<T>) {
+        let reactions = {
+            let mut state = self.state.borrow_mut();
+
+            if state.settlement.is_some() {
+                return;
+            }
+
+            state.settlement = Some(settlement.clone());
+            std::mem::take(&mut state.reactions)
+        };
+
+        for reaction in reactions {
+            let settlement = settlement.clone();
+
+            queue_microtask(move || reaction(settlement));
+        }
+    }
+
+    /// Settles the promise like another promise, which takes two microtasks like resolving with a thenable in
+    /// JavaScript.
+    fn settle_with(&self, promise: Promise<T>) {
+        let settled = self.clone();
+
+        queue_microtask(move || promise.react(move |settlement| settled.settle(settlement)));
+    }
+
+    /// Adds a callback that runs in a microtask once the promise is settled.
+    fn react<F: FnOnce(Settlement<T>) + 'static>(&self, reaction: F) {
+        let mut state = self.state.borrow_mut();
+
+        match &state.settlement {
+            Some(settlement) => {
+                let settlement = settlement.clone();
+                drop(state);
+
+                queue_microtask(move || reaction(settlement));
+            }
+            None => state.reactions.push(Box::new(reaction)),
+        }
+    }
+}
+
+impl Promise<()> {
+    /// `new Promise<void>(executor)`, where `resolve` takes no value.
+    pub fn new_void<F: FnOnce(ResolveVoid, Reject)>(executor: F) -> Self {
+        Promise::new(|resolve: Resolve<()>, reject| executor(Rc::new(move || resolve(())), reject))
+    }
+}
+
+impl<T> Clone for Promise<T> {
+    fn clone(&self) -> Self {
+        Promise {
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl<T: Clone + 'static> IntoFuture for Promise<T> {
+    type Output = T;
+    type IntoFuture = Awaiting<T>;
+
+    fn into_future(self) -> Awaiting<T> {
+        Awaiting {
+            promise: self,
+            settlement: Rc::new(RefCell::new(None)),
+            reacting: false,
+        }
+    }
+}
+
+/// The future of `await promise`, which resumes in a microtask once the promise is settled, even if it already is.
+///
+/// Awaiting a rejected promise panics, since the rejection can't be caught.
+pub struct Awaiting<T> {
+    promise: Promise<T>,
+    settlement: Rc<RefCell<Option<Settlement<T>>>>,
+    reacting: bool,
+}
+
+impl<T: Clone + 'static> Future for Awaiting<T> {
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        if let Some(settlement) = self.settlement.take() {
+            return match settlement {
+                Ok(value) => Poll::Ready(value),
+                Err(reason) => panic!("Uncaught (in promise) {}", reason),
+            };
+        }
+
+        if !self.reacting {
+            let settlement = self.settlement.clone();
+            let waker = cx.waker().clone();
+
+            self.reacting = true;
+            self.promise.react(move |settled| {
+                *settlement.borrow_mut() = Some(settled);
+                waker.wake();
+            });
+        }
+
+        Poll::Pending
+    }
+}
@@ -0,0 +1,38 @@
+use std::{cell::RefCell, fmt, rc::Rc};
+
+/// A binding that closures share with the code around them, e.g. a counter that a timer callback increments.
+///
+/// Like JavaScript bindings, clones share the same value, so callbacks that are moved to the event loop still change
+/// the binding of the code that created them.
+#[derive(Default)]
+pub struct Shared<T>(Rc<RefCell<T>>);
+
+impl<T> Shared<T> {
+    pub fn new(value: T) -> Self {
+        Shared(Rc::new(RefCell::new(value)))
+    }
+
+    /// Assigns the binding.
+    pub fn set(&self, value: T) {
+        *self.0.borrow_mut() = value;
+    }
+}
+
+impl<T: Clone> Shared<T> {
+    /// Reads the binding, which clones the value so the binding isn't borrowed while it is used.
+    pub fn get(&self) -> T {
+        self.0.borrow().clone()
+    }
+}
+
+impl<T> Clone for Shared<T> {
+    fn clone(&self) -> Self {
+        Shared(self.0.clone())
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Shared<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.borrow().fmt(f)
+    }
+}
@@ -0,0 +1,162 @@
+//! String operations with JavaScript semantics, where strings are sequences of UTF-16 code units.
+
+use crate::value::{Null, Undefined, Value};
+
+/// `value.length`, which counts UTF-16 code units rather than bytes.
+pub fn length(value: &str) -> usize {
+    value.encode_utf16().count()
+}
+
+/// Conversion of a value to a `string`, like `ToString`, e.g. for `"a" + b`.
+pub trait ToJsString {
+    fn to_js_string(&self) -> String;
+}
+
+impl<T: ToJsString + ?Sized> ToJsString for &T {
+    fn to_js_string(&self) -> String {
+        (**self).to_js_string()
+    }
+}
+
+impl ToJsString for str {
+    fn to_js_string(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl ToJsString for String {
+    fn to_js_string(&self) -> String {
+        self.clone()
+    }
+}
+
+impl ToJsString for f64 {
+    fn to_js_string(&self) -> String {
+        number_to_string(*self)
+    }
+}
+
+impl ToJsString for i32 {
+    fn to_js_string(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl ToJsString for i64 {
+    fn to_js_string(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl ToJsString for usize {
+    fn to_js_string(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl ToJsString for bool {
+    fn to_js_string(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl ToJsString for Null {
+    fn to_js_string(&self) -> String {
+        "null".to_string()
+    }
+}
+
+impl ToJsString for Undefined {
+    fn to_js_string(&self) -> String {
+        "undefined".to_string()
+    }
+}
+
+/// Arrays are their elements joined with commas, like `Array::join`.
+impl<T: ToJsString> ToJsString for [T] {
+    fn to_js_string(&self) -> String {
+        self.iter()
+            .map(ToJsString::to_js_string)
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+impl<T: ToJsString> ToJsString for Vec<T> {
+    fn to_js_string(&self) -> String {
+        self.as_slice().to_js_string()
+    }
+}
+
+/// Absent optional values are `undefined`.
+impl<T: ToJsString> ToJsString for Option<T> {
+    fn to_js_string(&self) -> String {
+        match self {
+            Some(value) => value.to_js_string(),
+            None => "undefined".to_string(),
+        }
+    }
+}
+
+/// Objects are `[object Object]`, since they don't have a custom `toString`.
+impl ToJsString for Value<'_> {
+    fn to_js_string(&self) -> String {
+        match self {
+            Value::Undefined => Undefined.to_js_string(),
+            Value::Null => Null.to_js_string(),
+            Value::Boolean(value) => value.to_js_string(),
+            Value::Number(value) => value.to_js_string(),
+            Value::String(value) => value.to_js_string(),
+            Value::Object(_) => "[object Object]".to_string(),
+        }
+    }
+}
+
+/// Converts a number to a string, like `Number::toString`.
+///
+/// Uses the shortest digits that round-trip, in exponential notation below `1e-6` and from `1e21`, e.g. `1e+21`.
+pub fn number_to_string(value: f64) -> String {
+    if value.is_nan() {
+        return "NaN".to_string();
+    }
+    if value == 0.0 {
+        return "0".to_string();
+    }
+    if value < 0.0 {
+        return format!("-{}", number_to_string(-value));
+    }
+    if value.is_infinite() {
+        return "Infinity".to_string();
+    }
+
+    // Rust's exponential format also uses the shortest round-trip digits, e.g. `1.5e-7`.
+    let exponential = format!("{:e}", value);
+    let (mantissa, exponent) = exponential
+        .split_once('e')
+        .expect("Exponential format has an exponent.");
+    let digits = mantissa.replace('.', "");
+    let count = digits.len() as i32;
+    // The decimal point is after the first `point` digits.
+    let point = exponent.parse::<i32>().expect("Exponent is an integer.") + 1;
+
+    if count <= point && point <= 21 {
+        format!("{}{}", digits, "0".repeat((point - count) as usize))
+    } else if 0 < point && point <= 21 {
+        format!(
+            "{}.{}",
+            &digits[..point as usize],
+            &digits[point as usize..]
+        )
+    } else if -6 < point && point <= 0 {
+        format!("0.{}{}", "0".repeat(-point as usize), digits)
+    } else {
+        let fraction = if count > 1 {
+            format!(".{}", &digits[1..])
+        } else {
+            String::new()
+        };
+        let sign = if point > 0 { "+" } else { "-" };
+
+        format!("{}{}e{}{}", &digits[..1], fraction, sign, (point - 1).abs())
+    }
+}
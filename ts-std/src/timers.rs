@@ -0,0 +1,184 @@
+use std::{
+    cell::RefCell,
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::number::ToNumber;
+
+/// The longest delay of a timer, like in Node, where longer delays fire after 1ms instead.
+const MAX_DELAY: f64 = 2147483647.0;
+
+/// The time that timers fire at.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Clock {
+    /// Wall-clock time, where the event loop sleeps until the next timer is due.
+    #[default]
+    Real,
+    /// Time that jumps to the next timer as soon as there is nothing else to run, so programs with timers run
+    /// instantly and deterministically, e.g. in tests.
+    Virtual,
+}
+
+/// A timer from `set_timeout` or `set_interval`, which `clear_timeout` and `clear_interval` cancel.
+///
+/// The default timer was never set, like a binding that a timer callback refers to before the timer is returned.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Timer(u64);
+
+enum TimerCallback {
+    Timeout(Box<dyn FnOnce()>),
+    Interval(Box<dyn FnMut()>, Duration),
+}
+
+struct Timers {
+    clock: Clock,
+    start: Instant,
+    /// The time of the virtual clock since the start of the event loop.
+    virtual_time: Duration,
+    /// The id of the next timer, starting at 1 since the default timer is 0.
+    next_id: u64,
+    /// Due times of the timers, where timers that are due at the same time fire in the order they were set.
+    queue: BinaryHeap<Reverse<(Duration, u64)>>,
+    /// Callbacks of the timers that weren't cleared, which are `None` while an interval runs.
+    callbacks: HashMap<u64, Option<TimerCallback>>,
+}
+
+impl Timers {
+    fn now(&self) -> Duration {
+        match self.clock {
+            Clock::Real => self.start.elapsed(),
+            Clock::Virtual => self.virtual_time,
+        }
+    }
+
+    fn schedule(&mut self, id: u64, delay: Duration) {
+        let due = self.now() + delay;
+
+        self.queue.push(Reverse((due, id)));
+    }
+}
+
+thread_local! {
+    static TIMERS: RefCell<Timers> = RefCell::new(Timers {
+        clock: Clock::Real,
+        start: Instant::now(),
+        virtual_time: Duration::ZERO,
+        next_id: 1,
+        queue: BinaryHeap::new(),
+        callbacks: HashMap::new(),
+    });
+}
+
+/// The delay of a timer in Node, which is at least 1ms.
+fn timer_delay<N: ToNumber>(delay: N) -> Duration {
+    let delay = delay.to_number();
+
+    if (1.0..=MAX_DELAY).contains(&delay) {
+        Duration::from_secs_f64(delay / 1000.0)
+    } else {
+        Duration::from_millis(1)
+    }
+}
+
+fn set_timer(callback: TimerCallback, delay: Duration) -> Timer {
+    TIMERS.with(|timers| {
+        let mut timers = timers.borrow_mut();
+        let id = timers.next_id;
+
+        timers.next_id += 1;
+        timers.callbacks.insert(id, Some(callback));
+        timers.schedule(id, delay);
+
+        Timer(id)
+    })
+}
+
+/// `setTimeout(callback, delay)`, which calls the callback once after `delay` milliseconds.
+pub fn set_timeout<F: FnOnce() + 'static, N: ToNumber>(callback: F, delay: N) -> Timer {
+    set_timer(
+        TimerCallback::Timeout(Box::new(callback)),
+        timer_delay(delay),
+    )
+}
+
+/// `setInterval(callback, delay)`, which calls the callback every `delay` milliseconds until it is cleared.
+pub fn set_interval<F: FnMut() + 'static, N: ToNumber>(callback: F, delay: N) -> Timer {
+    let delay = timer_delay(delay);
+
+    set_timer(TimerCallback::Interval(Box::new(callback), delay), delay)
+}
+
+/// `clearTimeout(timer)`, which also clears intervals like in JavaScript.
+pub fn clear_timeout(timer: Timer) {
+    TIMERS.with(|timers| timers.borrow_mut().callbacks.remove(&timer.0));
+}
+
+/// `clearInterval(timer)`, which also clears timeouts like in JavaScript.
+pub fn clear_interval(timer: Timer) {
+    clear_timeout(timer);
+}
+
+pub(crate) fn set_clock(clock: Clock) {
+    TIMERS.with(|timers| {
+        let mut timers = timers.borrow_mut();
+
+        timers.clock = clock;
+        timers.start = Instant::now();
+        timers.virtual_time = Duration::ZERO;
+    });
+}
+
+/// Waits for the next timer and calls its callback, or returns `false` if there are no timers left.
+pub(crate) fn run_next_timer() -> bool {
+    let next = TIMERS.with(|timers| {
+        let mut timers = timers.borrow_mut();
+
+        // Cleared timers are skipped when they are due.
+        while let Some(Reverse((due, id))) = timers.queue.pop() {
+            if let Some(callback) = timers.callbacks.get_mut(&id).and_then(Option::take) {
+                return Some((due, id, callback, timers.clock, timers.now()));
+            }
+        }
+
+        None
+    });
+    let Some((due, id, callback, clock, now)) = next else {
+        return false;
+    };
+
+    match clock {
+        Clock::Real if due > now => thread::sleep(due - now),
+        Clock::Real => {}
+        Clock::Virtual => TIMERS.with(|timers| {
+            let mut timers = timers.borrow_mut();
+
+            timers.virtual_time = timers.virtual_time.max(due);
+        }),
+    }
+
+    match callback {
+        TimerCallback::Timeout(callback) => {
+            TIMERS.with(|timers| timers.borrow_mut().callbacks.remove(&id));
+
+            callback();
+        }
+        TimerCallback::Interval(mut callback, delay) => {
+            callback();
+
+            // The interval is rescheduled after its callback, unless the callback cleared it.
+            TIMERS.with(|timers| {
+                let mut timers = timers.borrow_mut();
+
+                if let Some(slot) = timers.callbacks.get_mut(&id) {
+                    *slot = Some(TimerCallback::Interval(callback, delay));
+                    timers.schedule(id, delay);
+                }
+            });
+        }
+    }
+
+    true
+}
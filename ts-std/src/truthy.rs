@@ -0,0 +1,116 @@
+use std::rc::Rc;
+
+use crate::{
+    object::Object,
+    value::{Null, Undefined, Value},
+};
+
+/// Conversion of a value to a boolean, like `ToBoolean`.
+///
+/// `false`, `0`, `NaN`, `""`, `null` and `undefined` are falsy, everything else is truthy.
+pub trait Truthy {
+    fn truthy(&self) -> bool;
+}
+
+impl<T: Truthy + ?Sized> Truthy for &T {
+    fn truthy(&self) -> bool {
+        (**self).truthy()
+    }
+}
+
+impl Truthy for bool {
+    fn truthy(&self) -> bool {
+        *self
+    }
+}
+
+impl Truthy for f64 {
+    fn truthy(&self) -> bool {
+        *self != 0.0 && !self.is_nan()
+    }
+}
+
+impl Truthy for i32 {
+    fn truthy(&self) -> bool {
+        *self != 0
+    }
+}
+
+impl Truthy for i64 {
+    fn truthy(&self) -> bool {
+        *self != 0
+    }
+}
+
+impl Truthy for usize {
+    fn truthy(&self) -> bool {
+        *self != 0
+    }
+}
+
+impl Truthy for str {
+    fn truthy(&self) -> bool {
+        !self.is_empty()
+    }
+}
+
+impl Truthy for String {
+    fn truthy(&self) -> bool {
+        !self.is_empty()
+    }
+}
+
+impl Truthy for Null {
+    fn truthy(&self) -> bool {
+        false
+    }
+}
+
+impl Truthy for Undefined {
+    fn truthy(&self) -> bool {
+        false
+    }
+}
+
+/// Absent optional values are `undefined`, present ones are truthy depending on their value.
+impl<T: Truthy> Truthy for Option<T> {
+    fn truthy(&self) -> bool {
+        self.as_ref().is_some_and(Truthy::truthy)
+    }
+}
+
+impl<T: ?Sized> Truthy for Rc<T> {
+    fn truthy(&self) -> bool {
+        true
+    }
+}
+
+impl<T> Truthy for [T] {
+    fn truthy(&self) -> bool {
+        true
+    }
+}
+
+impl<T> Truthy for Vec<T> {
+    fn truthy(&self) -> bool {
+        true
+    }
+}
+
+impl<V> Truthy for Object<V> {
+    fn truthy(&self) -> bool {
+        true
+    }
+}
+
+impl Truthy for Value<'_> {
+    fn truthy(&self) -> bool {
+        match self {
+            Value::Undefined | Value::Null => false,
+            Value::Boolean(value) => *value,
+            Value::Number(value) => value.truthy(),
+            Value::String(value) => value.truthy(),
+            Value::Object(_) => true,
+        }
+    }
+}
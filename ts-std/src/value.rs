@@ -0,0 +1,198 @@
+//! Runtime view of values, for operators whose semantics depend on the types of their operands.
+
+use std::rc::Rc;
+
+use crate::{number::string_to_number, object::Object};
+
+/// The type `null`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Null;
+
+/// The type `undefined`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Undefined;
+
+#[allow(non_upper_case_globals)]
+pub const null: Null = Null;
+
+#[allow(non_upper_case_globals)]
+pub const undefined: Undefined = Undefined;
+
+#[allow(non_upper_case_globals)]
+pub const NaN: f64 = f64::NAN;
+
+#[allow(non_upper_case_globals)]
+pub const Infinity: f64 = f64::INFINITY;
+
+/// A value by its TypeScript type, where objects are identified by their address.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Value<'a> {
+    Undefined,
+    Null,
+    Boolean(bool),
+    Number(f64),
+    String(&'a str),
+    Object(usize),
+}
+
+impl Value<'_> {
+    /// The result of `typeof`.
+    pub fn type_of(&self) -> &'static str {
+        match self {
+            Value::Undefined => "undefined",
+            Value::Null | Value::Object(_) => "object",
+            Value::Boolean(_) => "boolean",
+            Value::Number(_) => "number",
+            Value::String(_) => "string",
+        }
+    }
+
+    /// `===`, the Strict Equality Comparison.
+    pub fn strict_eq(&self, other: &Value) -> bool {
+        match (self, other) {
+            (Value::Undefined, Value::Undefined) | (Value::Null, Value::Null) => true,
+            (Value::Boolean(left), Value::Boolean(right)) => left == right,
+            // NaN isn't equal to itself for `f64` either.
+            (Value::Number(left), Value::Number(right)) => left == right,
+            (Value::String(left), Value::String(right)) => left == right,
+            (Value::Object(left), Value::Object(right)) => left == right,
+            _ => false,
+        }
+    }
+
+    /// `==`, the Abstract Equality Comparison.
+    ///
+    /// Objects are never converted to primitives, so they are only equal to themselves.
+    pub fn loose_eq(&self, other: &Value) -> bool {
+        match (self, other) {
+            (Value::Undefined | Value::Null, Value::Undefined | Value::Null) => true,
+            (Value::Undefined | Value::Null, _) | (_, Value::Undefined | Value::Null) => false,
+            (Value::Number(left), Value::String(right)) => *left == string_to_number(right),
+            (Value::String(left), Value::Number(right)) => string_to_number(left) == *right,
+            (Value::Boolean(left), right) => Value::Number(*left as u8 as f64).loose_eq(right),
+            (left, Value::Boolean(right)) => left.loose_eq(&Value::Number(*right as u8 as f64)),
+            (left, right) => left.strict_eq(right),
+        }
+    }
+}
+
+/// Conversion to the runtime view of a value.
+pub trait ToValue {
+    fn to_value(&self) -> Value<'_>;
+}
+
+impl<T: ToValue + ?Sized> ToValue for &T {
+    fn to_value(&self) -> Value<'_> {
+        (**self).to_value()
+    }
+}
+
+impl ToValue for Undefined {
+    fn to_value(&self) -> Value<'_> {
+        Value::Undefined
+    }
+}
+
+impl ToValue for Null {
+    fn to_value(&self) -> Value<'_> {
+        Value::Null
+    }
+}
+
+impl ToValue for bool {
+    fn to_value(&self) -> Value<'_> {
+        Value::Boolean(*self)
+    }
+}
+
+impl ToValue for f64 {
+    fn to_value(&self) -> Value<'_> {
+        Value::Number(*self)
+    }
+}
+
+impl ToValue for i32 {
+    fn to_value(&self) -> Value<'_> {
+        Value::Number(*self as f64)
+    }
+}
+
+impl ToValue for i64 {
+    fn to_value(&self) -> Value<'_> {
+        Value::Number(*self as f64)
+    }
+}
+
+impl ToValue for usize {
+    fn to_value(&self) -> Value<'_> {
+        Value::Number(*self as f64)
+    }
+}
+
+impl ToValue for str {
+    fn to_value(&self) -> Value<'_> {
+        Value::String(self)
+    }
+}
+
+impl ToValue for String {
+    fn to_value(&self) -> Value<'_> {
+        Value::String(self)
+    }
+}
+
+/// Absent optional values are `undefined`.
+impl<T: ToValue> ToValue for Option<T> {
+    fn to_value(&self) -> Value<'_> {
+        match self {
+            Some(value) => value.to_value(),
+            None => Value::Undefined,
+        }
+    }
+}
+
+impl<T: ?Sized> ToValue for Rc<T> {
+    fn to_value(&self) -> Value<'_> {
+        Value::Object(Rc::as_ptr(self) as *const () as usize)
+    }
+}
+
+/// Arrays are identified by the address of their elements, which is the same for a `Vec` and the slices borrowed from
+/// it. Empty arrays only have an address of their own if they allocate, see `empty_array`.
+impl<T> ToValue for [T] {
+    fn to_value(&self) -> Value<'_> {
+        Value::Object(self.as_ptr() as usize)
+    }
+}
+
+impl<T> ToValue for Vec<T> {
+    fn to_value(&self) -> Value<'_> {
+        self.as_slice().to_value()
+    }
+}
+
+impl<V> ToValue for Object<V> {
+    fn to_value(&self) -> Value<'_> {
+        Value::Object(self as *const Object<V> as usize)
+    }
+}
+
+/// The empty array `[]`, which allocates so that it is only identical to itself, like any other array.
+pub fn empty_array<T>() -> Vec<T> {
+    Vec::with_capacity(1)
+}
+
+/// `left === right`
+pub fn strict_eq<L: ToValue + ?Sized, R: ToValue + ?Sized>(left: &L, right: &R) -> bool {
+    left.to_value().strict_eq(&right.to_value())
+}
+
+/// `left == right`
+pub fn loose_eq<L: ToValue + ?Sized, R: ToValue + ?Sized>(left: &L, right: &R) -> bool {
+    left.to_value().loose_eq(&right.to_value())
+}
+
+/// `typeof value`
+pub fn type_of<T: ToValue + ?Sized>(value: &T) -> &'static str {
+    value.to_value().type_of()
+}